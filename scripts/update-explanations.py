@@ -0,0 +1,66 @@
+#!/usr/bin/env python3
+"""Regenerates the explanation database in src/explain/ from the installed toolchain.
+
+Run from the repository root after updating the toolchain, then update
+`explain::VERSION` to the version printed by `rustc --version`.
+"""
+
+import re
+import subprocess
+import sys
+import tempfile
+
+KEPT_SECTIONS = ("What it does", "Why is this bad?", "Why restrict this?", "Known problems")
+
+
+def run(*args):
+    result = subprocess.run(args, capture_output=True, text=True, cwd=tempfile.gettempdir())
+    return result.returncode, result.stdout
+
+
+def error_codes(out):
+    for number in range(1, 1000):
+        code = f"E{number:04}"
+        status, text = run("rustc", "--explain", code)
+        if status == 0:
+            out.write(f"@@ {code}\n{text.strip()}\n")
+
+
+def clippy_lints(out):
+    _, help_text = run("clippy-driver", "-W", "help")
+    lints, groups = {}, {}
+    section = None
+    for line in help_text.splitlines():
+        if line.startswith("Lint checks loaded by this crate"):
+            section = "lints"
+        elif line.startswith("Lint groups loaded by this crate"):
+            section = "groups"
+        match = re.match(r"\s*clippy::(\S+)\s+(.*)$", line)
+        if not match:
+            continue
+        name, rest = match[1].replace("-", "_"), match[2]
+        if section == "lints":
+            level, _, summary = rest.partition(" ")
+            lints[name] = (level, summary.strip())
+        elif section == "groups" and name not in ("all", "nursery_all"):
+            for member in rest.split(","):
+                groups.setdefault(member.strip().removeprefix("clippy::").replace("-", "_"), name)
+
+    for name, (level, summary) in sorted(lints.items()):
+        _, text = run("cargo", "clippy", "--explain", name)
+        kept, keep = [], False
+        for line in text.strip().splitlines():
+            if line.startswith("### "):
+                keep = line[4:].strip() in KEPT_SECTIONS
+            if keep:
+                kept.append(line)
+        group = groups.get(name, "-")
+        out.write(f"@@ {name} {group} {level}\n{summary}\n\n" + "\n".join(kept).strip() + "\n")
+
+
+if __name__ == "__main__":
+    with open("src/explain/error_codes.txt", "w") as out:
+        error_codes(out)
+    with open("src/explain/clippy_lints.txt", "w") as out:
+        clippy_lints(out)
+    print(run("rustc", "--version")[1].strip(), file=sys.stderr)
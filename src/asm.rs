@@ -0,0 +1,496 @@
+//! Parsing of the assembly output of [`CompileTarget::Assembly`](crate::endpoints::CompileTarget::Assembly).
+//!
+//! [`Assembly::parse`] splits the output into [`Function`]s made of labels,
+//! instructions and directives. Both AT&T and Intel syntax are supported, as well
+//! as the filtered and demangled output the playground returns by default and the
+//! raw output of rustc.
+
+use crate::endpoints::AssemblyFlavor;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, fmt};
+
+/// A single machine instruction.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Instruction {
+    /// The mnemonic, e.g. `movq` in AT&T or `mov` in Intel syntax.
+    pub mnemonic: String,
+
+    /// The operands in the order they were printed.
+    pub operands: Vec<String>,
+}
+
+impl Instruction {
+    /// Parses an instruction line like `movl (%rdi,%rdx,4), %eax`.
+    fn parse(line: &str) -> Self {
+        let line = strip_comment(line).trim();
+        let (mnemonic, operands) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        Self {
+            mnemonic: mnemonic.to_owned(),
+            operands: split_operands(operands.trim()),
+        }
+    }
+
+    /// Returns `true` for calls.
+    pub fn is_call(&self) -> bool {
+        self.mnemonic.starts_with("call")
+    }
+
+    /// Returns `true` for jumps, both conditional and unconditional.
+    pub fn is_jump(&self) -> bool {
+        self.mnemonic.starts_with('j')
+    }
+
+    /// Returns the demangled name of the function this instruction calls or
+    /// tail-calls, or `None` for other instructions, indirect calls and jumps
+    /// to local labels.
+    pub fn call_target(&self) -> Option<String> {
+        if !self.is_call() && !self.is_jump() {
+            return None;
+        }
+        let operand = self.operands.first()?;
+        let target = operand.trim_start_matches('*');
+        let target = target
+            .strip_prefix("qword ptr ")
+            .unwrap_or(target)
+            .trim_start_matches('[')
+            .trim_end_matches(']');
+        let target = target.strip_prefix("rip + ").unwrap_or(target);
+        let target = target.strip_suffix("(%rip)").unwrap_or(target);
+
+        let (target, relocation) = match target.rsplit_once('@') {
+            Some((target, _)) => (target, true),
+            None => (target, false),
+        };
+        let is_symbol = relocation
+            || target.contains(['_', ':', '$'])
+            || (target.starts_with(|c: char| c.is_ascii_alphabetic()) && operand.contains("%rip"));
+        if target.is_empty() || target.starts_with(".L") || target.starts_with('%') || !is_symbol {
+            return None;
+        }
+        Some(demangle(target))
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.operands.is_empty() {
+            write!(f, "{}", self.mnemonic)
+        } else {
+            write!(f, "{}\t{}", self.mnemonic, self.operands.join(", "))
+        }
+    }
+}
+
+/// An assembler directive like `.p2align 4` or `.asciz "src/main.rs"`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Directive {
+    /// The directive name including the dot, e.g. `.p2align`.
+    pub name: String,
+
+    /// Everything after the name.
+    pub arguments: String,
+}
+
+/// A line of a function's body.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum Statement {
+    /// A local label like `.LBB0_2`.
+    Label(String),
+
+    /// A machine instruction.
+    Instruction(Instruction),
+
+    /// An assembler directive.
+    Directive(Directive),
+}
+
+impl fmt::Display for Statement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Statement::Label(label) => write!(f, "{label}:"),
+            Statement::Instruction(instruction) => write!(f, "\t{instruction}"),
+            Statement::Directive(directive) if directive.arguments.is_empty() => {
+                write!(f, "\t{}", directive.name)
+            }
+            Statement::Directive(directive) => {
+                write!(f, "\t{}\t{}", directive.name, directive.arguments)
+            }
+        }
+    }
+}
+
+/// A function in the assembly output.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Function {
+    /// The demangled name without hash, e.g. `playground::main`.
+    pub name: String,
+
+    /// The label as printed, which may be a mangled symbol.
+    pub symbol: String,
+
+    /// The body of the function.
+    pub statements: Vec<Statement>,
+}
+
+impl Function {
+    /// Returns the local labels inside the function.
+    pub fn labels(&self) -> impl Iterator<Item = &str> {
+        self.statements
+            .iter()
+            .filter_map(|statement| match statement {
+                Statement::Label(label) => Some(label.as_str()),
+                _ => None,
+            })
+    }
+
+    /// Returns the instructions of the function.
+    pub fn instructions(&self) -> impl Iterator<Item = &Instruction> {
+        self.statements
+            .iter()
+            .filter_map(|statement| match statement {
+                Statement::Instruction(instruction) => Some(instruction),
+                _ => None,
+            })
+    }
+
+    /// Returns the directives inside the function.
+    pub fn directives(&self) -> impl Iterator<Item = &Directive> {
+        self.statements
+            .iter()
+            .filter_map(|statement| match statement {
+                Statement::Directive(directive) => Some(directive),
+                _ => None,
+            })
+    }
+
+    /// Returns the number of instructions.
+    pub fn instruction_count(&self) -> usize {
+        self.instructions().count()
+    }
+
+    /// Returns how often each mnemonic is used.
+    pub fn mnemonic_counts(&self) -> BTreeMap<String, usize> {
+        let mut counts = BTreeMap::new();
+        for instruction in self.instructions() {
+            *counts.entry(instruction.mnemonic.clone()).or_default() += 1;
+        }
+        counts
+    }
+
+    /// Returns the demangled names of the functions called by this one, in order
+    /// of their first call and without duplicates.
+    pub fn calls(&self) -> Vec<String> {
+        let mut calls: Vec<String> = Vec::new();
+        for target in self.instructions().filter_map(Instruction::call_target) {
+            if !calls.contains(&target) {
+                calls.push(target);
+            }
+        }
+        calls
+    }
+}
+
+impl fmt::Display for Function {
+    /// Renders the function like the playground does, with its name as label.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:", self.name)?;
+        for statement in &self.statements {
+            write!(f, "\n{statement}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A call from one function to another.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CallEdge {
+    /// The calling function.
+    pub caller: String,
+
+    /// The called function.
+    pub callee: String,
+}
+
+/// Parsed assembly output.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Assembly {
+    /// The syntax of the output.
+    pub flavor: AssemblyFlavor,
+
+    /// The functions in order of appearance.
+    pub functions: Vec<Function>,
+}
+
+impl Assembly {
+    /// Parses assembly output in the given syntax.
+    ///
+    /// A function starts at a non-local label and ends at a blank line, a
+    /// `.section` directive or the `.Lfunc_end` label rustc emits. Local labels
+    /// and data outside of functions, like string constants, are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use playground_api::{asm::Assembly, endpoints::AssemblyFlavor};
+    ///
+    /// let asm = "playground::add:\n\tleal\t(%rdi,%rsi), %eax\n\tretq\n";
+    /// let assembly = Assembly::parse(asm, AssemblyFlavor::Att);
+    ///
+    /// let add = assembly.function("add").unwrap();
+    /// assert_eq!(add.instruction_count(), 2);
+    /// ```
+    pub fn parse(text: &str, flavor: AssemblyFlavor) -> Self {
+        let mut functions = Vec::new();
+        let mut current: Option<Function> = None;
+
+        for line in text.lines() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                functions.extend(current.take());
+                continue;
+            }
+            if trimmed.starts_with('#') || trimmed.starts_with("//") || trimmed.starts_with(';') {
+                continue;
+            }
+
+            if !line.starts_with(char::is_whitespace) {
+                if let Some(label) = trimmed.strip_suffix(':') {
+                    if label.starts_with(".Lfunc_end") {
+                        functions.extend(current.take());
+                    } else if label.starts_with('.') {
+                        if let Some(function) = current.as_mut() {
+                            function.statements.push(Statement::Label(label.to_owned()));
+                        }
+                    } else {
+                        functions.extend(current.take());
+                        current = Some(Function {
+                            name: demangle(label),
+                            symbol: label.to_owned(),
+                            statements: Vec::new(),
+                        });
+                    }
+                    continue;
+                }
+            }
+
+            if trimmed.starts_with('.') {
+                let (name, arguments) = trimmed
+                    .split_once(char::is_whitespace)
+                    .unwrap_or((trimmed, ""));
+                if matches!(name, ".section" | ".text" | ".data" | ".bss") {
+                    functions.extend(current.take());
+                } else if let Some(function) = current.as_mut() {
+                    function.statements.push(Statement::Directive(Directive {
+                        name: name.to_owned(),
+                        arguments: arguments.trim().to_owned(),
+                    }));
+                }
+            } else if let Some(function) = current.as_mut() {
+                function
+                    .statements
+                    .push(Statement::Instruction(Instruction::parse(trimmed)));
+            }
+        }
+        functions.extend(current);
+
+        Self { flavor, functions }
+    }
+
+    /// Parses assembly output, detecting the syntax from the operands.
+    ///
+    /// AT&T syntax prefixes registers with `%`, Intel syntax doesn't.
+    pub fn parse_detect(text: &str) -> Self {
+        let intel = text.contains(".intel_syntax")
+            || !text
+                .lines()
+                .any(|line| line.starts_with(char::is_whitespace) && line.contains('%'));
+        let flavor = if intel {
+            AssemblyFlavor::Intel
+        } else {
+            AssemblyFlavor::Att
+        };
+        Self::parse(text, flavor)
+    }
+
+    /// Finds a function by name.
+    ///
+    /// Matches the full demangled name (`playground::sum`), a path suffix (`sum`)
+    /// or the symbol as printed.
+    pub fn function(&self, name: &str) -> Option<&Function> {
+        let suffix = format!("::{name}");
+        self.functions
+            .iter()
+            .find(|function| function.name == name || function.symbol == name)
+            .or_else(|| {
+                self.functions
+                    .iter()
+                    .find(|function| function.name.ends_with(&suffix))
+            })
+    }
+
+    /// Returns the functions whose name contains the given text.
+    pub fn filter(&self, pattern: &str) -> Vec<&Function> {
+        self.functions
+            .iter()
+            .filter(|function| function.name.contains(pattern))
+            .collect()
+    }
+
+    /// Returns the number of instructions of each function.
+    pub fn instruction_counts(&self) -> BTreeMap<String, usize> {
+        self.functions
+            .iter()
+            .map(|function| (function.name.clone(), function.instruction_count()))
+            .collect()
+    }
+
+    /// Returns every call from one function to another, including calls to
+    /// functions which aren't part of the output like `core::panicking::panic`.
+    pub fn call_graph(&self) -> Vec<CallEdge> {
+        self.functions
+            .iter()
+            .flat_map(|function| {
+                function.calls().into_iter().map(|callee| CallEdge {
+                    caller: function.name.clone(),
+                    callee,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Demangles a Rust symbol without its hash, returning other names unchanged.
+pub(crate) fn demangle(symbol: &str) -> String {
+    match rustc_demangle::try_demangle(symbol) {
+        Ok(demangled) => format!("{demangled:#}"),
+        Err(_) => symbol.to_owned(),
+    }
+}
+
+/// Removes a trailing `# comment`, ignoring `#` inside string literals.
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+    for (idx, c) in line.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..idx],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Splits operands at the commas which aren't inside parentheses or brackets.
+fn split_operands(operands: &str) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (idx, c) in operands.char_indices() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                result.push(operands[start..idx].trim().to_owned());
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    let last = operands[start..].trim();
+    if !last.is_empty() {
+        result.push(last.to_owned());
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_filtered_att() {
+        let assembly = Assembly::parse_detect(include_str!("../test-outputs/asm-att.s"));
+        assert_eq!(assembly.flavor, AssemblyFlavor::Att);
+
+        let names: Vec<_> = assembly.functions.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "playground::checked_sum",
+                "playground::first_or_zero",
+                "playground::get",
+                "playground::sum"
+            ]
+        );
+
+        let sum = assembly.function("sum").unwrap();
+        assert_eq!(sum.instruction_count(), 35);
+        assert_eq!(
+            sum.labels().collect::<Vec<_>>(),
+            [".LBB3_1", ".LBB3_5", ".LBB3_6", ".LBB3_4", ".LBB3_8"]
+        );
+        assert_eq!(sum.mnemonic_counts()["paddd"], 5);
+
+        let load = sum.instructions().nth(18).unwrap();
+        assert_eq!(load.mnemonic, "movdqu");
+        assert_eq!(load.operands, ["16(%rdi,%rdx)", "%xmm2"]);
+        assert!(sum
+            .to_string()
+            .starts_with("playground::sum:\n\ttestq\t%rsi, %rsi\n"));
+    }
+
+    #[test]
+    fn parses_raw_intel() {
+        let assembly = Assembly::parse_detect(include_str!("../test-outputs/asm-intel.s"));
+        assert_eq!(assembly.flavor, AssemblyFlavor::Intel);
+        assert_eq!(assembly.functions.len(), 4);
+
+        let get = assembly.function("playground::get").unwrap();
+        assert_eq!(get.symbol, "_ZN10playground3get17h53dae1e8bd5cfe57E");
+        assert_eq!(get.instruction_count(), 9);
+        assert!(get.directives().any(|d| d.name == ".cfi_startproc"));
+        assert_eq!(
+            get.instructions().nth(2).unwrap().operands,
+            ["eax", "dword ptr [rdi + 4*rdx]"]
+        );
+    }
+
+    #[test]
+    fn builds_call_graph() {
+        for (text, flavor) in [
+            (
+                include_str!("../test-outputs/asm-att.s"),
+                AssemblyFlavor::Att,
+            ),
+            (
+                include_str!("../test-outputs/asm-intel.s"),
+                AssemblyFlavor::Intel,
+            ),
+        ] {
+            let assembly = Assembly::parse(text, flavor);
+            let edges: Vec<_> = assembly
+                .call_graph()
+                .into_iter()
+                .map(|edge| (edge.caller, edge.callee))
+                .collect();
+            let edge = |caller: &str, callee: &str| (caller.to_owned(), callee.to_owned());
+            assert_eq!(
+                edges,
+                [
+                    edge("playground::checked_sum", "playground::sum"),
+                    edge("playground::checked_sum", "playground::get"),
+                    edge("playground::first_or_zero", "core::option::unwrap_failed"),
+                    edge("playground::get", "core::panicking::panic_bounds_check"),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn filters_functions() {
+        let assembly = Assembly::parse_detect(include_str!("../test-outputs/asm-att.s"));
+        assert_eq!(assembly.filter("sum").len(), 2);
+        assert!(assembly.function("missing").is_none());
+        assert_eq!(assembly.instruction_counts()["playground::get"], 9);
+    }
+}
@@ -0,0 +1,447 @@
+//! Per-function comparison of two assembly outputs.
+//!
+//! [`Client::compare_assembly`](crate::Client::compare_assembly) compiles two
+//! requests, e.g. the same code in debug and release mode, and aligns the
+//! functions of both outputs by name. Each function gets a [`FunctionDiff`] with
+//! the added and removed instructions, which renders as a unified diff.
+
+use crate::{
+    asm::{Assembly, Function, Statement},
+    endpoints::{AssemblyFlavor, CompileRequest, CompileResponse, CompileTarget},
+    error::Error,
+    validation::{self, ValidationIssue},
+};
+use serde::{Deserialize, Serialize};
+use similar::{ChangeTag, TextDiff};
+use std::{collections::HashMap, fmt};
+
+/// How a function changed between the two outputs.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum DiffStatus {
+    /// The function only exists in the new output.
+    Added,
+
+    /// The function only exists in the old output.
+    Removed,
+
+    /// The function exists in both outputs with different code.
+    Changed,
+
+    /// The function exists in both outputs with the same code.
+    Unchanged,
+}
+
+/// The difference of a single function.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FunctionDiff {
+    /// The demangled function name.
+    pub name: String,
+
+    /// How the function changed.
+    pub status: DiffStatus,
+
+    /// The number of instructions in the old output, 0 if the function was added.
+    pub old_instructions: usize,
+
+    /// The number of instructions in the new output, 0 if the function was removed.
+    pub new_instructions: usize,
+
+    /// The instructions only in the new output, rendered like
+    /// [`Instruction`](crate::asm::Instruction) with a tab after the mnemonic.
+    pub added: Vec<String>,
+
+    /// The instructions only in the old output.
+    pub removed: Vec<String>,
+
+    /// A unified diff of the function, empty if it is unchanged.
+    pub diff: String,
+}
+
+impl FunctionDiff {
+    fn new(name: &str, old: Option<&Function>, new: Option<&Function>) -> Self {
+        let old_text = old.map(normalize).unwrap_or_default();
+        let new_text = new.map(normalize).unwrap_or_default();
+        let status = match (old, new) {
+            (None, _) => DiffStatus::Added,
+            (_, None) => DiffStatus::Removed,
+            _ if old_text == new_text => DiffStatus::Unchanged,
+            _ => DiffStatus::Changed,
+        };
+
+        let text_diff = TextDiff::from_lines(&old_text, &new_text);
+        let mut added = Vec::new();
+        let mut removed = Vec::new();
+        for change in text_diff.iter_all_changes() {
+            // Labels aren't indented, instructions are.
+            if !change.value().starts_with('\t') {
+                continue;
+            }
+            let line = change.value().trim().to_owned();
+            match change.tag() {
+                ChangeTag::Insert => added.push(line),
+                ChangeTag::Delete => removed.push(line),
+                ChangeTag::Equal => {}
+            }
+        }
+        let diff = if status == DiffStatus::Unchanged {
+            String::new()
+        } else {
+            text_diff
+                .unified_diff()
+                .header(&format!("old/{name}"), &format!("new/{name}"))
+                .to_string()
+        };
+
+        Self {
+            name: name.to_owned(),
+            status,
+            old_instructions: old.map_or(0, Function::instruction_count),
+            new_instructions: new.map_or(0, Function::instruction_count),
+            added,
+            removed,
+            diff,
+        }
+    }
+
+    /// Returns the change in the number of instructions, negative if the new
+    /// output has fewer.
+    pub fn delta(&self) -> isize {
+        self.new_instructions as isize - self.old_instructions as isize
+    }
+}
+
+impl fmt::Display for FunctionDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.diff)
+    }
+}
+
+/// The per-function difference of two assembly outputs.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AssemblyDiff {
+    /// The functions in order of the old output, followed by the added ones.
+    pub functions: Vec<FunctionDiff>,
+}
+
+impl AssemblyDiff {
+    /// Compares two parsed assembly outputs.
+    ///
+    /// Functions are aligned by their demangled name. Local labels and references to
+    /// constants like `.Lanon.<hash>.3` or `.L__unnamed_2` are renumbered in order of
+    /// appearance before comparing, so `.LBB3_1` and `.LBB0_1` match when the
+    /// functions are in a different order. Directives are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use playground_api::{asm::Assembly, asm_diff::{AssemblyDiff, DiffStatus}};
+    ///
+    /// let old = Assembly::parse_detect("playground::one:\n\tmovl\t$1, %eax\n\tretq\n");
+    /// let new = Assembly::parse_detect("playground::one:\n\tmovl\t$1, %eax\n\tnop\n\tretq\n");
+    /// let diff = AssemblyDiff::new(&old, &new);
+    ///
+    /// assert_eq!(diff.functions[0].status, DiffStatus::Changed);
+    /// assert_eq!(diff.functions[0].added, ["nop"]);
+    /// ```
+    pub fn new(old: &Assembly, new: &Assembly) -> Self {
+        let old_functions = keyed(old);
+        let mut new_functions = keyed(new);
+        let mut new_order: Vec<_> = new_functions.keys().cloned().collect();
+        new_order.sort_by_key(|key| new_functions[key].0);
+
+        let mut functions: Vec<FunctionDiff> = Vec::new();
+        let mut old_order: Vec<_> = old_functions.iter().collect();
+        old_order.sort_by_key(|(_, (idx, _))| *idx);
+        for (key, (_, old_function)) in old_order {
+            let new_function = new_functions.remove(key).map(|(_, function)| function);
+            functions.push(FunctionDiff::new(&key.0, Some(old_function), new_function));
+        }
+        for key in new_order {
+            if let Some((_, new_function)) = new_functions.get(&key) {
+                functions.push(FunctionDiff::new(&key.0, None, Some(new_function)));
+            }
+        }
+
+        Self { functions }
+    }
+
+    /// Finds the diff of a function by its full name or a path suffix like `sum`.
+    pub fn function(&self, name: &str) -> Option<&FunctionDiff> {
+        let suffix = format!("::{name}");
+        self.functions
+            .iter()
+            .find(|function| function.name == name)
+            .or_else(|| {
+                self.functions
+                    .iter()
+                    .find(|function| function.name.ends_with(&suffix))
+            })
+    }
+
+    /// Returns the functions which were added, removed or changed.
+    pub fn changed(&self) -> impl Iterator<Item = &FunctionDiff> {
+        self.functions
+            .iter()
+            .filter(|function| function.status != DiffStatus::Unchanged)
+    }
+
+    /// Returns `true` if no function changed.
+    pub fn is_identical(&self) -> bool {
+        self.changed().next().is_none()
+    }
+
+    /// Returns a table of the changed functions with their instruction counts, e.g.
+    /// `playground::sum: 8 -> 35 (+27)`.
+    pub fn summary(&self) -> String {
+        self.changed()
+            .map(|function| {
+                format!(
+                    "{}: {} -> {} ({:+})\n",
+                    function.name,
+                    function.old_instructions,
+                    function.new_instructions,
+                    function.delta()
+                )
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for AssemblyDiff {
+    /// Renders the unified diffs of all changed functions.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for function in self.changed() {
+            f.write_str(&function.diff)?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks that both requests of a comparison compile to assembly.
+pub(crate) fn check_targets(old: &CompileRequest, new: &CompileRequest) -> Result<(), Error> {
+    let issues = [old, new]
+        .into_iter()
+        .filter(|request| request.target != CompileTarget::Assembly)
+        .map(|request| ValidationIssue::AssemblyTargetRequired(request.target))
+        .collect();
+    validation::into_result(issues)
+}
+
+/// Parses the output of a compared request in the flavor the request asked for.
+///
+/// Detecting the flavor fails for output without `%` operands, e.g. a function
+/// which is only `retq`, so the flavor of the request is used instead.
+pub(crate) fn parse_output(request: &CompileRequest, response: &CompileResponse) -> Assembly {
+    let flavor = request.assembly_flavor.unwrap_or(AssemblyFlavor::Att);
+    Assembly::parse(&response.code, flavor)
+}
+
+/// Maps `(name, occurrence)` to the position and function, so generic functions
+/// which demangle to the same name are paired in order of appearance.
+fn keyed(assembly: &Assembly) -> HashMap<(String, usize), (usize, &Function)> {
+    let mut occurrences: HashMap<&str, usize> = HashMap::new();
+    assembly
+        .functions
+        .iter()
+        .enumerate()
+        .map(|(idx, function)| {
+            let occurrence = occurrences.entry(&function.name).or_default();
+            *occurrence += 1;
+            ((function.name.clone(), *occurrence - 1), (idx, function))
+        })
+        .collect()
+}
+
+/// Renders the labels and instructions of a function with renumbered labels and
+/// constants.
+fn normalize(function: &Function) -> String {
+    let labels: HashMap<&str, String> = function
+        .labels()
+        .enumerate()
+        .map(|(idx, label)| (label, format!(".L{idx}")))
+        .collect();
+    let mut constants: HashMap<String, String> = HashMap::new();
+
+    let mut text = String::new();
+    for statement in &function.statements {
+        match statement {
+            Statement::Label(label) => {
+                text.push_str(&labels[label.as_str()]);
+                text.push_str(":\n");
+            }
+            Statement::Instruction(instruction) => {
+                let mut instruction = instruction.clone();
+                for operand in &mut instruction.operands {
+                    if let Some(label) = labels.get(operand.as_str()) {
+                        operand.clone_from(label);
+                    } else {
+                        *operand = renumber_constants(operand, &mut constants);
+                    }
+                }
+                text.push_str(&format!("\t{instruction}\n"));
+            }
+            Statement::Directive(_) => {}
+        }
+    }
+    text
+}
+
+/// Replaces the compiler-generated constant symbols in an operand, like
+/// `.Lanon.<hash>.3(%rip)` or `[rip + .L__unnamed_2]`. The hash and numbers depend
+/// on the whole crate, so they are renumbered per function in order of appearance.
+fn renumber_constants(operand: &str, constants: &mut HashMap<String, String>) -> String {
+    const PREFIXES: [&str; 2] = [".Lanon.", ".L__unnamed_"];
+
+    let mut result = String::new();
+    let mut rest = operand;
+    while let Some(start) = rest.find(".L") {
+        let symbol_len = rest[start..]
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_' || c == '.'))
+            .unwrap_or(rest.len() - start);
+        let symbol = &rest[start..start + symbol_len];
+        result.push_str(&rest[..start]);
+
+        match PREFIXES.iter().find(|prefix| symbol.starts_with(*prefix)) {
+            Some(prefix) => {
+                let count = constants
+                    .values()
+                    .filter(|replacement| replacement.starts_with(prefix))
+                    .count();
+                let replacement = constants
+                    .entry(symbol.to_owned())
+                    .or_insert_with(|| format!("{prefix}{count}"));
+                result.push_str(replacement);
+            }
+            None => result.push_str(symbol),
+        }
+        rest = &rest[start + symbol_len..];
+    }
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compares_debug_and_release() {
+        let debug = Assembly::parse_detect(include_str!("../test-outputs/asm-debug-att.s"));
+        let release = Assembly::parse_detect(include_str!("../test-outputs/asm-att.s"));
+        let diff = AssemblyDiff::new(&debug, &release);
+
+        let get = diff.function("get").unwrap();
+        assert_eq!(get.status, DiffStatus::Changed);
+        assert_eq!((get.old_instructions, get.new_instructions), (15, 9));
+        assert_eq!(get.delta(), -6);
+        assert!(get.removed.contains(&"subq\t$24, %rsp".to_owned()));
+        assert!(get
+            .diff
+            .starts_with("--- old/playground::get\n+++ new/playground::get\n"));
+
+        let checked_sum = diff.function("checked_sum").unwrap();
+        assert!(checked_sum
+            .removed
+            .iter()
+            .any(|line| line.contains("panic_const_add_overflow")));
+
+        // Inlined helpers only exist in the debug build.
+        let removed = diff.function("core::slice::iter::Iter<T>::new").unwrap();
+        assert_eq!(removed.status, DiffStatus::Removed);
+        assert_eq!(removed.new_instructions, 0);
+        assert!(diff.summary().contains("playground::get: 15 -> 9 (-6)\n"));
+        assert!(diff.to_string().contains("+++ new/playground::sum\n"));
+    }
+
+    #[test]
+    fn renumbers_labels() {
+        let old = Assembly::parse_detect(
+            "playground::a:\n\tretq\n\nplayground::f:\n\tjmp\t.LBB1_1\n.LBB1_1:\n\tretq\n",
+        );
+        let new = Assembly::parse_detect(
+            "playground::f:\n\tjmp\t.LBB0_1\n.LBB0_1:\n\tretq\n\nplayground::b:\n\tretq\n",
+        );
+        let diff = AssemblyDiff::new(&old, &new);
+
+        let statuses: Vec<_> = diff
+            .functions
+            .iter()
+            .map(|function| (function.name.as_str(), function.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            [
+                ("playground::a", DiffStatus::Removed),
+                ("playground::f", DiffStatus::Unchanged),
+                ("playground::b", DiffStatus::Added),
+            ]
+        );
+        assert!(diff.function("f").unwrap().diff.is_empty());
+        assert!(!diff.is_identical());
+        assert!(AssemblyDiff::new(&old, &old).is_identical());
+    }
+
+    #[test]
+    fn renumbers_constants() {
+        let old = Assembly::parse_detect(
+            "playground::f:\n\tleaq\t.Lanon.d5924fec.1(%rip), %rdi\n\tleaq\t.L__unnamed_4(%rip), %rsi\n\tleaq\t.Lanon.d5924fec.1(%rip), %rdx\n\tretq\n",
+        );
+        let new = Assembly::parse_detect(
+            "playground::f:\n\tleaq\t.Lanon.ce3c7c4b.3(%rip), %rdi\n\tleaq\t.L__unnamed_1(%rip), %rsi\n\tleaq\t.Lanon.ce3c7c4b.3(%rip), %rdx\n\tretq\n",
+        );
+        assert!(AssemblyDiff::new(&old, &new).is_identical());
+
+        // Different constants are still told apart.
+        let swapped = Assembly::parse_detect(
+            "playground::f:\n\tleaq\t.Lanon.ce3c7c4b.3(%rip), %rdi\n\tleaq\t.L__unnamed_1(%rip), %rsi\n\tleaq\t.Lanon.ce3c7c4b.4(%rip), %rdx\n\tretq\n",
+        );
+        let diff = AssemblyDiff::new(&old, &swapped);
+        assert_eq!(diff.functions[0].added, ["leaq\t.Lanon.1(%rip), %rdx"]);
+    }
+
+    #[test]
+    fn requires_assembly_targets() {
+        let assembly = CompileRequest::builder()
+            .target(CompileTarget::Assembly)
+            .build();
+        let mir = CompileRequest::builder().target(CompileTarget::Mir).build();
+        assert!(check_targets(&assembly, &assembly).is_ok());
+        let Err(Error::InvalidRequest(issues)) = check_targets(&mir, &assembly) else {
+            panic!("expected an invalid request");
+        };
+        assert_eq!(
+            issues,
+            [ValidationIssue::AssemblyTargetRequired(CompileTarget::Mir)]
+        );
+    }
+    #[test]
+    fn parses_in_requested_flavor() {
+        let response: CompileResponse = serde_json::from_value(serde_json::json!({
+            "success": true,
+            "exitDetail": "",
+            "code": "playground::noop:\n\tretq\n",
+            "stdout": "",
+            "stderr": "",
+        }))
+        .unwrap();
+        assert_eq!(
+            Assembly::parse_detect(&response.code).flavor,
+            AssemblyFlavor::Intel
+        );
+
+        let att = CompileRequest::builder()
+            .target(CompileTarget::Assembly)
+            .assembly_flavor(AssemblyFlavor::Att)
+            .build();
+        assert_eq!(parse_output(&att, &response).flavor, AssemblyFlavor::Att);
+
+        let intel = CompileRequest::builder()
+            .target(CompileTarget::Assembly)
+            .assembly_flavor(AssemblyFlavor::Intel)
+            .build();
+        assert_eq!(
+            parse_output(&intel, &response).flavor,
+            AssemblyFlavor::Intel
+        );
+    }
+}
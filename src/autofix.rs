@@ -0,0 +1,203 @@
+//! Automatic fixing of code using the suggestions of the compiler.
+//!
+//! [`Client::autofix`](crate::Client::autofix) compiles the code, applies every
+//! machine-applicable suggestion from the diagnostics and repeats until the
+//! compiler has nothing left to suggest. The result is an [`AutofixResponse`].
+
+use crate::{
+    diagnostics::{self, Applicability, Diagnostic, Suggestion},
+    endpoints::CompileResponse,
+};
+use serde::{Deserialize, Serialize};
+use similar::TextDiff;
+
+/// A suggestion which was applied to the code.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Fix {
+    /// The message of the diagnostic the suggestion belongs to.
+    pub message: String,
+
+    /// The error or lint code of the diagnostic, e.g. `E0384`.
+    pub code: Option<String>,
+
+    /// The applied suggestion.
+    pub suggestion: Suggestion,
+}
+
+/// The fixes applied after one compilation.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Round {
+    /// The applied fixes, in the order of the diagnostics.
+    pub fixes: Vec<Fix>,
+}
+
+/// The result of [`Client::autofix`](crate::Client::autofix).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AutofixResponse {
+    /// The fixed code.
+    pub code: String,
+
+    /// A unified diff from the original to the fixed code, empty if nothing changed.
+    pub diff: String,
+
+    /// The fixes applied in each round.
+    pub rounds: Vec<Round>,
+
+    /// The response of compiling the fixed code.
+    pub response: CompileResponse,
+}
+
+impl AutofixResponse {
+    pub(crate) fn new(
+        original: &str,
+        code: String,
+        rounds: Vec<Round>,
+        response: CompileResponse,
+    ) -> Self {
+        Self {
+            diff: unified_diff(original, &code),
+            code,
+            rounds,
+            response,
+        }
+    }
+
+    /// Returns every applied fix across all rounds.
+    pub fn fixes(&self) -> impl Iterator<Item = &Fix> {
+        self.rounds.iter().flat_map(|round| round.fixes.iter())
+    }
+
+    /// Returns `true` if the fixed code compiles.
+    pub fn is_fixed(&self) -> bool {
+        self.response.success
+    }
+}
+
+/// Applies the first machine-applicable suggestion of each diagnostic to `code`.
+///
+/// Returns `None` if no suggestion could be applied.
+pub(crate) fn fix_round(code: &str, diagnostics: &[Diagnostic]) -> Option<(String, Round)> {
+    let candidates: Vec<(&Diagnostic, &Suggestion)> = diagnostics
+        .iter()
+        .filter_map(|diagnostic| {
+            diagnostic
+                .suggestions
+                .iter()
+                .find(|s| s.applicability == Applicability::MachineApplicable)
+                .map(|suggestion| (diagnostic, suggestion))
+        })
+        .collect();
+    let suggestions: Vec<&Suggestion> = candidates.iter().map(|(_, s)| *s).collect();
+
+    let (fixed, applied) = diagnostics::apply(code, &suggestions);
+    if applied.is_empty() {
+        return None;
+    }
+
+    let fixes = applied
+        .into_iter()
+        .map(|idx| {
+            let (diagnostic, suggestion) = candidates[idx];
+            Fix {
+                message: diagnostic.message.clone(),
+                code: diagnostic.code.clone(),
+                suggestion: suggestion.clone(),
+            }
+        })
+        .collect();
+    Some((fixed, Round { fixes }))
+}
+
+/// Renders a unified diff with three lines of context.
+pub(crate) fn unified_diff(original: &str, changed: &str) -> String {
+    if original == changed {
+        return String::new();
+    }
+    TextDiff::from_lines(original, changed)
+        .unified_diff()
+        .header("original", "fixed")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CODE: &str = "use std::collections::HashMap;
+
+struct Point {
+    x: i32,
+}
+
+fn main() {
+    let p = Point { x: 1 };
+    println!(\"{}\", p.x);
+    let s: String = 5;
+    let m: HashSet<u8> = HashSet::new();
+}
+";
+
+    #[test]
+    fn fixes_machine_applicable_suggestions() {
+        let code = "fn main() {
+    let x = 5;
+    x = 6;
+    let v = vec![1, 2, 3];
+    let w = v;
+    println!(\"{:?} {}\", v, x);
+    let unused = 3;
+    let mut s = String::new();
+    let r = &s;
+    s.push('a');
+    println!(\"{r}\");
+}
+";
+        let diagnostics = diagnostics::parse(include_str!("../test-outputs/borrow.stderr"));
+        let (fixed, round) = fix_round(code, &diagnostics).unwrap();
+
+        // Cloning `v` is only maybe correct, so it is left to the user.
+        let codes: Vec<_> = round.fixes.iter().map(|fix| fix.code.as_deref()).collect();
+        assert_eq!(codes, [Some("E0384"), None, None]);
+        assert!(fixed.contains("    let mut x = 5;\n"));
+        assert!(fixed.contains("    let _w = v;\n"));
+        assert!(fixed.contains("    let _unused = 3;\n"));
+        assert!(!fixed.contains("clone"));
+
+        let diff = unified_diff(code, &fixed);
+        assert!(diff.starts_with("--- original\n+++ fixed\n@@ -1,10 +1,10 @@\n"));
+        assert!(diff.contains("\n+    let mut x = 5;\n"));
+
+        // Importing `HashSet` and converting with `.to_string()` are only maybe correct.
+        let diagnostics = diagnostics::parse(include_str!("../test-outputs/errors.stderr"));
+        assert!(fix_round(CODE, &diagnostics).is_none());
+    }
+
+    #[test]
+    fn fixes_tab_indented_code() {
+        let code = include_str!("../test-outputs/tabs.rs");
+        let stderr = include_str!("../test-outputs/tabs.stderr");
+        let (fixed, round) = fix_round(code, &diagnostics::parse_with_code(stderr, code)).unwrap();
+        let codes: Vec<_> = round.fixes.iter().map(|fix| fix.code.as_deref()).collect();
+        assert_eq!(codes, [Some("E0384"), None]);
+        assert_eq!(
+            fixed,
+            code.replacen("\tlet x = 5;", "\tlet mut x = 5;", 1)
+                .replacen("\tlet w = v;", "\tlet _w = v;", 1)
+        );
+
+        // Without the code the columns of line 2 are unknown, so only the rename on
+        // line 8, anchored by the `-->` location, is applied.
+        let (fixed, round) = fix_round(code, &diagnostics::parse(stderr)).unwrap();
+        assert_eq!(round.fixes.len(), 1);
+        assert_eq!(fixed, code.replacen("\tlet w = v;", "\tlet _w = v;", 1));
+    }
+
+    #[test]
+    fn stops_without_suggestions() {
+        let mut diagnostics = diagnostics::parse(include_str!("../test-outputs/errors.stderr"));
+        diagnostics.retain(|diagnostic| diagnostic.code.as_deref() == Some("E0308"));
+        assert!(fix_round(CODE, &diagnostics).is_none());
+        assert!(fix_round(CODE, &[]).is_none());
+        assert_eq!(unified_diff("a\n", "a\n"), "");
+    }
+}
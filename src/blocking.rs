@@ -1,10 +1,25 @@
 //! Holds the blocking version of the Client. Only accessible by enabling the `blocking` feature.
 
 #[cfg(feature = "blocking")]
-use crate::{endpoints::*, error::Error};
+use crate::{
+    asm_diff::{self, AssemblyDiff},
+    autofix::AutofixResponse,
+    endpoints::*,
+    error::Error,
+    eval::EvalResponse,
+    explore::{ExploreOptions, ExploreResponse},
+    hir::{self, HirFragment, Selection},
+    ice::Ice,
+    mir::Mir,
+    rustfmt::FormatCheck,
+    snippet::Snippet,
+    validation::{self, Validate},
+};
 #[cfg(feature = "blocking")]
 use serde::{de::Deserialize, Serialize};
 #[cfg(feature = "blocking")]
+use std::sync::{Arc, OnceLock};
+#[cfg(feature = "blocking")]
 use url::{ParseError, Url};
 
 /// A client for interacting with the Rust playground API.
@@ -15,6 +30,8 @@ use url::{ParseError, Url};
 pub struct Client {
     url: Url,
     client: reqwest::blocking::Client,
+    validate: bool,
+    versions: Arc<OnceLock<VersionsResponse>>,
 }
 
 #[cfg(feature = "blocking")]
@@ -34,7 +51,33 @@ impl Client {
     pub fn new(url: &str) -> Result<Client, Error> {
         let url = Url::parse(url)?;
         let client = reqwest::blocking::Client::new();
-        Ok(Client { url, client })
+        Ok(Client {
+            url,
+            client,
+            validate: false,
+            versions: Arc::default(),
+        })
+    }
+
+    /// Enables or disables client-side validation of requests.
+    ///
+    /// When enabled, every request is checked with its `validate()` method before
+    /// it is sent, and invalid requests fail with [`Error::InvalidRequest`] without
+    /// a round trip to the playground. The edition of each request is also checked
+    /// against the rustc version of its channel, see [`Edition::check_support`]. The
+    /// versions are fetched once with [`Client::versions`] and cached; if they can't
+    /// be fetched, the edition check is skipped. Disabled by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether requests should be validated before sending.
+    ///
+    /// # Returns
+    ///
+    /// * `Client` - The same client with the validation mode updated.
+    pub fn validate_requests(mut self, enabled: bool) -> Self {
+        self.validate = enabled;
+        self
     }
 
     /// Sends a code execution request to the Rust playground and returns the result.
@@ -269,15 +312,346 @@ impl Client {
         self.get(Endpoints::GistGet(id))
     }
 
+    /// Evaluates an expression or a list of statements and returns its value.
+    ///
+    /// The code is wrapped into a runnable program with [`crate::eval::wrap`] and executed
+    /// with the default [`ExecuteRequest`] settings. The Debug representation of the
+    /// final expression is returned separately from anything the code printed itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The expression or statements to evaluate, e.g. `1 + 2` or
+    ///   `let v = vec![1, 2]; v.len()`.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<EvalResponse, Error>` - On success, returns an [`EvalResponse`] with the value,
+    ///   the program's own stdout and the full [`ExecuteResponse`]. On failure, returns an [`Error`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`Client::execute`].
+    pub fn eval(&self, code: &str) -> Result<EvalResponse, Error> {
+        let request = ExecuteRequest::builder()
+            .code(crate::eval::wrap(code))
+            .build();
+        let response = self.execute(&request)?;
+        Ok(response.into())
+    }
+
+    /// Executes a [`Snippet`] and returns the result.
+    ///
+    /// Shorthand for converting the snippet into an [`ExecuteRequest`] and calling
+    /// [`Client::execute`].
+    ///
+    /// # Arguments
+    ///
+    /// * `snippet` - A reference to the [`Snippet`] to run.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<ExecuteResponse, Error>` - The same result as [`Client::execute`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`Client::execute`].
+    pub fn run(&self, snippet: &Snippet) -> Result<ExecuteResponse, Error> {
+        self.execute(&snippet.into())
+    }
+
+    /// Compiles a [`Snippet`] to assembly in the given flavor and returns the result.
+    ///
+    /// Shorthand for [`Snippet::assembly_request`] followed by [`Client::compile`].
+    ///
+    /// # Arguments
+    ///
+    /// * `snippet` - A reference to the [`Snippet`] to compile.
+    /// * `flavor` - The assembly syntax to emit.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<CompileResponse, Error>` - The same result as [`Client::compile`], with the
+    ///   assembly in [`CompileResponse::code`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`Client::compile`].
+    pub fn asm(&self, snippet: &Snippet, flavor: AssemblyFlavor) -> Result<CompileResponse, Error> {
+        self.compile(&snippet.assembly_request(flavor))
+    }
+
+    /// Runs Clippy on a [`Snippet`] and returns the result.
+    ///
+    /// Shorthand for converting the snippet into a [`ClippyRequest`] and calling
+    /// [`Client::clippy`].
+    ///
+    /// # Arguments
+    ///
+    /// * `snippet` - A reference to the [`Snippet`] to lint.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<ClippyResponse, Error>` - The same result as [`Client::clippy`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`Client::clippy`].
+    pub fn lint(&self, snippet: &Snippet) -> Result<ClippyResponse, Error> {
+        self.clippy(&snippet.into())
+    }
+
+    /// Repeatedly compiles a [`Snippet`] and applies the compiler's suggested fixes.
+    ///
+    /// Each round compiles the code to LLVM IR, applies the first machine-applicable
+    /// suggestion of every diagnostic and starts over with the changed code. The loop
+    /// stops once no suggestion can be applied or after `max_rounds` rounds. The
+    /// diagnostics are parsed with [`parse_with_code`](crate::diagnostics::parse_with_code),
+    /// so fixes on tab-indented lines land at the right column.
+    ///
+    /// # Arguments
+    ///
+    /// * `snippet` - A reference to the [`Snippet`] to fix.
+    /// * `max_rounds` - The maximum number of times fixes are applied.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<AutofixResponse, Error>` - On success, returns an [`AutofixResponse`] with the
+    ///   fixed code, a unified diff, the fixes of each round and the response of compiling the
+    ///   fixed code. On failure, returns an [`Error`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`Client::compile`].
+    pub fn autofix(&self, snippet: &Snippet, max_rounds: usize) -> Result<AutofixResponse, Error> {
+        let mut request = snippet.compile_request(CompileTarget::LlvmIr);
+        let mut rounds = Vec::new();
+
+        loop {
+            let response = self.compile(&request)?;
+            let fixed = if rounds.len() < max_rounds {
+                let diagnostics =
+                    crate::diagnostics::parse_with_code(&response.stderr, &request.code);
+                crate::autofix::fix_round(&request.code, &diagnostics)
+            } else {
+                None
+            };
+
+            match fixed {
+                Some((code, round)) => {
+                    request.code = code;
+                    rounds.push(round);
+                }
+                None => {
+                    return Ok(AutofixResponse::new(
+                        &snippet.code,
+                        request.code,
+                        rounds,
+                        response,
+                    ))
+                }
+            }
+        }
+    }
+
+    /// Creates a bug report for an internal compiler error.
+    ///
+    /// Fetches the tool versions with [`Client::versions`] and renders the report
+    /// with [`Ice::bug_report`] using the versions of the snippet's channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `snippet` - The [`Snippet`] which caused the ICE.
+    /// * `ice` - The ICE, e.g. from [`CompileResponse::ice`].
+    ///
+    /// # Returns
+    ///
+    /// * `Result<String, Error>` - On success, returns the Markdown body of the bug report.
+    ///   On failure, returns an [`Error`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`Client::versions`].
+    pub fn bug_report(&self, snippet: &Snippet, ice: &Ice) -> Result<String, Error> {
+        let versions = self.versions()?;
+        Ok(ice.bug_report(snippet, Some(versions.channel(snippet.channel))))
+    }
+
+    /// Compiles two requests to assembly and compares the output per function.
+    ///
+    /// Functions are aligned by name, see [`AssemblyDiff::new`]. Use this to compare
+    /// two versions of the code or the same code with different settings, e.g.
+    /// debug and release mode. Both requests are compiled concurrently, and each output
+    /// is parsed in the `assembly_flavor` of its request.
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - The [`CompileRequest`] of the baseline. Its target must be
+    ///   [`CompileTarget::Assembly`].
+    /// * `new` - The [`CompileRequest`] to compare against the baseline. Its target must
+    ///   also be [`CompileTarget::Assembly`].
+    ///
+    /// # Returns
+    ///
+    /// * `Result<AssemblyDiff, Error>` - On success, returns an [`AssemblyDiff`] with a diff of
+    ///   each function. On failure, returns an [`Error`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRequest`] if either target isn't [`CompileTarget::Assembly`].
+    /// Otherwise returns an error under the same conditions as [`Client::compile`], or
+    /// [`Error::Unsuccessful`] if either request doesn't compile.
+    pub fn compare_assembly(
+        &self,
+        old: &CompileRequest,
+        new: &CompileRequest,
+    ) -> Result<AssemblyDiff, Error> {
+        asm_diff::check_targets(old, new)?;
+        let (old_response, new_response) = std::thread::scope(|scope| {
+            let old = scope.spawn(|| self.compile(old));
+            let new = self.compile(new);
+            let old = old
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+            (old, new)
+        });
+        let (old_response, new_response) =
+            (old_response?.into_result()?, new_response?.into_result()?);
+        Ok(AssemblyDiff::new(
+            &asm_diff::parse_output(old, &old_response),
+            &asm_diff::parse_output(new, &new_response),
+        ))
+    }
+
+    /// Compiles a [`Snippet`] to MIR and parses the output.
+    ///
+    /// Use [`Function::to_dot`](crate::mir::Function::to_dot) on a body of the result to
+    /// render its control-flow graph.
+    ///
+    /// # Arguments
+    ///
+    /// * `snippet` - A reference to the [`Snippet`] to compile.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Mir, Error>` - On success, returns the parsed [`Mir`]. On failure, returns an
+    ///   [`Error`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`Client::compile`], or
+    /// [`Error::Unsuccessful`] if the snippet doesn't compile.
+    pub fn mir(&self, snippet: &Snippet) -> Result<Mir, Error> {
+        let request = snippet.compile_request(CompileTarget::Mir);
+        Ok(self.compile(&request)?.into_result()?.mir())
+    }
+
+    /// Compiles a [`Snippet`] to HIR and returns the cleaned-up HIR of a selected item or
+    /// range of source lines, e.g. to show what `?` or `.await` desugar to.
+    ///
+    /// The HIR target requires nightly, so the snippet is always compiled on the nightly
+    /// channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `snippet` - A reference to the [`Snippet`] to compile.
+    /// * `selection` - The item or source lines to select, see [`hir::select`].
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<HirFragment>, Error>` - On success, returns the selected fragments, empty
+    ///   if nothing matched. On failure, returns an [`Error`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`Client::compile`], or
+    /// [`Error::Unsuccessful`] if the snippet doesn't compile.
+    pub fn desugar(
+        &self,
+        snippet: &Snippet,
+        selection: &Selection,
+    ) -> Result<Vec<HirFragment>, Error> {
+        let mut request = snippet.compile_request(CompileTarget::Hir);
+        request.channel = Channel::Nightly;
+        let response = self.compile(&request)?.into_result()?;
+        Ok(hir::select(&response.code, &snippet.code, selection))
+    }
+
+    /// Compiles code to several targets concurrently.
+    ///
+    /// Sends one compile request per target selected in `options`. A failing request
+    /// doesn't abort the others: each target's result is kept separately in the
+    /// [`ExploreResponse`], e.g. Wasm may fail to compile while the other targets still
+    /// return their output. HIR is always compiled on the nightly channel.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The Rust source code.
+    /// * `options` - The targets and the settings shared by all requests.
+    ///
+    /// # Returns
+    ///
+    /// * `ExploreResponse` - The [`CompileResponse`] or [`Error`] of each selected target.
+    ///   A response for code which didn't compile is reported by
+    ///   [`ExploreResponse::errors`].
+    pub fn explore(&self, code: &str, options: &ExploreOptions) -> ExploreResponse {
+        let requests = options.requests(code);
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = requests
+                .iter()
+                .map(|request| (request.target, scope.spawn(|| self.compile(request))))
+                .collect();
+            ExploreResponse::new(handles.into_iter().map(|(target, handle)| {
+                let result = handle
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+                (target, result)
+            }))
+        })
+    }
+
+    /// Formats a [`Snippet`] with rustfmt and compares the result with the input.
+    ///
+    /// Use this to check whether code is rustfmt-clean and to show what rustfmt
+    /// would change.
+    ///
+    /// # Arguments
+    ///
+    /// * `snippet` - A reference to the [`Snippet`] to check.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<FormatCheck, Error>` - On success, returns a [`FormatCheck`] with the
+    ///   formatted code, a unified diff and the changed hunks. If rustfmt failed, e.g. on
+    ///   a syntax error, its [`failure`](FormatCheck::failure) holds the parsed errors.
+    ///   On failure, returns an [`Error`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the same conditions as [`Client::format`]. A failure of
+    /// rustfmt itself is reported in the [`FormatCheck`] instead.
+    pub fn check_format(&self, snippet: &Snippet) -> Result<FormatCheck, Error> {
+        let response = self.format(&snippet.into())?;
+        Ok(FormatCheck::from_response(&snippet.code, response))
+    }
+
     /// Sends a POST request with a serialized JSON payload to the specified endpoint,
     /// and deserializes the response into the expected type.
     ///
     /// Used internally to interact with Rust playground endpoints.
     fn post<T, U>(&self, request: &T, endpoint: Endpoints) -> Result<U, Error>
     where
-        T: Serialize,
+        T: Serialize + Validate,
         U: for<'de> Deserialize<'de>,
     {
+        if self.validate {
+            request.validate()?;
+            if request.toolchain().is_some() {
+                if let Some(versions) = self.cached_versions() {
+                    validation::check_edition(request, versions)?;
+                }
+            }
+        }
+
         let url = self.get_url(endpoint)?;
         let res = self.client.post(url).json(request).send()?;
 
@@ -308,6 +682,18 @@ impl Client {
         Ok(res)
     }
 
+    /// Returns the tool versions of the playground, fetching them on first use.
+    ///
+    /// Returns `None` if they can't be fetched, so a failing versions endpoint
+    /// doesn't block other requests.
+    fn cached_versions(&self) -> Option<&VersionsResponse> {
+        if let Some(versions) = self.versions.get() {
+            return Some(versions);
+        }
+        let versions = self.versions().ok()?;
+        Some(self.versions.get_or_init(|| versions))
+    }
+
     /// Takes an endpoint and returns the correct url.
     fn get_url(&self, endpoint: Endpoints) -> Result<Url, ParseError> {
         let url = match endpoint {
@@ -334,6 +720,8 @@ impl Default for Client {
         Self {
             url: Url::parse("https://play.rust-lang.org/").unwrap(),
             client,
+            validate: false,
+            versions: Arc::default(),
         }
     }
 }
@@ -0,0 +1,141 @@
+//! Helpers for separating cargo's own output from compiler and program output.
+
+use std::time::Duration;
+
+/// The status verbs cargo prints right-aligned at the start of its progress lines.
+const STATUS_VERBS: [&str; 14] = [
+    "Compiling",
+    "Checking",
+    "Finished",
+    "Running",
+    "Blocking",
+    "Downloading",
+    "Downloaded",
+    "Updating",
+    "Locking",
+    "Adding",
+    "Fresh",
+    "Documenting",
+    "Doc-tests",
+    "Executable",
+];
+
+/// The parts of the stderr of a `cargo run` or `cargo test` invocation.
+pub(crate) struct Stderr<'a> {
+    /// Cargo's progress and summary lines.
+    pub(crate) build_log: Vec<&'a str>,
+
+    /// Everything rustc printed while building.
+    pub(crate) compiler: Vec<&'a str>,
+
+    /// Everything printed after cargo started the program, without cargo's own
+    /// status lines.
+    pub(crate) program: String,
+}
+
+/// Splits stderr at the first `Running` line into the build and the program part.
+///
+/// Cargo keeps printing status lines after that, e.g. `Running` for every test
+/// binary and `Doc-tests` before the doc tests of a library. They go into the
+/// build log instead of the program output.
+pub(crate) fn split(stderr: &str) -> Stderr<'_> {
+    let mut build_log = Vec::new();
+    let mut compiler = Vec::new();
+    let mut program = String::new();
+    let mut running = false;
+
+    for line in stderr.split_inclusive('\n') {
+        let trimmed = line.trim_end_matches(['\n', '\r']);
+        let verb = status_verb(trimmed);
+
+        if running {
+            if matches!(verb, Some("Running" | "Doc-tests")) {
+                build_log.push(trimmed);
+            } else {
+                program.push_str(line);
+            }
+        } else if verb.is_some() || is_summary(trimmed) {
+            running = verb == Some("Running");
+            build_log.push(trimmed);
+        } else {
+            compiler.push(trimmed);
+        }
+    }
+
+    Stderr {
+        build_log,
+        compiler,
+        program,
+    }
+}
+
+/// Returns the verb of a cargo status line like `   Compiling playground v0.0.1`.
+pub(crate) fn status_verb(line: &str) -> Option<&'static str> {
+    if !line.starts_with(' ') {
+        return None;
+    }
+    let word = line.split_whitespace().next()?;
+    STATUS_VERBS.iter().copied().find(|verb| *verb == word)
+}
+
+/// Returns `true` for cargo's summary lines like "could not compile `playground`".
+fn is_summary(line: &str) -> bool {
+    line.starts_with("warning: `playground` (")
+        || line.starts_with("error: could not compile")
+        || line.starts_with("error: aborting due to")
+}
+
+/// Extracts the build time from a line like
+/// `Finished `dev` profile [unoptimized + debuginfo] target(s) in 0.61s`.
+pub(crate) fn build_time(line: &str) -> Option<Duration> {
+    if status_verb(line) != Some("Finished") {
+        return None;
+    }
+
+    let time = line.rsplit(" in ").next()?.trim();
+    let (minutes, seconds) = match time.split_once("m ") {
+        Some((minutes, seconds)) => (minutes.parse::<u64>().ok()?, seconds),
+        None => (0, time),
+    };
+    let seconds: f64 = seconds.strip_suffix('s')?.parse().ok()?;
+    Some(Duration::from_secs(minutes * 60) + Duration::from_secs_f64(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_stderr() {
+        let stderr = include_str!("../test-outputs/execute.stderr");
+        let parts = split(stderr);
+
+        assert_eq!(parts.build_log.len(), 4);
+        assert!(parts.build_log[0].trim_start().starts_with("Compiling"));
+        assert!(parts.compiler[0].starts_with("warning: unused variable"));
+        assert!(parts.program.starts_with("program error line\n"));
+    }
+
+    #[test]
+    fn splits_library_tests() {
+        let stderr = include_str!("../test-outputs/lib-tests.stderr");
+        let parts = split(stderr);
+
+        let verbs: Vec<_> = parts
+            .build_log
+            .iter()
+            .filter_map(|line| status_verb(line))
+            .collect();
+        assert_eq!(verbs, ["Compiling", "Finished", "Running", "Doc-tests"]);
+        assert_eq!(parts.build_log.len(), 6);
+        assert!(parts.compiler[0].starts_with("warning: unused variable"));
+        assert_eq!(parts.program, "program error line\n");
+    }
+
+    #[test]
+    fn parses_build_time() {
+        let line = "    Finished `dev` profile [unoptimized + debuginfo] target(s) in 1m 02s";
+        assert_eq!(build_time(line), Some(Duration::from_secs(62)));
+        assert_eq!(build_time("     Running `target/debug/playground`"), None);
+    }
+}
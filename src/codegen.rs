@@ -0,0 +1,395 @@
+//! Detection of panic paths and vectorization in generated code.
+//!
+//! [`CodegenReport`] answers the usual performance questions per function without
+//! reading the output by hand: are there bounds checks, unwraps or overflow
+//! checks left, and was the code vectorized? It works on the output of both
+//! [`CompileTarget::LlvmIr`](crate::endpoints::CompileTarget::LlvmIr) and
+//! [`CompileTarget::Assembly`](crate::endpoints::CompileTarget::Assembly).
+
+use crate::{
+    asm::{Assembly, Instruction},
+    llvm_ir::Module,
+};
+use serde::{Deserialize, Serialize};
+
+/// Why a function may panic.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum PanicKind {
+    /// An index out of bounds, e.g. `core::panicking::panic_bounds_check` or
+    /// `core::slice::index::slice_end_index_len_fail`.
+    BoundsCheck,
+
+    /// `unwrap` or `expect` on a `None` or an `Err`.
+    Unwrap,
+
+    /// An arithmetic overflow check, e.g. `panic_const_add_overflow`.
+    Overflow,
+
+    /// A division or remainder by zero.
+    DivisionByZero,
+
+    /// Any other panic, e.g. `panic!` or a failed assertion.
+    Panic,
+}
+
+impl PanicKind {
+    /// Classifies a call by the demangled name of the callee.
+    ///
+    /// Returns `None` for functions which don't panic.
+    pub fn from_callee(callee: &str) -> Option<Self> {
+        let name = callee.rsplit("::").next().unwrap_or(callee);
+        if callee.starts_with("core::panicking::panic_const::") {
+            return Some(if name.ends_with("_by_zero") {
+                PanicKind::DivisionByZero
+            } else if name.ends_with("_overflow") {
+                PanicKind::Overflow
+            } else {
+                PanicKind::Panic
+            });
+        }
+
+        match name {
+            "panic_bounds_check" => Some(PanicKind::BoundsCheck),
+            _ if callee.starts_with("core::slice::index::") && name.ends_with("_fail") => {
+                Some(PanicKind::BoundsCheck)
+            }
+            "unwrap_failed" | "expect_failed" => Some(PanicKind::Unwrap),
+            _ if callee.starts_with("core::panicking::")
+                || callee.starts_with("std::panicking::")
+                || name == "begin_panic" =>
+            {
+                Some(PanicKind::Panic)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A call which panics.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct PanicSite {
+    /// Why the call panics.
+    pub kind: PanicKind,
+
+    /// The demangled name of the called function, e.g. `core::option::unwrap_failed`.
+    pub callee: String,
+}
+
+/// The analysis of a single function.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FunctionReport {
+    /// The demangled function name.
+    pub name: String,
+
+    /// Every call to a panicking function, in order of appearance.
+    pub panics: Vec<PanicSite>,
+
+    /// The number of packed SIMD instructions, e.g. `paddd`. Always 0 for LLVM IR.
+    pub vector_instructions: usize,
+
+    /// The distinct LLVM vector types used, e.g. `<4 x i32>`. Always empty for assembly.
+    pub vector_types: Vec<String>,
+}
+
+impl FunctionReport {
+    fn new(name: String, callees: impl IntoIterator<Item = String>) -> Self {
+        let panics = callees
+            .into_iter()
+            .filter_map(|callee| {
+                PanicKind::from_callee(&callee).map(|kind| PanicSite { kind, callee })
+            })
+            .collect();
+        Self {
+            name,
+            panics,
+            vector_instructions: 0,
+            vector_types: Vec::new(),
+        }
+    }
+
+    /// Returns the number of panic sites of the given kind.
+    pub fn count(&self, kind: PanicKind) -> usize {
+        self.panics.iter().filter(|site| site.kind == kind).count()
+    }
+
+    /// Returns `true` if a bounds check is left in the function.
+    pub fn has_bounds_checks(&self) -> bool {
+        self.count(PanicKind::BoundsCheck) > 0
+    }
+
+    /// Returns `true` if an overflow check is left in the function.
+    pub fn has_overflow_checks(&self) -> bool {
+        self.count(PanicKind::Overflow) > 0
+    }
+
+    /// Returns `true` if the function can't panic by itself.
+    ///
+    /// Functions it calls may still panic.
+    pub fn is_panic_free(&self) -> bool {
+        self.panics.is_empty()
+    }
+
+    /// Returns `true` if the function uses SIMD instructions or vector types.
+    pub fn is_vectorized(&self) -> bool {
+        self.vector_instructions > 0 || !self.vector_types.is_empty()
+    }
+}
+
+/// The analysis of every function in a compilation output.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CodegenReport {
+    /// The functions in order of appearance.
+    pub functions: Vec<FunctionReport>,
+}
+
+impl CodegenReport {
+    /// Analyzes parsed assembly.
+    ///
+    /// Panic sites are the calls and tail calls to panicking functions. An
+    /// instruction counts as vectorized if it works on packed values in an `xmm`,
+    /// `ymm` or `zmm` register; moves, scalar floating point instructions and
+    /// zeroing idioms like `pxor %xmm0, %xmm0` don't count.
+    pub fn from_assembly(assembly: &Assembly) -> Self {
+        let functions = assembly
+            .functions
+            .iter()
+            .map(|function| {
+                let callees = function.instructions().filter_map(Instruction::call_target);
+                let mut report = FunctionReport::new(function.name.clone(), callees);
+                report.vector_instructions = function
+                    .instructions()
+                    .filter(|instruction| is_vector_instruction(instruction))
+                    .count();
+                report
+            })
+            .collect();
+        Self { functions }
+    }
+
+    /// Analyzes LLVM IR.
+    ///
+    /// Panic sites are the `call` and `invoke` instructions of panicking
+    /// functions. A function is vectorized if its body uses vector types like
+    /// `<4 x i32>`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use playground_api::codegen::CodegenReport;
+    ///
+    /// let ir = "define i32 @get() {\nstart:\n  call void @_ZN4core9panicking18panic_bounds_check17h0123456789abcdefE()\n  unreachable\n}\n";
+    /// let report = CodegenReport::from_llvm_ir(ir);
+    ///
+    /// assert!(report.functions[0].has_bounds_checks());
+    /// ```
+    pub fn from_llvm_ir(ir: &str) -> Self {
+        let functions = Module::parse(ir)
+            .functions
+            .iter()
+            .map(|function| {
+                let callees = function.instructions().filter_map(|i| i.callee.clone());
+                let mut report = FunctionReport::new(function.name.clone(), callees);
+                for instruction in function.instructions() {
+                    for vector_type in ir_vector_types(&instruction.text) {
+                        if !report.vector_types.contains(&vector_type) {
+                            report.vector_types.push(vector_type);
+                        }
+                    }
+                }
+                report
+            })
+            .collect();
+        Self { functions }
+    }
+
+    /// Analyzes compilation output, detecting whether it is LLVM IR or assembly.
+    pub fn parse_detect(output: &str) -> Self {
+        if output.lines().any(|line| line.starts_with("define ")) {
+            Self::from_llvm_ir(output)
+        } else {
+            Self::from_assembly(&Assembly::parse_detect(output))
+        }
+    }
+
+    /// Finds a function by its full name or a path suffix like `sum`.
+    pub fn function(&self, name: &str) -> Option<&FunctionReport> {
+        let suffix = format!("::{name}");
+        self.functions
+            .iter()
+            .find(|function| function.name == name)
+            .or_else(|| {
+                self.functions
+                    .iter()
+                    .find(|function| function.name.ends_with(&suffix))
+            })
+    }
+
+    /// Returns the functions with at least one bounds check.
+    pub fn bounds_checked(&self) -> impl Iterator<Item = &FunctionReport> {
+        self.functions
+            .iter()
+            .filter(|function| function.has_bounds_checks())
+    }
+
+    /// Returns the vectorized functions.
+    pub fn vectorized(&self) -> impl Iterator<Item = &FunctionReport> {
+        self.functions
+            .iter()
+            .filter(|function| function.is_vectorized())
+    }
+}
+
+/// Returns the vector types like `<4 x i32>` in a line of LLVM IR.
+fn ir_vector_types(line: &str) -> Vec<String> {
+    let mut types = Vec::new();
+    let mut rest = line;
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start..];
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+        let candidate = &rest[..=end];
+        let is_vector = candidate[1..]
+            .split_once(" x ")
+            .is_some_and(|(lanes, _)| lanes.trim_start_matches("vscale x ").parse::<u32>().is_ok());
+        if is_vector {
+            types.push(candidate.to_owned());
+        }
+        rest = &rest[1..];
+    }
+    types
+}
+
+/// Returns `true` for SIMD instructions on packed values.
+fn is_vector_instruction(instruction: &Instruction) -> bool {
+    let is_vector_register = |operand: &String| {
+        let register = operand.trim_start_matches('%');
+        ["xmm", "ymm", "zmm"]
+            .iter()
+            .any(|prefix| register.starts_with(prefix))
+    };
+    if !instruction.operands.iter().any(is_vector_register) {
+        return false;
+    }
+
+    let mnemonic = instruction.mnemonic.as_str();
+    let base = mnemonic.strip_prefix('v').unwrap_or(mnemonic);
+    let is_zeroing = matches!(base, "pxor" | "xorps" | "xorpd")
+        && instruction
+            .operands
+            .windows(2)
+            .all(|pair| pair[0] == pair[1]);
+    let is_packed = base.starts_with('p') || base.ends_with("ps") || base.ends_with("pd");
+    is_packed && !is_zeroing && !base.contains("mov")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn analyzes_release_ir() {
+        let report =
+            CodegenReport::from_llvm_ir(include_str!("../test-outputs/llvm-ir-release.ll"));
+        let names: Vec<_> = report.functions.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "playground::checked_sum",
+                "playground::first_or_zero",
+                "playground::get",
+                "playground::sum"
+            ]
+        );
+
+        let get = report.function("get").unwrap();
+        assert!(get.has_bounds_checks());
+        assert_eq!(get.panics[0].callee, "core::panicking::panic_bounds_check");
+        assert!(!get.is_vectorized());
+
+        let first = report.function("first_or_zero").unwrap();
+        assert_eq!(first.count(PanicKind::Unwrap), 1);
+
+        let sum = report.function("sum").unwrap();
+        assert!(sum.is_panic_free());
+        assert_eq!(sum.vector_types, ["<4 x i32>"]);
+        assert_eq!(report.vectorized().count(), 1);
+    }
+
+    #[test]
+    fn analyzes_debug_ir() {
+        let report = CodegenReport::from_llvm_ir(include_str!("../test-outputs/llvm-ir-debug.ll"));
+        let checked_sum = report.function("checked_sum").unwrap();
+        assert!(checked_sum.has_overflow_checks());
+        assert_eq!(
+            checked_sum.panics[0].callee,
+            "core::panicking::panic_const::panic_const_add_overflow"
+        );
+
+        let closure = report
+            .function("<u32 as core::iter::traits::accum::Sum<&u32>>::sum::{{closure}}")
+            .unwrap();
+        assert!(closure.has_overflow_checks());
+
+        let fold = report.function("fold").unwrap();
+        assert_eq!(fold.count(PanicKind::Panic), 1);
+        assert!(report.vectorized().next().is_none());
+    }
+
+    #[test]
+    fn analyzes_assembly() {
+        let report = CodegenReport::parse_detect(include_str!("../test-outputs/asm-att.s"));
+        assert_eq!(
+            report
+                .bounds_checked()
+                .map(|f| f.name.as_str())
+                .collect::<Vec<_>>(),
+            ["playground::get"]
+        );
+
+        // Five `paddd` and two `pshufd`, the `pxor` zeroing and moves don't count.
+        let sum = report.function("sum").unwrap();
+        assert_eq!(sum.vector_instructions, 7);
+        assert!(sum.is_vectorized());
+
+        let intel = CodegenReport::parse_detect(include_str!("../test-outputs/asm-intel.s"));
+        assert_eq!(intel.function("sum").unwrap().vector_instructions, 7);
+        assert_eq!(
+            intel.function("first_or_zero").unwrap().panics[0].kind,
+            PanicKind::Unwrap
+        );
+
+        let debug = CodegenReport::parse_detect(include_str!("../test-outputs/asm-debug-att.s"));
+        assert!(debug.function("checked_sum").unwrap().has_overflow_checks());
+        assert!(debug.vectorized().next().is_none());
+    }
+
+    #[test]
+    fn classifies_callees() {
+        for (callee, kind) in [
+            (
+                "core::panicking::panic_bounds_check",
+                Some(PanicKind::BoundsCheck),
+            ),
+            (
+                "core::slice::index::slice_end_index_len_fail",
+                Some(PanicKind::BoundsCheck),
+            ),
+            ("core::result::unwrap_failed", Some(PanicKind::Unwrap)),
+            ("core::option::expect_failed", Some(PanicKind::Unwrap)),
+            (
+                "core::panicking::panic_const::panic_const_mul_overflow",
+                Some(PanicKind::Overflow),
+            ),
+            (
+                "core::panicking::panic_const::panic_const_rem_by_zero",
+                Some(PanicKind::DivisionByZero),
+            ),
+            ("core::panicking::panic_fmt", Some(PanicKind::Panic)),
+            ("std::panicking::begin_panic", Some(PanicKind::Panic)),
+            ("playground::sum", None),
+        ] {
+            assert_eq!(PanicKind::from_callee(callee), kind, "{callee}");
+        }
+    }
+}
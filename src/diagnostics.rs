@@ -0,0 +1,1187 @@
+//! Structured rustc diagnostics parsed from human-readable compiler output.
+//!
+//! The playground only returns the rendered text rustc prints to stderr. [`parse`]
+//! turns that text back into [`Diagnostic`] values with their level, error code,
+//! spans, labels, notes and suggestions. Cargo's own status lines like
+//! `Compiling playground v0.0.1` are skipped.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// The severity of a diagnostic.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    /// A hard error which stops compilation.
+    Error,
+
+    /// A warning, including lints.
+    Warning,
+
+    /// A free-standing note.
+    Note,
+
+    /// A free-standing help message.
+    Help,
+}
+
+/// A range of source code, using 1-based lines and character columns.
+///
+/// The end column is exclusive, so a span covering a single character at column 5
+/// has `column: 5` and `end_column: 6`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// The file the span is in, e.g. `src/main.rs` for the submitted code.
+    pub file: String,
+
+    /// The line the span starts on.
+    pub line: usize,
+
+    /// The column the span starts at.
+    pub column: usize,
+
+    /// The line the span ends on.
+    pub end_line: usize,
+
+    /// The column right after the end of the span.
+    pub end_column: usize,
+}
+
+impl Span {
+    /// Returns `true` if the span points into the submitted code rather than into
+    /// the standard library or a dependency.
+    pub fn is_user_code(&self) -> bool {
+        self.file.starts_with("src/")
+    }
+}
+
+/// A marked piece of code with an optional message, e.g. "value moved here".
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Label {
+    /// The marked code.
+    pub span: Span,
+
+    /// The text printed next to the marker, if any.
+    pub message: Option<String>,
+
+    /// `true` for the primary (`^^^`) label, `false` for secondary (`---`) ones.
+    pub primary: bool,
+}
+
+/// How confident the compiler is that a suggestion is correct.
+///
+/// Human-readable output doesn't include the applicability rustc assigns, so it is
+/// estimated from the suggestion: replacements containing placeholders are
+/// [`Applicability::HasPlaceholders`], and only suggestions known to be applied
+/// automatically by rustc and Clippy, like adding `mut` or an `_` prefix, are
+/// [`Applicability::MachineApplicable`]. Suggestions worded as one option among
+/// several (e.g. "consider cloning", "consider importing") are
+/// [`Applicability::MaybeIncorrect`] and everything else is
+/// [`Applicability::Unspecified`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Applicability {
+    /// The suggestion is definitely what the user intended and can be applied automatically.
+    MachineApplicable,
+
+    /// The suggestion may be what the user intended, but it is uncertain.
+    MaybeIncorrect,
+
+    /// The suggestion contains placeholders like `/* value */` which must be filled in.
+    HasPlaceholders,
+
+    /// The applicability is unknown.
+    Unspecified,
+}
+
+/// A single text replacement which is part of a [`Suggestion`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Edit {
+    /// The code to replace. An empty span means the replacement is inserted.
+    pub span: Span,
+
+    /// The replacement text.
+    pub replacement: String,
+}
+
+/// A code change suggested by the compiler.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Suggestion {
+    /// The help message describing the change.
+    pub message: String,
+
+    /// The replacements making up the change.
+    pub edits: Vec<Edit>,
+
+    /// How confident the compiler is that the change is correct.
+    pub applicability: Applicability,
+}
+
+/// A single error, warning or note emitted by rustc or Clippy.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The severity of the diagnostic.
+    pub level: Level,
+
+    /// The error or lint code, e.g. `E0382`.
+    pub code: Option<String>,
+
+    /// The main message, e.g. "borrow of moved value: `v`".
+    pub message: String,
+
+    /// The primary location the diagnostic points at.
+    pub span: Option<Span>,
+
+    /// Every marked piece of code shown in the diagnostic.
+    pub labels: Vec<Label>,
+
+    /// The `note` messages attached to the diagnostic.
+    pub notes: Vec<String>,
+
+    /// The `help` messages attached to the diagnostic.
+    pub help: Vec<String>,
+
+    /// The code changes suggested by the compiler.
+    pub suggestions: Vec<Suggestion>,
+}
+
+impl Diagnostic {
+    fn new(level: Level, code: Option<String>, message: String) -> Self {
+        Self {
+            level,
+            code,
+            message,
+            span: None,
+            labels: Vec::new(),
+            notes: Vec::new(),
+            help: Vec::new(),
+            suggestions: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if the diagnostic is an error.
+    pub fn is_error(&self) -> bool {
+        self.level == Level::Error
+    }
+}
+
+/// Parses the human-readable output of rustc (or Clippy) into diagnostics.
+///
+/// Lines which don't belong to a diagnostic, like cargo's progress output or the
+/// final "could not compile" summary, are ignored.
+///
+/// rustc renders each tab as four spaces, so the markers on lines with tabs are
+/// shifted. Without the code only the line a `-->` location points at can be
+/// corrected, and suggestions touching other lines of a file with tabs are dropped.
+/// Use [`parse_with_code`] to get exact columns everywhere.
+///
+/// # Example
+///
+/// ```
+/// use playground_api::diagnostics::{parse, Level};
+///
+/// let stderr = "error[E0425]: cannot find value `x` in this scope\n --> src/main.rs:1:13\n";
+/// let diagnostics = parse(stderr);
+///
+/// assert_eq!(diagnostics[0].level, Level::Error);
+/// assert_eq!(diagnostics[0].code.as_deref(), Some("E0425"));
+/// assert_eq!(diagnostics[0].span.as_ref().unwrap().line, 1);
+/// ```
+pub fn parse(stderr: &str) -> Vec<Diagnostic> {
+    Parser::default().parse(stderr)
+}
+
+/// Parses the output of compiling `code` like [`parse`], mapping the columns of
+/// every span in `src/` back through the tab expansion of its line.
+///
+/// # Example
+///
+/// ```
+/// use playground_api::diagnostics::parse_with_code;
+///
+/// let code = "fn main() {\n\tlet x: i32 = \"x\";\n}\n";
+/// let stderr = "error[E0308]: mismatched types\n --> src/main.rs:2:15\n  |\n2 |     let x: i32 = \"x\";\n  |            ---   ^^^ expected `i32`, found `&str`\n";
+/// let diagnostics = parse_with_code(stderr, code);
+///
+/// let label = &diagnostics[0].labels[0];
+/// assert_eq!((label.span.column, label.span.end_column), (9, 12));
+/// ```
+pub fn parse_with_code(stderr: &str, code: &str) -> Vec<Diagnostic> {
+    let mut parser = Parser::default();
+    parser.columns.code = Some(code.lines().collect());
+    parser.parse(stderr)
+}
+
+/// Which part of a diagnostic the parser is currently in.
+enum Section {
+    /// The main message and its code snippet.
+    Main,
+
+    /// A `help:` sub-diagnostic, which may show a suggestion.
+    Help(PendingSuggestion),
+
+    /// A `note:` sub-diagnostic.
+    Note,
+}
+
+/// A suggestion whose snippet is still being read.
+#[derive(Default)]
+struct PendingSuggestion {
+    message: String,
+    edits: Vec<Edit>,
+    /// The line number and text of the last displayed (already changed) source line.
+    displayed: Option<(usize, String)>,
+    removed: Vec<(usize, String)>,
+    added: Vec<(usize, String)>,
+    /// Whether an edit was dropped because its columns couldn't be mapped.
+    unmapped: bool,
+}
+
+/// A run of `^` or `-` markers whose label text is printed on a later line.
+struct PendingLabel {
+    index: usize,
+    column: usize,
+}
+
+/// Maps the columns of rendered snippets back to source columns.
+///
+/// rustc renders each tab as four spaces, which moves the markers on lines with
+/// tabs to the right of the code they point at.
+#[derive(Default)]
+struct Columns<'a> {
+    /// The lines of the submitted code, if known.
+    code: Option<Vec<&'a str>>,
+    /// How far the rendered columns are shifted on lines a `-->` location points at.
+    anchors: HashMap<(String, usize), usize>,
+    /// The files known to contain tabs.
+    tabbed: HashSet<String>,
+}
+
+impl Columns<'_> {
+    /// Records the rendered column of the location a `-->` line points at.
+    fn anchor(&mut self, file: &str, line: usize, column: usize, rendered: usize) {
+        let shift = rendered.saturating_sub(column);
+        if shift > 0 {
+            self.tabbed.insert(file.to_owned());
+        }
+        self.anchors.entry((file.to_owned(), line)).or_insert(shift);
+    }
+
+    /// Returns the source column for a rendered column, or `None` if the line may
+    /// contain tabs which can't be accounted for.
+    fn source(&self, file: &str, line: usize, rendered: usize) -> Option<usize> {
+        let text = self
+            .code
+            .as_ref()
+            .filter(|_| file.starts_with("src/"))
+            .and_then(|code| code.get(line.checked_sub(1)?));
+        if let Some(text) = text {
+            return Some(unexpand_tabs(text, rendered));
+        }
+        if let Some(shift) = self.anchors.get(&(file.to_owned(), line)) {
+            return Some(rendered.saturating_sub(*shift).max(1));
+        }
+        (!self.tabbed.contains(file)).then_some(rendered)
+    }
+}
+
+#[derive(Default)]
+struct Parser<'a> {
+    diagnostics: Vec<Diagnostic>,
+    current: Option<(Diagnostic, Section)>,
+    file: String,
+    line: usize,
+    /// The original source lines shown in the main snippet, used to resolve
+    /// replacements which only show the changed line.
+    source: Vec<(usize, String)>,
+    /// The lines of the main snippet which haven't been parsed yet. They are parsed
+    /// together since the margin drawn for multi-line spans is only known at the end.
+    snippet: Vec<(Option<usize>, String)>,
+    pending_labels: Vec<PendingLabel>,
+    /// The start line and column of multi-line spans whose end hasn't been read yet.
+    open_spans: Vec<(usize, usize)>,
+    columns: Columns<'a>,
+}
+
+impl Parser<'_> {
+    fn parse(mut self, stderr: &str) -> Vec<Diagnostic> {
+        for line in stderr.lines() {
+            self.line(line);
+        }
+        self.finish()
+    }
+
+    fn line(&mut self, line: &str) {
+        let gutter = parse_gutter(line);
+        if matches!(self.current, Some((_, Section::Main))) {
+            if let Some((number, '|', content)) = gutter {
+                self.snippet.push((number, content.to_owned()));
+                return;
+            }
+            if line.trim() == "..." {
+                return;
+            }
+        }
+        self.flush_snippet();
+
+        if let Some((level, code, message)) = parse_header(line) {
+            self.header(level, code, message);
+            return;
+        }
+        if self.current.is_none() {
+            return;
+        }
+
+        let trimmed = line.trim_start();
+        if let Some(location) = trimmed
+            .strip_prefix("--> ")
+            .or_else(|| trimmed.strip_prefix("::: "))
+        {
+            self.location(location);
+        } else if let Some(note) = trimmed.strip_prefix("= note: ") {
+            self.diagnostic().notes.push(note.to_owned());
+        } else if let Some(help) = trimmed.strip_prefix("= help: ") {
+            self.diagnostic().help.push(help.to_owned());
+        } else if let Some((number, marker, content)) = gutter {
+            self.gutter(number, marker, content);
+        } else {
+            self.finish_current();
+        }
+    }
+
+    fn header(&mut self, level: Level, code: Option<String>, message: String) {
+        let is_sub = matches!(level, Level::Help | Level::Note) && self.current.is_some();
+        if is_sub {
+            self.finish_section();
+            let (diagnostic, section) = self.current.as_mut().expect("checked above");
+            if level == Level::Help {
+                diagnostic.help.push(message.clone());
+                *section = Section::Help(PendingSuggestion {
+                    message,
+                    ..PendingSuggestion::default()
+                });
+            } else {
+                diagnostic.notes.push(message);
+                *section = Section::Note;
+            }
+            return;
+        }
+
+        self.finish_current();
+        if !is_cargo_summary(&message) {
+            self.current = Some((Diagnostic::new(level, code, message), Section::Main));
+        }
+    }
+
+    fn location(&mut self, location: &str) {
+        let mut parts = location.rsplitn(3, ':');
+        let column = parts.next().and_then(|c| c.parse().ok());
+        let line = parts.next().and_then(|l| l.parse().ok());
+        let (Some(column), Some(line), Some(file)) = (column, line, parts.next()) else {
+            return;
+        };
+
+        self.file = file.to_owned();
+        self.line = line;
+        self.open_spans.clear();
+        let span = Span {
+            file: self.file.clone(),
+            line,
+            column,
+            end_line: line,
+            end_column: column,
+        };
+
+        let (diagnostic, section) = self.current.as_mut().expect("checked by caller");
+        if matches!(section, Section::Main) && diagnostic.span.is_none() {
+            diagnostic.span = Some(span);
+        }
+    }
+
+    /// Handles a snippet line of a `help:` section.
+    fn gutter(&mut self, number: Option<usize>, marker: char, content: &str) {
+        let source = &self.source;
+        let columns = &self.columns;
+        let file = self.file.clone();
+        let Some((_, Section::Help(pending))) = self.current.as_mut() else {
+            return;
+        };
+        match (number, marker) {
+            (Some(number), '|') => {
+                pending.flush_hunk(&file, columns);
+                pending.displayed = Some((number, content.to_owned()));
+            }
+            (Some(number), '-') => pending.removed.push((number, content.to_owned())),
+            (Some(number), '+') => pending.added.push((number, content.to_owned())),
+            (None, '|') => pending.marker_line(content, source, &file, columns),
+            _ => {}
+        }
+    }
+
+    /// Parses the buffered lines of the main snippet.
+    ///
+    /// If the snippet shows a multi-line span, every line starts with a margin for
+    /// the `|` rail connecting its start and end, which is split off first.
+    fn flush_snippet(&mut self) {
+        let lines = std::mem::take(&mut self.snippet);
+        let margin = lines
+            .iter()
+            .filter(|(number, _)| number.is_none())
+            .filter_map(|(_, content)| rail_margin(content))
+            .max()
+            .unwrap_or(0);
+
+        for (number, content) in lines {
+            let split = content
+                .char_indices()
+                .nth(margin)
+                .map_or(content.len(), |(idx, _)| idx);
+            let (rail, content) = content.split_at(split);
+            match number {
+                Some(number) => self.source_line(number, rail, content),
+                None => self.marker_line(rail, content),
+            }
+        }
+    }
+
+    /// Records a source line of the main snippet. A `/` in the margin starts a
+    /// multi-line span at the first non-whitespace character.
+    fn source_line(&mut self, number: usize, rail: &str, content: &str) {
+        self.line = number;
+        self.source.push((number, content.to_owned()));
+        self.pending_labels.clear();
+
+        if rail.contains('/') {
+            let rendered = content.chars().take_while(|c| c.is_whitespace()).count() + 1;
+            let column = self.columns.source(&self.file, number, rendered);
+            self.open_spans.push((number, column.unwrap_or(rendered)));
+        }
+    }
+
+    /// Parses a line of `^^^`/`---` markers below a source line in the main snippet.
+    fn marker_line(&mut self, rail: &str, content: &str) {
+        if rail.ends_with('_') {
+            self.multiline_marker(rail, content);
+            return;
+        }
+
+        let first = content.trim_start().chars().next();
+        if !matches!(first, Some('^' | '-')) {
+            self.attach_pending_labels(content);
+            return;
+        }
+
+        let file = self.file.clone();
+        let line = self.line;
+        let runs = marker_runs(content, &['^', '-']);
+        let diagnostic = &mut self.current.as_mut().expect("checked by caller").0;
+        self.pending_labels.clear();
+
+        let main = diagnostic
+            .span
+            .as_ref()
+            .filter(|main| main.file == file && main.line == line);
+        if let Some(main) = main {
+            let primary = runs
+                .iter()
+                .find(|run| run.marker == '^' && run.start + 1 >= main.column);
+            if let Some(run) = primary {
+                self.columns.anchor(&file, line, main.column, run.start + 1);
+            }
+        }
+
+        for (idx, run) in runs.iter().enumerate() {
+            let column = self.columns.source(&file, line, run.start + 1);
+            let end_column = self.columns.source(&file, line, run.end + 1);
+            let span = Span {
+                file: file.clone(),
+                line,
+                column: column.unwrap_or(run.start + 1),
+                end_line: line,
+                end_column: end_column.unwrap_or(run.end + 1),
+            };
+            let is_mapped = column.is_some() && end_column.is_some();
+            let primary = run.marker == '^';
+            let is_last = idx == runs.len() - 1;
+            let text = if is_last { run.text.clone() } else { None };
+
+            if let Some(help) = text.as_deref().and_then(|t| t.strip_prefix("help: ")) {
+                diagnostic.help.push(help.to_owned());
+                let suggestion = split_inline_suggestion(help).filter(|_| is_mapped);
+                if let Some((message, code)) = suggestion {
+                    diagnostic.suggestions.push(Suggestion {
+                        applicability: estimate_applicability(message, &code),
+                        message: message.to_owned(),
+                        edits: vec![Edit {
+                            span: span.clone(),
+                            replacement: code,
+                        }],
+                    });
+                }
+            }
+
+            if primary {
+                if let Some(main) = diagnostic
+                    .span
+                    .as_mut()
+                    .filter(|s| s.line == line && s.column == span.column)
+                {
+                    main.end_column = span.end_column;
+                }
+            }
+
+            if !is_last {
+                self.pending_labels.push(PendingLabel {
+                    index: diagnostic.labels.len(),
+                    column: run.start,
+                });
+            }
+            let is_inline_help = text.as_deref().is_some_and(|t| t.starts_with("help: "));
+            diagnostic.labels.push(Label {
+                span,
+                message: text.filter(|_| !is_inline_help),
+                primary,
+            });
+        }
+    }
+
+    /// Parses the `___-` line marking the start or the `|___-` line marking the end
+    /// of a multi-line span, which points at its first or last character.
+    fn multiline_marker(&mut self, rail: &str, content: &str) {
+        let underscores = content.chars().take_while(|&c| c == '_').count();
+        let mut rest = content.chars().skip(underscores);
+        let Some(marker @ ('^' | '-')) = rest.next() else {
+            return;
+        };
+        let (file, line) = (self.file.clone(), self.line);
+        let rendered = underscores + 1;
+
+        if rail.chars().rev().nth(1) != Some('|') {
+            let column = self.columns.source(&file, line, rendered);
+            self.open_spans.push((line, column.unwrap_or(rendered)));
+            return;
+        }
+        let Some((start_line, column)) = self.open_spans.pop() else {
+            return;
+        };
+
+        let end_column = self.columns.source(&file, line, rendered + 1);
+        let span = Span {
+            file,
+            line: start_line,
+            column,
+            end_line: line,
+            end_column: end_column.unwrap_or(rendered + 1),
+        };
+        let primary = marker == '^';
+        let text: String = rest.collect();
+        let text = text.trim();
+
+        let diagnostic = self.diagnostic();
+        if primary {
+            if let Some(main) = diagnostic
+                .span
+                .as_mut()
+                .filter(|s| s.line == span.line && s.column == span.column)
+            {
+                main.end_line = span.end_line;
+                main.end_column = span.end_column;
+            }
+        }
+        diagnostic.labels.push(Label {
+            span,
+            message: (!text.is_empty()).then(|| text.to_owned()),
+            primary,
+        });
+    }
+
+    /// Assigns text printed below a marker line to the markers it belongs to.
+    fn attach_pending_labels(&mut self, content: &str) {
+        let diagnostic = &mut self.current.as_mut().expect("checked by caller").0;
+        self.pending_labels.retain(|pending| {
+            let text: String = content.chars().skip(pending.column).collect();
+            if text.is_empty() || text.starts_with('|') || text.starts_with(' ') {
+                return true;
+            }
+            diagnostic.labels[pending.index].message = Some(text.trim_end().to_owned());
+            false
+        });
+    }
+
+    fn diagnostic(&mut self) -> &mut Diagnostic {
+        &mut self.current.as_mut().expect("checked by caller").0
+    }
+
+    /// Completes the current `help:` section, turning it into a suggestion if it had edits.
+    fn finish_section(&mut self) {
+        let file = self.file.clone();
+        let columns = &self.columns;
+        let Some((diagnostic, section)) = self.current.as_mut() else {
+            return;
+        };
+        if let Section::Help(pending) = section {
+            pending.flush_hunk(&file, columns);
+            if !pending.edits.is_empty() && !pending.unmapped {
+                let edits = std::mem::take(&mut pending.edits);
+                let replacement: String = edits.iter().map(|e| e.replacement.as_str()).collect();
+                diagnostic.suggestions.push(Suggestion {
+                    applicability: estimate_applicability(&pending.message, &replacement),
+                    message: pending.message.clone(),
+                    edits,
+                });
+            }
+        }
+        *section = Section::Main;
+    }
+
+    fn finish_current(&mut self) {
+        self.flush_snippet();
+        self.finish_section();
+        if let Some((diagnostic, _)) = self.current.take() {
+            self.diagnostics.push(diagnostic);
+        }
+        self.source.clear();
+        self.pending_labels.clear();
+        self.open_spans.clear();
+    }
+
+    fn finish(mut self) -> Vec<Diagnostic> {
+        self.finish_current();
+        self.diagnostics
+    }
+}
+
+impl PendingSuggestion {
+    /// Parses the `+++` (inserted) or `~~~` (replaced) markers below a displayed line.
+    ///
+    /// If the original line was shown in the main snippet, the edit is computed by
+    /// comparing both versions, since the markers don't cover inserted whitespace.
+    /// Otherwise the inserted text is taken from below the `+` markers.
+    fn marker_line(
+        &mut self,
+        content: &str,
+        source: &[(usize, String)],
+        file: &str,
+        columns: &Columns,
+    ) {
+        let Some((line, displayed)) = self.displayed.take() else {
+            return;
+        };
+        let span = |column, end_column| {
+            Some(Span {
+                file: file.to_owned(),
+                line,
+                column: columns.source(file, line, column)?,
+                end_line: line,
+                end_column: columns.source(file, line, end_column)?,
+            })
+        };
+
+        if let Some((_, original)) = source.iter().find(|(number, _)| *number == line) {
+            let original: Vec<char> = original.chars().collect();
+            let displayed: Vec<char> = displayed.chars().collect();
+            let prefix = original
+                .iter()
+                .zip(&displayed)
+                .take_while(|(a, b)| a == b)
+                .count();
+            let suffix = original[prefix..]
+                .iter()
+                .rev()
+                .zip(displayed[prefix..].iter().rev())
+                .take_while(|(a, b)| a == b)
+                .count();
+
+            let replacement = displayed[prefix..displayed.len() - suffix].iter().collect();
+            self.push_edit(span(prefix + 1, original.len() - suffix + 1), replacement);
+            return;
+        }
+
+        let runs = marker_runs(content, &['+', '~']);
+        if runs.iter().any(|run| run.marker == '~') {
+            return;
+        }
+
+        let displayed: Vec<char> = displayed.chars().collect();
+        let mut inserted = 0;
+        for run in runs {
+            let end = run.end.min(displayed.len());
+            let start = run.start.min(end);
+            let column = start - inserted + 1;
+            self.push_edit(span(column, column), displayed[start..end].iter().collect());
+            inserted += end - start;
+        }
+    }
+
+    /// Adds an edit, or marks the suggestion as unusable if its span is unknown.
+    fn push_edit(&mut self, span: Option<Span>, replacement: String) {
+        match span {
+            Some(span) => self.edits.push(Edit { span, replacement }),
+            None => self.unmapped = true,
+        }
+    }
+
+    /// Turns collected `-`/`+` diff lines into an edit.
+    ///
+    /// Whole lines are replaced, so an end column which can't be mapped only
+    /// overshoots the end of the line.
+    fn flush_hunk(&mut self, file: &str, columns: &Columns) {
+        if self.removed.is_empty() && self.added.is_empty() {
+            return;
+        }
+        let removed = std::mem::take(&mut self.removed);
+        let added = std::mem::take(&mut self.added);
+        let added_text: Vec<&str> = added.iter().map(|(_, text)| text.as_str()).collect();
+
+        let edit = match (removed.first(), removed.last()) {
+            (Some((first, _)), Some((last, last_text))) => {
+                let end_column = last_text.chars().count() + 1;
+                Edit {
+                    span: Span {
+                        file: file.to_owned(),
+                        line: *first,
+                        column: 1,
+                        end_line: *last,
+                        end_column: columns
+                            .source(file, *last, end_column)
+                            .unwrap_or(end_column),
+                    },
+                    replacement: added_text.join("\n"),
+                }
+            }
+            _ => {
+                let line = added.first().map_or(1, |(number, _)| *number);
+                Edit {
+                    span: Span {
+                        file: file.to_owned(),
+                        line,
+                        column: 1,
+                        end_line: line,
+                        end_column: 1,
+                    },
+                    replacement: format!("{}\n", added_text.join("\n")),
+                }
+            }
+        };
+        self.edits.push(edit);
+    }
+}
+
+/// A run of identical marker characters in a marker line.
+struct MarkerRun {
+    marker: char,
+    /// 0-based character offset of the first marker.
+    start: usize,
+    /// 0-based character offset right after the last marker.
+    end: usize,
+    /// The text following the run, only set for the last run on a line.
+    text: Option<String>,
+}
+
+/// Splits a marker line like `------   ^ expected ...` into its runs.
+fn marker_runs(content: &str, markers: &[char]) -> Vec<MarkerRun> {
+    let chars: Vec<char> = content.chars().collect();
+    let mut runs: Vec<MarkerRun> = Vec::new();
+    let mut idx = 0;
+
+    while idx < chars.len() {
+        let c = chars[idx];
+        if c == ' ' {
+            idx += 1;
+        } else if markers.contains(&c) {
+            let start = idx;
+            while idx < chars.len() && chars[idx] == c {
+                idx += 1;
+            }
+            runs.push(MarkerRun {
+                marker: c,
+                start,
+                end: idx,
+                text: None,
+            });
+        } else {
+            let text: String = chars[idx..].iter().collect();
+            if let Some(last) = runs.last_mut() {
+                last.text = Some(text.trim_end().to_owned());
+            }
+            break;
+        }
+    }
+
+    runs
+}
+
+/// Returns the width of the margin drawn for multi-line spans, detected from the
+/// `|_` rail leading to the end of such a span.
+fn rail_margin(content: &str) -> Option<usize> {
+    let idx = content.find("|_")?;
+    content[..idx]
+        .chars()
+        .all(|c| matches!(c, ' ' | '|'))
+        .then_some(idx + 2)
+}
+
+/// Converts a 1-based column of a line rendered with four spaces per tab into a
+/// column of the line itself.
+fn unexpand_tabs(line: &str, rendered: usize) -> usize {
+    let offset = rendered.saturating_sub(1);
+    let mut width = 0;
+    for (idx, c) in line.chars().enumerate() {
+        let next = width + if c == '\t' { 4 } else { 1 };
+        if offset < next {
+            return idx + 1;
+        }
+        width = next;
+    }
+    line.chars().count() + offset - width + 1
+}
+
+/// Parses a line like `error[E0382]: borrow of moved value: `v``.
+fn parse_header(line: &str) -> Option<(Level, Option<String>, String)> {
+    let (head, message) = line.split_once(": ")?;
+    let (level, code) = match head.split_once('[') {
+        Some((level, code)) => (level, Some(code.strip_suffix(']')?.to_owned())),
+        None => (head, None),
+    };
+    let level = match level {
+        "error" => Level::Error,
+        "warning" => Level::Warning,
+        "note" => Level::Note,
+        "help" => Level::Help,
+        _ => return None,
+    };
+    Some((level, code, message.to_owned()))
+}
+
+/// Parses the gutter of a snippet line like `12 |     code`, `  |  ^^^` or `3 + code`.
+fn parse_gutter(line: &str) -> Option<(Option<usize>, char, &str)> {
+    let rest = line.trim_start();
+    let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let number = rest[..digits].parse().ok();
+    let rest = rest[digits..].trim_start();
+
+    let marker = rest.chars().next()?;
+    let allowed = if number.is_some() {
+        ['|', '+', '-', '~'].as_slice()
+    } else {
+        ['|'].as_slice()
+    };
+    if !allowed.contains(&marker) {
+        return None;
+    }
+    let content = &rest[1..];
+    if !content.is_empty() && !content.starts_with(' ') {
+        return None;
+    }
+    Some((number, marker, content.strip_prefix(' ').unwrap_or(content)))
+}
+
+/// Splits an inline suggestion like "prefix it with an underscore: `_w`".
+fn split_inline_suggestion(help: &str) -> Option<(&str, String)> {
+    let (message, code) = help.rsplit_once(": `")?;
+    Some((message, code.strip_suffix('`')?.to_owned()))
+}
+
+/// Estimates the applicability rustc assigned to a suggestion from its message.
+fn estimate_applicability(message: &str, replacement: &str) -> Applicability {
+    const MACHINE_APPLICABLE: [&str; 9] = [
+        "consider making this binding mutable",
+        "consider changing this to be mutable",
+        "prefix it with an underscore",
+        "remove this `mut`",
+        "remove the unnecessary",
+        "remove the whole `use` item",
+        "remove `return`",
+        "using `is_empty` is clearer",
+        "you can use an array directly",
+    ];
+    const UNCERTAIN: [&str; 7] = [
+        "cloning",
+        "consider importing",
+        "you might",
+        "if you meant",
+        "alternatively",
+        "one of these",
+        "conversion method",
+    ];
+
+    if replacement.contains("/*") || replacement.contains("...") {
+        Applicability::HasPlaceholders
+    } else if MACHINE_APPLICABLE
+        .iter()
+        .any(|phrase| message.contains(phrase))
+    {
+        Applicability::MachineApplicable
+    } else if UNCERTAIN.iter().any(|phrase| message.contains(phrase)) {
+        Applicability::MaybeIncorrect
+    } else {
+        Applicability::Unspecified
+    }
+}
+
+/// Returns `true` for summary lines like "could not compile `playground`" which
+/// cargo and rustc print with an `error:`/`warning:` prefix.
+fn is_cargo_summary(message: &str) -> bool {
+    message.starts_with("could not compile")
+        || message.starts_with("aborting due to")
+        || message.starts_with("build failed")
+        || message.starts_with("`playground` (")
+}
+
+/// Applies the edits of the given suggestions to `code`.
+///
+/// A suggestion is applied as a whole or not at all: suggestions with an edit
+/// outside the submitted code or overlapping an edit of an earlier suggestion are
+/// skipped. Returns the new code and the indices of the applied suggestions.
+pub(crate) fn apply(code: &str, suggestions: &[&Suggestion]) -> (String, Vec<usize>) {
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(code.match_indices('\n').map(|(idx, _)| idx + 1))
+        .collect();
+    let offset = |line: usize, column: usize| {
+        let start = *line_starts.get(line.checked_sub(1)?)?;
+        let end = line_starts.get(line).map_or(code.len(), |next| next - 1);
+        let text = &code[start..end];
+        let within = text
+            .char_indices()
+            .nth(column.checked_sub(1)?)
+            .map_or(text.len(), |(idx, _)| idx);
+        Some(start + within)
+    };
+
+    let mut accepted: Vec<(usize, usize, &str)> = Vec::new();
+    let mut applied = Vec::new();
+    'suggestions: for (idx, suggestion) in suggestions.iter().enumerate() {
+        let mut edits = Vec::new();
+        for edit in &suggestion.edits {
+            let span = &edit.span;
+            let (Some(start), Some(end)) = (
+                offset(span.line, span.column),
+                offset(span.end_line, span.end_column),
+            ) else {
+                continue 'suggestions;
+            };
+            if !span.is_user_code() || start > end {
+                continue 'suggestions;
+            }
+            edits.push((start, end, edit.replacement.as_str()));
+        }
+
+        let overlaps = |(start, end, _): &(usize, usize, &str)| {
+            accepted
+                .iter()
+                .any(|(s, e, _)| (start < e && s < end) || start == s)
+        };
+        if edits.is_empty() || edits.iter().any(overlaps) {
+            continue;
+        }
+        accepted.extend(edits);
+        applied.push(idx);
+    }
+
+    accepted.sort_by_key(|(start, _, _)| std::cmp::Reverse(*start));
+    let mut fixed = code.to_owned();
+    for (start, end, replacement) in accepted {
+        fixed.replace_range(start..end, replacement);
+    }
+    (fixed, applied)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn errors() {
+        let diagnostics = parse(include_str!("../test-outputs/errors.stderr"));
+        assert_eq!(diagnostics.len(), 4);
+
+        let missing = &diagnostics[0];
+        assert_eq!(missing.level, Level::Error);
+        assert_eq!(missing.code.as_deref(), Some("E0425"));
+        assert_eq!(missing.message, "cannot find type `HashSet` in this scope");
+        let span = missing.span.as_ref().unwrap();
+        assert_eq!((span.line, span.column, span.end_column), (11, 12, 19));
+        assert_eq!(
+            missing.labels[0].message.as_deref(),
+            Some("not found in this scope")
+        );
+        assert_eq!(missing.help, vec!["consider importing this struct"]);
+
+        let import = &missing.suggestions[0];
+        assert_eq!(import.applicability, Applicability::MaybeIncorrect);
+        assert_eq!(import.edits[0].span.line, 1);
+        assert_eq!(
+            import.edits[0].replacement,
+            "use std::collections::HashSet;\n"
+        );
+
+        let unused = &diagnostics[2];
+        assert_eq!(unused.level, Level::Warning);
+        assert_eq!(unused.code, None);
+        assert_eq!(unused.notes.len(), 1);
+
+        let mismatched = &diagnostics[3];
+        assert_eq!(mismatched.labels.len(), 2);
+        assert_eq!(
+            mismatched.labels[0].message.as_deref(),
+            Some("expected due to this")
+        );
+        assert!(!mismatched.labels[0].primary);
+        assert_eq!(
+            mismatched.labels[1].message.as_deref(),
+            Some("expected `String`, found integer")
+        );
+        let edit = &mismatched.suggestions[0].edits[0];
+        assert_eq!((edit.span.line, edit.span.column), (10, 22));
+        assert_eq!(edit.replacement, ".to_string()");
+    }
+
+    #[test]
+    fn borrow_errors() {
+        let diagnostics = parse(include_str!("../test-outputs/borrow.stderr"));
+        assert_eq!(diagnostics.len(), 6);
+
+        let reassign = &diagnostics[0];
+        assert_eq!(reassign.code.as_deref(), Some("E0384"));
+        assert_eq!(reassign.labels.len(), 2);
+        let edit = &reassign.suggestions[0].edits[0];
+        assert_eq!((edit.span.line, edit.span.column), (2, 9));
+        assert_eq!(edit.replacement, "mut ");
+        assert_eq!(
+            reassign.suggestions[0].applicability,
+            Applicability::MachineApplicable
+        );
+
+        let moved = &diagnostics[1];
+        assert_eq!(moved.labels.len(), 3);
+        assert_eq!(
+            moved.suggestions[0].applicability,
+            Applicability::MaybeIncorrect
+        );
+
+        let unused = &diagnostics[3];
+        assert_eq!(unused.suggestions[0].edits[0].replacement, "_w");
+        assert_eq!(unused.suggestions[0].edits[0].span.column, 9);
+        assert_eq!(
+            unused.suggestions[0].applicability,
+            Applicability::MachineApplicable
+        );
+        assert_eq!(
+            estimate_applicability("consider adding a leading `&`", "&x"),
+            Applicability::Unspecified
+        );
+
+        let assigned = &diagnostics[5];
+        assert_eq!(
+            assigned.help,
+            vec!["maybe it is overwritten before being read?"]
+        );
+    }
+
+    #[test]
+    fn tab_indented_code() {
+        let stderr = include_str!("../test-outputs/tabs.stderr");
+        let code = include_str!("../test-outputs/tabs.rs");
+        let diagnostics = parse_with_code(stderr, code);
+        assert_eq!(diagnostics.len(), 3);
+
+        let reassign = &diagnostics[0];
+        let span = reassign.span.as_ref().unwrap();
+        assert_eq!((span.line, span.column, span.end_column), (4, 3, 8));
+        let first = &reassign.labels[0].span;
+        assert_eq!((first.line, first.column, first.end_column), (2, 6, 7));
+        let edit = &reassign.suggestions[0].edits[0];
+        assert_eq!((edit.span.line, edit.span.column), (2, 6));
+        assert_eq!(edit.replacement, "mut ");
+
+        let moved = &diagnostics[1];
+        assert_eq!(moved.span.as_ref().unwrap().column, 19);
+        assert_eq!(moved.labels[2].span.column, 19);
+        let edit = &moved.suggestions[0].edits[0];
+        assert_eq!((edit.span.line, edit.span.column), (8, 11));
+        assert_eq!(edit.replacement, ".clone()");
+
+        let unused = &diagnostics[2];
+        let edit = &unused.suggestions[0].edits[0];
+        assert_eq!(
+            (edit.span.line, edit.span.column, edit.span.end_column),
+            (8, 6, 7)
+        );
+
+        // Without the code only the lines `-->` points at can be mapped.
+        let diagnostics = parse(stderr);
+        let span = diagnostics[0].span.as_ref().unwrap();
+        assert_eq!((span.line, span.column, span.end_column), (4, 3, 8));
+        assert_eq!(diagnostics[0].labels[1].span.column, 3);
+        assert!(diagnostics[0].suggestions.is_empty());
+        assert!(diagnostics[1].suggestions.is_empty());
+        let edit = &diagnostics[2].suggestions[0].edits[0];
+        assert_eq!((edit.span.line, edit.span.column), (8, 6));
+    }
+
+    #[test]
+    fn multiline_spans() {
+        let diagnostics = parse(include_str!("../test-outputs/multiline.stderr"));
+        assert_eq!(diagnostics.len(), 2);
+
+        let arms = &diagnostics[0];
+        let span = arms.span.as_ref().unwrap();
+        assert_eq!((span.line, span.column, span.end_column), (4, 14, 20));
+        let labels: Vec<_> = arms
+            .labels
+            .iter()
+            .map(|label| {
+                let span = &label.span;
+                (
+                    span.line,
+                    span.column,
+                    span.end_line,
+                    span.end_column,
+                    label.primary,
+                )
+            })
+            .collect();
+        assert_eq!(
+            labels,
+            [
+                (3, 14, 3, 15, false),
+                (4, 14, 4, 20, true),
+                (2, 18, 5, 6, false),
+            ]
+        );
+        assert_eq!(
+            arms.labels[2].message.as_deref(),
+            Some("`match` arms have incompatible types")
+        );
+        assert_eq!(
+            arms.labels[1].message.as_deref(),
+            Some("expected `u32`, found `&str`")
+        );
+
+        let branches = &diagnostics[1];
+        let whole = branches.labels.last().unwrap();
+        let span = &whole.span;
+        assert_eq!(
+            (span.line, span.column, span.end_line, span.end_column),
+            (10, 5, 14, 6)
+        );
+        assert_eq!(
+            whole.message.as_deref(),
+            Some("`if` and `else` have incompatible types")
+        );
+        assert_eq!(branches.labels[0].span.column, 9);
+    }
+
+    #[test]
+    fn clippy_diff_suggestion() {
+        let diagnostics = parse(include_str!("../test-outputs/clippy.stderr"));
+        assert_eq!(diagnostics.len(), 3);
+
+        let edit = &diagnostics[0].suggestions[0].edits[0];
+        assert_eq!((edit.span.line, edit.span.column), (2, 1));
+        assert_eq!(edit.span.end_column, "    return a + b;".len() + 1);
+        assert_eq!(edit.replacement, "    a + b");
+
+        let len_zero = &diagnostics[1].suggestions[0];
+        assert_eq!(len_zero.edits[0].replacement, "v.is_empty()");
+    }
+}
@@ -10,20 +10,23 @@ mod macro_expansion;
 mod miri;
 mod versions;
 
-pub use clippy::{ClippyRequest, ClippyResponse};
+pub use clippy::{ClippyRequest, ClippyRequestBuilder, ClippyResponse};
 pub use compile::{
-    AssemblyFlavor, CompileRequest, CompileResponse, CompileTarget, DemangleAssembly,
-    ProcessAssembly,
+    AssemblyFlavor, CompileRequest, CompileRequestBuilder, CompileResponse, CompileTarget,
+    DemangleAssembly, ProcessAssembly,
 };
 pub use crates::{CrateInformation, CratesResponse};
-pub use execute::{ExecuteRequest, ExecuteResponse};
-pub use format::{FormatRequest, FormatResponse};
+pub use execute::{ExecuteRequest, ExecuteRequestBuilder, ExecuteResponse};
+pub use format::{FormatRequest, FormatRequestBuilder, FormatResponse};
 pub use gist::{GistCreateRequest, GistResponse};
-pub use macro_expansion::{MacroExpansionRequest, MacroExpansionResponse};
-pub use miri::{AliasingModel, MiriRequest, MiriResponse};
+pub use macro_expansion::{
+    MacroExpansionRequest, MacroExpansionRequestBuilder, MacroExpansionResponse,
+};
+pub use miri::{AliasingModel, MiriRequest, MiriRequestBuilder, MiriResponse};
 pub use versions::{ChannelVersion, Version, VersionsResponse};
 
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 pub(crate) enum Endpoints {
     Execute,
@@ -104,3 +107,35 @@ pub enum Channel {
     /// Nightly channel – bleeding-edge features and experimental tools.
     Nightly,
 }
+
+impl fmt::Display for Edition {
+    /// Formats the edition as its year, e.g. `2024`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let year = match self {
+            Edition::Edition2024 => "2024",
+            Edition::Edition2021 => "2021",
+            Edition::Edition2018 => "2018",
+            Edition::Edition2015 => "2015",
+        };
+        f.write_str(year)
+    }
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mode::Debug => f.write_str("debug"),
+            Mode::Release => f.write_str("release"),
+        }
+    }
+}
+
+impl fmt::Display for Channel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Channel::Stable => f.write_str("stable"),
+            Channel::Beta => f.write_str("beta"),
+            Channel::Nightly => f.write_str("nightly"),
+        }
+    }
+}
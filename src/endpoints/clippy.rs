@@ -1,4 +1,13 @@
 use super::{Channel, CrateType, Edition};
+use crate::{
+    diagnostics::{self, Diagnostic},
+    error::Error,
+    ice::{self, Ice},
+    inference,
+    lints::{self, Lint},
+    outcome::{self, ExitStatus, Outcome},
+    validation::{self, Validate, ValidationIssue},
+};
 use serde::{Deserialize, Serialize};
 
 /// Represents a request to run Clippy (Rust linter) on the given Rust code.
@@ -42,6 +51,30 @@ impl ClippyRequest {
             code,
         }
     }
+
+    /// Checks the request for problems the playground would reject.
+    ///
+    /// Reports empty code and binaries without a `main` function.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRequest`] listing every problem found.
+    pub fn validate(&self) -> Result<(), Error> {
+        let mut issues = Vec::new();
+        validation::check_code(&self.code, &mut issues);
+        if self.crate_type == CrateType::Binary && !validation::has_main_fn(&self.code) {
+            issues.push(ValidationIssue::MissingMain);
+        }
+        validation::into_result(issues)
+    }
+
+    /// Returns a [`ClippyRequestBuilder`] initialized with [`ClippyRequest::default`].
+    pub fn builder() -> ClippyRequestBuilder {
+        ClippyRequestBuilder {
+            request: Self::default(),
+            infer: false,
+        }
+    }
 }
 
 impl Default for ClippyRequest {
@@ -66,6 +99,76 @@ impl Default for ClippyRequest {
     }
 }
 
+/// A fluent builder for [`ClippyRequest`].
+///
+/// Starts from [`ClippyRequest::default`] and lets you override only the
+/// fields you care about.
+///
+/// # Example
+///
+/// ```
+/// use playground_api::endpoints::{Channel, ClippyRequest};
+///
+/// let req = ClippyRequest::builder()
+///     .channel(Channel::Nightly)
+///     .code("fn main() { let x = 1; }")
+///     .build();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClippyRequestBuilder {
+    request: ClippyRequest,
+    infer: bool,
+}
+
+impl ClippyRequestBuilder {
+    /// Sets the Rust release channel.
+    pub fn channel(mut self, channel: Channel) -> Self {
+        self.request.channel = channel;
+        self
+    }
+
+    /// Sets the crate type.
+    pub fn crate_type(mut self, crate_type: CrateType) -> Self {
+        self.request.crate_type = crate_type;
+        self
+    }
+
+    /// Sets the Rust edition.
+    pub fn edition(mut self, edition: Edition) -> Self {
+        self.request.edition = edition;
+        self
+    }
+
+    /// Sets the source code to lint.
+    pub fn code(mut self, code: impl Into<String>) -> Self {
+        self.request.code = code.into();
+        self
+    }
+
+    /// Enables inference of the crate type, channel and edition from the code.
+    ///
+    /// When enabled, [`build`](Self::build) replaces the crate type and edition with
+    /// the values from [`inference::infer`] and switches to nightly if the code
+    /// enables unstable features. An explicitly chosen channel is never downgraded.
+    pub fn infer(mut self, enabled: bool) -> Self {
+        self.infer = enabled;
+        self
+    }
+
+    /// Finishes the builder and returns the configured [`ClippyRequest`].
+    pub fn build(mut self) -> ClippyRequest {
+        if self.infer {
+            let inference = inference::infer(&self.request.code);
+            self.request.crate_type = inference.crate_type.value;
+            self.request.edition = inference.edition.value;
+            if inference.channel.value == Channel::Nightly {
+                self.request.channel = Channel::Nightly;
+            }
+        }
+        self.request
+    }
+}
+
 /// Represents the response from running Clippy on submitted code.
 ///
 /// Includes success status, exit details, and output streams.
@@ -83,3 +186,59 @@ pub struct ClippyResponse {
     /// Standard error output containing Clippy warnings, errors, and suggestions.
     pub stderr: String,
 }
+
+impl Validate for ClippyRequest {
+    fn validate(&self) -> Result<(), Error> {
+        ClippyRequest::validate(self)
+    }
+
+    fn toolchain(&self) -> Option<(Channel, Edition)> {
+        Some((self.channel, self.edition))
+    }
+}
+
+impl ClippyResponse {
+    /// Parses `exit_detail` into a typed [`ExitStatus`].
+    pub fn exit_status(&self) -> ExitStatus {
+        ExitStatus::parse(&self.exit_detail, self.success)
+    }
+
+    /// Classifies how the Clippy run went, e.g. success, compile error or timeout.
+    pub fn outcome(&self) -> Outcome {
+        outcome::classify_build(self.success, &self.exit_detail, &self.stderr)
+    }
+
+    /// Converts an unsuccessful response into an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Unsuccessful`] with the [`Outcome`] if the Clippy run didn't succeed.
+    pub fn into_result(self) -> Result<Self, Error> {
+        match self.outcome() {
+            outcome if outcome.is_success() => Ok(self),
+            outcome => Err(Error::Unsuccessful(outcome)),
+        }
+    }
+
+    /// Detects an internal compiler error in `stderr`.
+    ///
+    /// Returns `None` if the compiler didn't crash during the Clippy run.
+    pub fn ice(&self) -> Option<Ice> {
+        ice::detect(&self.stderr)
+    }
+
+    /// Parses the compiler output in `stderr` into structured diagnostics.
+    ///
+    /// Returns every error, warning and note emitted during the Clippy run, see
+    /// [`diagnostics::parse`] for details.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        diagnostics::parse(&self.stderr)
+    }
+
+    /// Parses the lints in `stderr`, with their names, groups and suggested fixes.
+    ///
+    /// Pass the result to [`lints::apply_suggestions`] to get a fixed version of the code.
+    pub fn lints(&self) -> Vec<Lint> {
+        lints::parse(&self.stderr)
+    }
+}
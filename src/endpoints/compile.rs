@@ -1,4 +1,16 @@
 use super::{Channel, CrateType, Edition, Mode};
+use crate::{
+    asm::Assembly,
+    codegen::CodegenReport,
+    diagnostics::{self, Diagnostic},
+    error::Error,
+    ice::{self, Ice},
+    inference,
+    llvm_ir::Module,
+    mir::Mir,
+    outcome::{self, ExitStatus, Outcome},
+    validation::{self, Validate, ValidationIssue},
+};
 use serde::{Deserialize, Serialize};
 
 /// Request structure for compiling Rust code via the playground API.
@@ -93,6 +105,56 @@ impl CompileRequest {
             code,
         }
     }
+
+    /// Checks the request for problems the playground would reject.
+    ///
+    /// Reports empty code, assembly options that don't match the target,
+    /// [`CompileTarget::Hir`] outside of nightly and binaries without a `main`
+    /// function when tests are disabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRequest`] listing every problem found.
+    pub fn validate(&self) -> Result<(), Error> {
+        let mut issues = Vec::new();
+        validation::check_code(&self.code, &mut issues);
+
+        let assembly_options = [
+            self.assembly_flavor.is_some(),
+            self.demangle_assembly.is_some(),
+            self.process_assembly.is_some(),
+        ];
+        if self.target == CompileTarget::Assembly {
+            if assembly_options.contains(&false) {
+                issues.push(ValidationIssue::MissingAssemblyOptions);
+            }
+        } else if assembly_options.contains(&true) {
+            issues.push(ValidationIssue::AssemblyOptionsWithoutAssembly(self.target));
+        }
+
+        if self.target == CompileTarget::Hir && self.channel != Channel::Nightly {
+            issues.push(ValidationIssue::RequiresNightly {
+                target: self.target,
+                channel: self.channel,
+            });
+        }
+
+        if self.crate_type == CrateType::Binary
+            && !self.tests
+            && !validation::has_main_fn(&self.code)
+        {
+            issues.push(ValidationIssue::MissingMain);
+        }
+        validation::into_result(issues)
+    }
+
+    /// Returns a [`CompileRequestBuilder`] initialized with [`CompileRequest::default`].
+    pub fn builder() -> CompileRequestBuilder {
+        CompileRequestBuilder {
+            request: Self::default(),
+            infer: false,
+        }
+    }
 }
 
 impl Default for CompileRequest {
@@ -131,6 +193,149 @@ impl Default for CompileRequest {
     }
 }
 
+/// A fluent builder for [`CompileRequest`].
+///
+/// Starts from [`CompileRequest::default`] and lets you override only the
+/// fields you care about.
+///
+/// # Example
+///
+/// ```
+/// use playground_api::endpoints::{AssemblyFlavor, CompileRequest, CompileTarget};
+///
+/// let req = CompileRequest::builder()
+///     .target(CompileTarget::Assembly)
+///     .assembly_flavor(AssemblyFlavor::Intel)
+///     .release()
+///     .code("pub fn square(x: u32) -> u32 { x * x }")
+///     .build();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompileRequestBuilder {
+    request: CompileRequest,
+    infer: bool,
+}
+
+impl CompileRequestBuilder {
+    /// Sets the compilation target.
+    ///
+    /// Switching away from [`CompileTarget::Assembly`] clears the assembly options,
+    /// since the playground ignores them for every other target. Switching back to
+    /// [`CompileTarget::Assembly`] restores their defaults if they were cleared.
+    pub fn target(mut self, target: CompileTarget) -> Self {
+        self.request.target = target;
+        if target == CompileTarget::Assembly {
+            let defaults = CompileRequest::default();
+            self.request.assembly_flavor =
+                self.request.assembly_flavor.or(defaults.assembly_flavor);
+            self.request.demangle_assembly = self
+                .request
+                .demangle_assembly
+                .or(defaults.demangle_assembly);
+            self.request.process_assembly =
+                self.request.process_assembly.or(defaults.process_assembly);
+        } else {
+            self.request.assembly_flavor = None;
+            self.request.demangle_assembly = None;
+            self.request.process_assembly = None;
+        }
+        self
+    }
+
+    /// Sets the assembly syntax flavor.
+    pub fn assembly_flavor(mut self, assembly_flavor: AssemblyFlavor) -> Self {
+        self.request.assembly_flavor = Some(assembly_flavor);
+        self
+    }
+
+    /// Sets whether symbols in the assembly output are demangled.
+    pub fn demangle_assembly(mut self, demangle_assembly: DemangleAssembly) -> Self {
+        self.request.demangle_assembly = Some(demangle_assembly);
+        self
+    }
+
+    /// Sets whether the assembly output is filtered or raw.
+    pub fn process_assembly(mut self, process_assembly: ProcessAssembly) -> Self {
+        self.request.process_assembly = Some(process_assembly);
+        self
+    }
+
+    /// Sets the Rust release channel.
+    pub fn channel(mut self, channel: Channel) -> Self {
+        self.request.channel = channel;
+        self
+    }
+
+    /// Sets the compilation mode.
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.request.mode = mode;
+        self
+    }
+
+    /// Shorthand for `.mode(Mode::Debug)`.
+    pub fn debug(self) -> Self {
+        self.mode(Mode::Debug)
+    }
+
+    /// Shorthand for `.mode(Mode::Release)`.
+    pub fn release(self) -> Self {
+        self.mode(Mode::Release)
+    }
+
+    /// Sets the Rust edition.
+    pub fn edition(mut self, edition: Edition) -> Self {
+        self.request.edition = edition;
+        self
+    }
+
+    /// Sets the crate type.
+    pub fn crate_type(mut self, crate_type: CrateType) -> Self {
+        self.request.crate_type = crate_type;
+        self
+    }
+
+    /// Sets whether the code should be compiled with the test harness.
+    pub fn tests(mut self, tests: bool) -> Self {
+        self.request.tests = tests;
+        self
+    }
+
+    /// Sets whether backtraces should be enabled.
+    pub fn backtrace(mut self, backtrace: bool) -> Self {
+        self.request.backtrace = backtrace;
+        self
+    }
+
+    /// Sets the source code to compile.
+    pub fn code(mut self, code: impl Into<String>) -> Self {
+        self.request.code = code.into();
+        self
+    }
+
+    /// Enables inference of the crate type, channel and edition from the code.
+    ///
+    /// When enabled, [`build`](Self::build) replaces the crate type and edition with
+    /// the values from [`inference::infer`] and switches to nightly if the code
+    /// enables unstable features. An explicitly chosen channel is never downgraded.
+    pub fn infer(mut self, enabled: bool) -> Self {
+        self.infer = enabled;
+        self
+    }
+
+    /// Finishes the builder and returns the configured [`CompileRequest`].
+    pub fn build(mut self) -> CompileRequest {
+        if self.infer {
+            let inference = inference::infer(&self.request.code);
+            self.request.crate_type = inference.crate_type.value;
+            self.request.edition = inference.edition.value;
+            if inference.channel.value == Channel::Nightly {
+                self.request.channel = Channel::Nightly;
+            }
+        }
+        self.request
+    }
+}
+
 /// Response structure returned after compiling Rust code.
 ///
 /// Includes compilation success status, process exit details, and outputs.
@@ -208,3 +413,87 @@ pub enum CompileTarget {
     Mir,
     Wasm,
 }
+
+impl Validate for CompileRequest {
+    fn validate(&self) -> Result<(), Error> {
+        CompileRequest::validate(self)
+    }
+
+    fn toolchain(&self) -> Option<(Channel, Edition)> {
+        Some((self.channel, self.edition))
+    }
+}
+
+impl CompileResponse {
+    /// Parses `exit_detail` into a typed [`ExitStatus`].
+    pub fn exit_status(&self) -> ExitStatus {
+        ExitStatus::parse(&self.exit_detail, self.success)
+    }
+
+    /// Classifies how the compilation went, e.g. success, compile error or timeout.
+    pub fn outcome(&self) -> Outcome {
+        outcome::classify_build(self.success, &self.exit_detail, &self.stderr)
+    }
+
+    /// Converts an unsuccessful response into an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Unsuccessful`] with the [`Outcome`] if the compilation didn't succeed.
+    pub fn into_result(self) -> Result<Self, Error> {
+        match self.outcome() {
+            outcome if outcome.is_success() => Ok(self),
+            outcome => Err(Error::Unsuccessful(outcome)),
+        }
+    }
+
+    /// Detects an internal compiler error in `stderr`.
+    ///
+    /// Returns `None` if the compiler didn't crash during the compilation.
+    pub fn ice(&self) -> Option<Ice> {
+        ice::detect(&self.stderr)
+    }
+
+    /// Parses the compiler output in `stderr` into structured diagnostics.
+    ///
+    /// Returns every error, warning and note emitted during the compilation, see
+    /// [`diagnostics::parse`] for details.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        diagnostics::parse(&self.stderr)
+    }
+
+    /// Parses the output of an assembly compilation into functions.
+    ///
+    /// The syntax is detected from the output, see [`Assembly::parse_detect`].
+    pub fn assembly(&self) -> Assembly {
+        Assembly::parse_detect(&self.code)
+    }
+
+    /// Parses the output of an LLVM IR compilation into a [`Module`].
+    pub fn llvm_ir(&self) -> Module {
+        Module::parse(&self.code)
+    }
+
+    /// Parses the output of a MIR compilation into bodies and basic blocks.
+    pub fn mir(&self) -> Mir {
+        Mir::parse(&self.code)
+    }
+
+    /// Assembles the output of a Wasm compilation and instantiates it for running its
+    /// exported functions.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`WasmError`](crate::wasm::WasmError) if the output can't be assembled or
+    /// instantiated, e.g. because the compilation failed.
+    #[cfg(feature = "wasm")]
+    pub fn wasm_module(&self) -> Result<crate::wasm::WasmModule, crate::wasm::WasmError> {
+        crate::wasm::WasmModule::new(&self.code)
+    }
+
+    /// Reports the panic paths and vectorization of each function in the LLVM IR
+    /// or assembly output, see [`CodegenReport`].
+    pub fn codegen_report(&self) -> CodegenReport {
+        CodegenReport::parse_detect(&self.code)
+    }
+}
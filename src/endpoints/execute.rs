@@ -1,5 +1,17 @@
 use super::{Channel, CrateType, Edition, Mode};
+use crate::{
+    cargo_output,
+    diagnostics::{self, Diagnostic},
+    error::Error,
+    ice::{self, Ice},
+    inference,
+    libtest::{self, TestReport},
+    outcome::{self, ExitStatus, Outcome},
+    panics::{self, PanicInfo},
+    validation::{self, Validate, ValidationIssue},
+};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 /// Request structure to execute Rust code on the playground.
 ///
@@ -65,6 +77,33 @@ impl ExecuteRequest {
             code,
         }
     }
+
+    /// Checks the request for problems the playground would reject.
+    ///
+    /// Reports empty code and binaries without a `main` function when tests are disabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRequest`] listing every problem found.
+    pub fn validate(&self) -> Result<(), Error> {
+        let mut issues = Vec::new();
+        validation::check_code(&self.code, &mut issues);
+        if self.crate_type == CrateType::Binary
+            && !self.tests
+            && !validation::has_main_fn(&self.code)
+        {
+            issues.push(ValidationIssue::MissingMain);
+        }
+        validation::into_result(issues)
+    }
+
+    /// Returns an [`ExecuteRequestBuilder`] initialized with [`ExecuteRequest::default`].
+    pub fn builder() -> ExecuteRequestBuilder {
+        ExecuteRequestBuilder {
+            request: Self::default(),
+            infer: false,
+        }
+    }
 }
 
 impl Default for ExecuteRequest {
@@ -95,6 +134,106 @@ impl Default for ExecuteRequest {
     }
 }
 
+/// A fluent builder for [`ExecuteRequest`].
+///
+/// Starts from [`ExecuteRequest::default`] and lets you override only the
+/// fields you care about.
+///
+/// # Example
+///
+/// ```
+/// use playground_api::endpoints::{Channel, ExecuteRequest};
+///
+/// let req = ExecuteRequest::builder()
+///     .channel(Channel::Nightly)
+///     .release()
+///     .tests(true)
+///     .code("fn main() {}")
+///     .build();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExecuteRequestBuilder {
+    request: ExecuteRequest,
+    infer: bool,
+}
+
+impl ExecuteRequestBuilder {
+    /// Sets the Rust release channel.
+    pub fn channel(mut self, channel: Channel) -> Self {
+        self.request.channel = channel;
+        self
+    }
+
+    /// Sets the compilation mode.
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.request.mode = mode;
+        self
+    }
+
+    /// Shorthand for `.mode(Mode::Debug)`.
+    pub fn debug(self) -> Self {
+        self.mode(Mode::Debug)
+    }
+
+    /// Shorthand for `.mode(Mode::Release)`.
+    pub fn release(self) -> Self {
+        self.mode(Mode::Release)
+    }
+
+    /// Sets the Rust edition.
+    pub fn edition(mut self, edition: Edition) -> Self {
+        self.request.edition = edition;
+        self
+    }
+
+    /// Sets the crate type.
+    pub fn crate_type(mut self, crate_type: CrateType) -> Self {
+        self.request.crate_type = crate_type;
+        self
+    }
+
+    /// Sets whether the code should be run as tests.
+    pub fn tests(mut self, tests: bool) -> Self {
+        self.request.tests = tests;
+        self
+    }
+
+    /// Sets whether backtraces should be enabled.
+    pub fn backtrace(mut self, backtrace: bool) -> Self {
+        self.request.backtrace = backtrace;
+        self
+    }
+
+    /// Sets the source code to execute.
+    pub fn code(mut self, code: impl Into<String>) -> Self {
+        self.request.code = code.into();
+        self
+    }
+
+    /// Enables inference of the crate type, channel and edition from the code.
+    ///
+    /// When enabled, [`build`](Self::build) replaces the crate type and edition with
+    /// the values from [`inference::infer`] and switches to nightly if the code
+    /// enables unstable features. An explicitly chosen channel is never downgraded.
+    pub fn infer(mut self, enabled: bool) -> Self {
+        self.infer = enabled;
+        self
+    }
+
+    /// Finishes the builder and returns the configured [`ExecuteRequest`].
+    pub fn build(mut self) -> ExecuteRequest {
+        if self.infer {
+            let inference = inference::infer(&self.request.code);
+            self.request.crate_type = inference.crate_type.value;
+            self.request.edition = inference.edition.value;
+            if inference.channel.value == Channel::Nightly {
+                self.request.channel = Channel::Nightly;
+            }
+        }
+        self.request
+    }
+}
+
 /// Response structure returned after executing Rust code.
 ///
 /// Contains execution success status, exit details, and output streams.
@@ -113,3 +252,95 @@ pub struct ExecuteResponse {
     /// Standard error output, including runtime errors and panics.
     pub stderr: String,
 }
+
+impl Validate for ExecuteRequest {
+    fn validate(&self) -> Result<(), Error> {
+        ExecuteRequest::validate(self)
+    }
+
+    fn toolchain(&self) -> Option<(Channel, Edition)> {
+        Some((self.channel, self.edition))
+    }
+}
+
+impl ExecuteResponse {
+    /// Parses the libtest output in `stdout` of a request run with `tests: true`.
+    ///
+    /// Returns `None` if no tests were run, e.g. because compilation failed.
+    pub fn test_report(&self) -> Option<TestReport> {
+        libtest::parse(&self.stdout)
+    }
+
+    /// Parses `exit_detail` into a typed [`ExitStatus`].
+    pub fn exit_status(&self) -> ExitStatus {
+        ExitStatus::parse(&self.exit_detail, self.success)
+    }
+
+    /// Classifies how the execution went, e.g. success, compile error or timeout.
+    pub fn outcome(&self) -> Outcome {
+        outcome::classify_run(self.success, &self.exit_detail, &self.stdout, &self.stderr)
+    }
+
+    /// Converts an unsuccessful response into an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Unsuccessful`] with the [`Outcome`] if the execution didn't succeed.
+    pub fn into_result(self) -> Result<Self, Error> {
+        match self.outcome() {
+            outcome if outcome.is_success() => Ok(self),
+            outcome => Err(Error::Unsuccessful(outcome)),
+        }
+    }
+
+    /// Detects an internal compiler error in `stderr`.
+    ///
+    /// Returns `None` if the compiler didn't crash during the build.
+    pub fn ice(&self) -> Option<Ice> {
+        ice::detect(&self.stderr)
+    }
+
+    /// Parses the compiler output in `stderr` into structured diagnostics.
+    ///
+    /// Only the part of `stderr` printed while building is parsed, so the program's
+    /// own output can't be mistaken for a diagnostic. See [`diagnostics::parse`]
+    /// for details.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        diagnostics::parse(&self.compiler_output())
+    }
+
+    /// Returns cargo's own progress lines from `stderr`, like
+    /// `Compiling playground v0.0.1` or `Finished ...`.
+    pub fn build_log(&self) -> String {
+        cargo_output::split(&self.stderr).build_log.join("\n")
+    }
+
+    /// Returns the warnings and errors rustc printed while building.
+    pub fn compiler_output(&self) -> String {
+        cargo_output::split(&self.stderr).compiler.join("\n")
+    }
+
+    /// Returns only what the program itself wrote to stderr.
+    ///
+    /// Cargo's status lines between test binaries, like `Doc-tests playground`, are
+    /// left out. Empty if the program never ran, e.g. because compilation failed.
+    pub fn program_stderr(&self) -> String {
+        cargo_output::split(&self.stderr).program
+    }
+
+    /// Parses the first panic the program printed, including its backtrace if
+    /// `backtrace` was enabled in the request.
+    ///
+    /// Returns `None` if the program didn't panic.
+    pub fn panic(&self) -> Option<PanicInfo> {
+        panics::parse(&self.program_stderr()).into_iter().next()
+    }
+
+    /// Returns the build time reported on cargo's `Finished` line, if it was printed.
+    pub fn build_time(&self) -> Option<Duration> {
+        cargo_output::split(&self.stderr)
+            .build_log
+            .into_iter()
+            .find_map(cargo_output::build_time)
+    }
+}
@@ -1,4 +1,12 @@
 use super::{Channel, CrateType, Edition};
+use crate::{
+    diagnostics::Diagnostic,
+    error::Error,
+    inference,
+    outcome::{self, ExitStatus, Outcome},
+    rustfmt,
+    validation::{self, Validate},
+};
 use serde::{Deserialize, Serialize};
 
 /// Request structure to format Rust source code via the playground.
@@ -40,6 +48,27 @@ impl FormatRequest {
             code,
         }
     }
+
+    /// Checks the request for problems the playground would reject.
+    ///
+    /// Reports empty code.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRequest`] listing every problem found.
+    pub fn validate(&self) -> Result<(), Error> {
+        let mut issues = Vec::new();
+        validation::check_code(&self.code, &mut issues);
+        validation::into_result(issues)
+    }
+
+    /// Returns a [`FormatRequestBuilder`] initialized with [`FormatRequest::default`].
+    pub fn builder() -> FormatRequestBuilder {
+        FormatRequestBuilder {
+            request: Self::default(),
+            infer: false,
+        }
+    }
 }
 
 impl Default for FormatRequest {
@@ -64,9 +93,79 @@ impl Default for FormatRequest {
     }
 }
 
+/// A fluent builder for [`FormatRequest`].
+///
+/// Starts from [`FormatRequest::default`] and lets you override only the
+/// fields you care about.
+///
+/// # Example
+///
+/// ```
+/// use playground_api::endpoints::{Edition, FormatRequest};
+///
+/// let req = FormatRequest::builder()
+///     .edition(Edition::Edition2021)
+///     .code("fn main(){}")
+///     .build();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatRequestBuilder {
+    request: FormatRequest,
+    infer: bool,
+}
+
+impl FormatRequestBuilder {
+    /// Sets the Rust release channel.
+    pub fn channel(mut self, channel: Channel) -> Self {
+        self.request.channel = channel;
+        self
+    }
+
+    /// Sets the crate type.
+    pub fn crate_type(mut self, crate_type: CrateType) -> Self {
+        self.request.crate_type = crate_type;
+        self
+    }
+
+    /// Sets the Rust edition.
+    pub fn edition(mut self, edition: Edition) -> Self {
+        self.request.edition = edition;
+        self
+    }
+
+    /// Sets the source code to format.
+    pub fn code(mut self, code: impl Into<String>) -> Self {
+        self.request.code = code.into();
+        self
+    }
+
+    /// Enables inference of the crate type, channel and edition from the code.
+    ///
+    /// When enabled, [`build`](Self::build) replaces the crate type and edition with
+    /// the values from [`inference::infer`] and switches to nightly if the code
+    /// enables unstable features. An explicitly chosen channel is never downgraded.
+    pub fn infer(mut self, enabled: bool) -> Self {
+        self.infer = enabled;
+        self
+    }
+
+    /// Finishes the builder and returns the configured [`FormatRequest`].
+    pub fn build(mut self) -> FormatRequest {
+        if self.infer {
+            let inference = inference::infer(&self.request.code);
+            self.request.crate_type = inference.crate_type.value;
+            self.request.edition = inference.edition.value;
+            if inference.channel.value == Channel::Nightly {
+                self.request.channel = Channel::Nightly;
+            }
+        }
+        self.request
+    }
+}
+
 /// Response structure returned after formatting Rust code.
 ///
-/// Contains success status, exit details, and the formatted code.
+/// Contains success status, exit details, the formatted code and the output of rustfmt.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct FormatResponse {
     /// Indicates whether formatting was successful.
@@ -78,4 +177,55 @@ pub struct FormatResponse {
 
     /// The resulting formatted Rust source code.
     pub code: String,
+
+    /// The standard output of rustfmt, empty if the playground didn't return it.
+    #[serde(default)]
+    pub stdout: String,
+
+    /// The standard error of rustfmt, e.g. the parse error when formatting failed.
+    /// Empty if the playground didn't return it.
+    #[serde(default)]
+    pub stderr: String,
+}
+
+impl Validate for FormatRequest {
+    fn validate(&self) -> Result<(), Error> {
+        FormatRequest::validate(self)
+    }
+
+    fn toolchain(&self) -> Option<(Channel, Edition)> {
+        Some((self.channel, self.edition))
+    }
+}
+
+impl FormatResponse {
+    /// Parses `exit_detail` into a typed [`ExitStatus`].
+    pub fn exit_status(&self) -> ExitStatus {
+        ExitStatus::parse(&self.exit_detail, self.success)
+    }
+
+    /// Classifies how the formatting went, e.g. success, compile error or timeout.
+    pub fn outcome(&self) -> Outcome {
+        outcome::classify_build(self.success, &self.exit_detail, &self.stderr)
+    }
+
+    /// Parses the errors rustfmt reported in `stderr` into structured diagnostics.
+    ///
+    /// Returns an empty list if formatting succeeded, see [`rustfmt::parse_errors`]
+    /// for details.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        rustfmt::parse_errors(&self.stderr)
+    }
+
+    /// Converts an unsuccessful response into an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Unsuccessful`] with the [`Outcome`] if the formatting didn't succeed.
+    pub fn into_result(self) -> Result<Self, Error> {
+        match self.outcome() {
+            outcome if outcome.is_success() => Ok(self),
+            outcome => Err(Error::Unsuccessful(outcome)),
+        }
+    }
 }
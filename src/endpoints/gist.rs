@@ -1,3 +1,7 @@
+use crate::{
+    error::Error,
+    validation::{self, Validate},
+};
 use serde::{Deserialize, Serialize};
 
 /// A request to create a new Gist on the Rust playground.
@@ -22,6 +26,19 @@ impl GistCreateRequest {
     pub fn new(code: String) -> Self {
         Self { code }
     }
+
+    /// Checks the request for problems the playground would reject.
+    ///
+    /// Reports empty code.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRequest`] listing every problem found.
+    pub fn validate(&self) -> Result<(), Error> {
+        let mut issues = Vec::new();
+        validation::check_code(&self.code, &mut issues);
+        validation::into_result(issues)
+    }
 }
 
 /// A response returned after creating or retrieving a Gist.
@@ -36,3 +53,9 @@ pub struct GistResponse {
     /// The Rust code stored in the Gist.
     pub code: String,
 }
+
+impl Validate for GistCreateRequest {
+    fn validate(&self) -> Result<(), Error> {
+        GistCreateRequest::validate(self)
+    }
+}
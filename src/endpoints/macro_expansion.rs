@@ -1,4 +1,11 @@
-use super::Edition;
+use super::{Channel, Edition};
+use crate::{
+    error::Error,
+    ice::{self, Ice},
+    inference,
+    outcome::{self, ExitStatus, Outcome},
+    validation::{self, Validate},
+};
 use serde::{Deserialize, Serialize};
 
 /// A request to expand macros in a given Rust code snippet.
@@ -26,6 +33,27 @@ impl MacroExpansionRequest {
     pub fn new(code: String, edition: Edition) -> Self {
         Self { code, edition }
     }
+
+    /// Checks the request for problems the playground would reject.
+    ///
+    /// Reports empty code.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRequest`] listing every problem found.
+    pub fn validate(&self) -> Result<(), Error> {
+        let mut issues = Vec::new();
+        validation::check_code(&self.code, &mut issues);
+        validation::into_result(issues)
+    }
+
+    /// Returns a [`MacroExpansionRequestBuilder`] initialized with [`MacroExpansionRequest::default`].
+    pub fn builder() -> MacroExpansionRequestBuilder {
+        MacroExpansionRequestBuilder {
+            request: Self::default(),
+            infer: false,
+        }
+    }
 }
 
 impl Default for MacroExpansionRequest {
@@ -39,6 +67,58 @@ impl Default for MacroExpansionRequest {
     }
 }
 
+/// A fluent builder for [`MacroExpansionRequest`].
+///
+/// Starts from [`MacroExpansionRequest::default`] and lets you override only the
+/// fields you care about.
+///
+/// # Example
+///
+/// ```
+/// use playground_api::endpoints::{Edition, MacroExpansionRequest};
+///
+/// let req = MacroExpansionRequest::builder()
+///     .edition(Edition::Edition2021)
+///     .code("fn main() { println!(\"hi\"); }")
+///     .build();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MacroExpansionRequestBuilder {
+    request: MacroExpansionRequest,
+    infer: bool,
+}
+
+impl MacroExpansionRequestBuilder {
+    /// Sets the Rust edition.
+    pub fn edition(mut self, edition: Edition) -> Self {
+        self.request.edition = edition;
+        self
+    }
+
+    /// Sets the source code whose macros should be expanded.
+    pub fn code(mut self, code: impl Into<String>) -> Self {
+        self.request.code = code.into();
+        self
+    }
+
+    /// Enables inference of the edition from the code.
+    ///
+    /// When enabled, [`build`](Self::build) replaces the edition with the value
+    /// from [`inference::infer`].
+    pub fn infer(mut self, enabled: bool) -> Self {
+        self.infer = enabled;
+        self
+    }
+
+    /// Finishes the builder and returns the configured [`MacroExpansionRequest`].
+    pub fn build(mut self) -> MacroExpansionRequest {
+        if self.infer {
+            self.request.edition = inference::infer(&self.request.code).edition.value;
+        }
+        self.request
+    }
+}
+
 /// A response from the Rust playground's macro expansion service.
 ///
 /// Contains the macro-expanded output and status information.
@@ -53,3 +133,44 @@ pub struct MacroExpansionResponse {
     /// The standard error from the macro expansion.
     pub stderr: String,
 }
+
+impl Validate for MacroExpansionRequest {
+    fn validate(&self) -> Result<(), Error> {
+        MacroExpansionRequest::validate(self)
+    }
+
+    fn toolchain(&self) -> Option<(Channel, Edition)> {
+        Some((Channel::Nightly, self.edition))
+    }
+}
+
+impl MacroExpansionResponse {
+    /// Parses `exit_detail` into a typed [`ExitStatus`].
+    pub fn exit_status(&self) -> ExitStatus {
+        ExitStatus::parse(&self.exit_detail, self.success)
+    }
+
+    /// Classifies how the macro expansion went, e.g. success, compile error or timeout.
+    pub fn outcome(&self) -> Outcome {
+        outcome::classify_build(self.success, &self.exit_detail, &self.stderr)
+    }
+
+    /// Converts an unsuccessful response into an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Unsuccessful`] with the [`Outcome`] if the macro expansion didn't succeed.
+    pub fn into_result(self) -> Result<Self, Error> {
+        match self.outcome() {
+            outcome if outcome.is_success() => Ok(self),
+            outcome => Err(Error::Unsuccessful(outcome)),
+        }
+    }
+
+    /// Detects an internal compiler error in `stderr`.
+    ///
+    /// Returns `None` if the compiler didn't crash during the macro expansion.
+    pub fn ice(&self) -> Option<Ice> {
+        ice::detect(&self.stderr)
+    }
+}
@@ -1,4 +1,13 @@
-use super::Edition;
+use super::{Channel, Edition};
+use crate::{
+    error::Error,
+    ice::{self, Ice},
+    inference,
+    libtest::{self, TestReport},
+    miri::{self, MiriReport},
+    outcome::{self, ExitStatus, Outcome},
+    validation::{self, Validate, ValidationIssue},
+};
 use serde::{Deserialize, Serialize};
 
 /// A request structure for running Rust code under Miri, the Rust interpreter for detecting undefined behavior.
@@ -39,6 +48,30 @@ impl MiriRequest {
             aliasing_model,
         }
     }
+
+    /// Checks the request for problems the playground would reject.
+    ///
+    /// Reports empty code and code without a `main` function when tests are disabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidRequest`] listing every problem found.
+    pub fn validate(&self) -> Result<(), Error> {
+        let mut issues = Vec::new();
+        validation::check_code(&self.code, &mut issues);
+        if !self.tests && !validation::has_main_fn(&self.code) {
+            issues.push(ValidationIssue::MissingMain);
+        }
+        validation::into_result(issues)
+    }
+
+    /// Returns a [`MiriRequestBuilder`] initialized with [`MiriRequest::default`].
+    pub fn builder() -> MiriRequestBuilder {
+        MiriRequestBuilder {
+            request: Self::default(),
+            infer: false,
+        }
+    }
 }
 
 impl Default for MiriRequest {
@@ -54,6 +87,70 @@ impl Default for MiriRequest {
     }
 }
 
+/// A fluent builder for [`MiriRequest`].
+///
+/// Starts from [`MiriRequest::default`] and lets you override only the
+/// fields you care about.
+///
+/// # Example
+///
+/// ```
+/// use playground_api::endpoints::{AliasingModel, MiriRequest};
+///
+/// let req = MiriRequest::builder()
+///     .aliasing_model(AliasingModel::Tree)
+///     .code("fn main() {}")
+///     .build();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MiriRequestBuilder {
+    request: MiriRequest,
+    infer: bool,
+}
+
+impl MiriRequestBuilder {
+    /// Sets the Rust edition.
+    pub fn edition(mut self, edition: Edition) -> Self {
+        self.request.edition = edition;
+        self
+    }
+
+    /// Sets whether the tests should be run instead of `main`.
+    pub fn tests(mut self, tests: bool) -> Self {
+        self.request.tests = tests;
+        self
+    }
+
+    /// Sets the aliasing model Miri checks against.
+    pub fn aliasing_model(mut self, aliasing_model: AliasingModel) -> Self {
+        self.request.aliasing_model = Some(aliasing_model);
+        self
+    }
+
+    /// Sets the source code to interpret.
+    pub fn code(mut self, code: impl Into<String>) -> Self {
+        self.request.code = code.into();
+        self
+    }
+
+    /// Enables inference of the edition from the code.
+    ///
+    /// When enabled, [`build`](Self::build) replaces the edition with the value
+    /// from [`inference::infer`].
+    pub fn infer(mut self, enabled: bool) -> Self {
+        self.infer = enabled;
+        self
+    }
+
+    /// Finishes the builder and returns the configured [`MiriRequest`].
+    pub fn build(mut self) -> MiriRequest {
+        if self.infer {
+            self.request.edition = inference::infer(&self.request.code).edition.value;
+        }
+        self.request
+    }
+}
+
 /// The response returned after executing a Miri request.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct MiriResponse {
@@ -81,3 +178,58 @@ pub enum AliasingModel {
     /// Uses the Tree Borrows model for aliasing checks.
     Tree,
 }
+
+impl Validate for MiriRequest {
+    fn validate(&self) -> Result<(), Error> {
+        MiriRequest::validate(self)
+    }
+
+    fn toolchain(&self) -> Option<(Channel, Edition)> {
+        Some((Channel::Nightly, self.edition))
+    }
+}
+
+impl MiriResponse {
+    /// Parses the libtest output in `stdout` of a request run with `tests: true`.
+    ///
+    /// Returns `None` if no tests were run, e.g. because compilation failed.
+    pub fn test_report(&self) -> Option<TestReport> {
+        libtest::parse(&self.stdout)
+    }
+
+    /// Parses the error Miri reported in `stderr`, e.g. undefined behavior.
+    ///
+    /// Returns `None` if Miri didn't report an error.
+    pub fn report(&self) -> Option<MiriReport> {
+        miri::parse(&self.stderr)
+    }
+
+    /// Parses `exit_detail` into a typed [`ExitStatus`].
+    pub fn exit_status(&self) -> ExitStatus {
+        ExitStatus::parse(&self.exit_detail, self.success)
+    }
+
+    /// Classifies how the Miri run went, e.g. success, compile error or timeout.
+    pub fn outcome(&self) -> Outcome {
+        outcome::classify_run(self.success, &self.exit_detail, &self.stdout, &self.stderr)
+    }
+
+    /// Converts an unsuccessful response into an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Unsuccessful`] with the [`Outcome`] if the Miri run didn't succeed.
+    pub fn into_result(self) -> Result<Self, Error> {
+        match self.outcome() {
+            outcome if outcome.is_success() => Ok(self),
+            outcome => Err(Error::Unsuccessful(outcome)),
+        }
+    }
+
+    /// Detects an internal compiler error in `stderr`.
+    ///
+    /// Returns `None` if the compiler didn't crash during the Miri run.
+    pub fn ice(&self) -> Option<Ice> {
+        ice::detect(&self.stderr)
+    }
+}
@@ -1,3 +1,4 @@
+use super::Channel;
 use serde::{Deserialize, Serialize};
 
 /// A response containing Rust compiler toolchain versions for different release channels.
@@ -13,6 +14,17 @@ pub struct VersionsResponse {
     pub nightly: ChannelVersion,
 }
 
+impl VersionsResponse {
+    /// Returns the versions of the given channel.
+    pub fn channel(&self, channel: Channel) -> &ChannelVersion {
+        match channel {
+            Channel::Stable => &self.stable,
+            Channel::Beta => &self.beta,
+            Channel::Nightly => &self.nightly,
+        }
+    }
+}
+
 /// Tool versions for a specific Rust release channel.
 ///
 /// Contains versions for rustc, rustfmt, clippy, and optionally miri.
@@ -1,3 +1,4 @@
+use crate::{outcome::Outcome, validation::ValidationIssue};
 use thiserror::Error;
 
 /// Represents all possible errors that can occur while interacting with the Rust playground API.
@@ -20,4 +21,25 @@ pub enum Error {
     /// Contains the numeric status code of the failed response.
     #[error("request status code was not successful: {0}")]
     NoSuccess(u16),
+
+    /// The request failed client-side validation and was not sent.
+    ///
+    /// Contains every problem that was found, not just the first one.
+    #[error("invalid request: {}", join_issues(.0))]
+    InvalidRequest(Vec<ValidationIssue>),
+
+    /// The playground handled the request, but the run itself didn't succeed.
+    ///
+    /// Returned by the `into_result()` methods of the responses.
+    #[error("run was not successful: {0}")]
+    Unsuccessful(Outcome),
+}
+
+/// Joins validation issues into a single line for the error message.
+fn join_issues(issues: &[ValidationIssue]) -> String {
+    issues
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
 }
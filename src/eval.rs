@@ -0,0 +1,106 @@
+//! Evaluation of single expressions or statement lists.
+//!
+//! [`wrap`] turns an expression like `1 + 2` into a runnable program which
+//! prints the Debug representation of its value, and [`EvalResponse`] picks that
+//! value back out of the program's output. [`Client::eval`](crate::Client::eval)
+//! combines both with an execute request.
+
+use crate::endpoints::ExecuteResponse;
+use serde::{Deserialize, Serialize};
+
+/// Printed right before the value so it can be told apart from the program's own output.
+const VALUE_MARKER: &str = "\u{1f}playground-api-eval\u{1f}";
+
+/// Wraps an expression or a list of statements into a runnable program.
+///
+/// The code is placed inside a block in a `main` returning
+/// `Result<(), Box<dyn std::error::Error>>`, so `?` can be used on results. If the
+/// code ends with an expression its value is printed with `{:?}`; if it ends with a
+/// semicolon nothing is printed.
+///
+/// # Example
+///
+/// ```
+/// let program = playground_api::eval::wrap("1 + 2");
+/// assert!(program.contains("fn main()"));
+/// ```
+pub fn wrap(code: &str) -> String {
+    let code = code.trim();
+    let print = if code.is_empty() || code.ends_with(';') {
+        String::new()
+    } else {
+        format!(
+            "\n    print!(\"{}{{:?}}\", __eval_value);",
+            VALUE_MARKER.escape_default()
+        )
+    };
+
+    format!(
+        "#[allow(unused)]\n\
+         fn main() -> Result<(), Box<dyn std::error::Error>> {{\n    \
+             let __eval_value = {{\n{code}\n    }};{print}\n    \
+             Ok(())\n\
+         }}\n"
+    )
+}
+
+/// The result of evaluating an expression.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct EvalResponse {
+    /// The Debug representation of the value, or `None` if the code ended with a
+    /// statement or didn't run successfully.
+    pub value: Option<String>,
+
+    /// Everything the evaluated code printed to stdout itself.
+    pub stdout: String,
+
+    /// The full response of the underlying execute request, including the
+    /// compiler output in `stderr`.
+    pub response: ExecuteResponse,
+}
+
+impl From<ExecuteResponse> for EvalResponse {
+    /// Splits the output of a program created by [`wrap`] into the printed value
+    /// and the program's own output.
+    fn from(response: ExecuteResponse) -> Self {
+        let (stdout, value) = match response.stdout.rsplit_once(VALUE_MARKER) {
+            Some((stdout, value)) => (stdout.to_owned(), Some(value.to_owned())),
+            None => (response.stdout.clone(), None),
+        };
+
+        Self {
+            value,
+            stdout,
+            response,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraps_expressions_and_statements() {
+        let program = wrap("1 + 2");
+        assert!(program.contains("let __eval_value = {\n1 + 2\n    };"));
+        assert!(program.contains("print!(\"\\u{1f}playground-api-eval\\u{1f}{:?}\""));
+
+        let program = wrap("let x = 1;\n");
+        assert!(!program.contains("print!"));
+    }
+
+    #[test]
+    fn splits_value_from_output() {
+        let response = ExecuteResponse {
+            success: true,
+            exit_detail: String::new(),
+            stdout: format!("hello\n{VALUE_MARKER}3"),
+            stderr: "   Compiling playground v0.0.1 (/playground)\n".to_owned(),
+        };
+
+        let eval = EvalResponse::from(response);
+        assert_eq!(eval.value.as_deref(), Some("3"));
+        assert_eq!(eval.stdout, "hello\n");
+    }
+}
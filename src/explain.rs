@@ -0,0 +1,208 @@
+//! Offline explanations of rustc error codes and Clippy lints.
+//!
+//! The explanations are embedded in the crate, so they can be attached to
+//! diagnostics without a network call. They were generated from the toolchain
+//! given by [`VERSION`] with `scripts/update-explanations.py`: error codes contain
+//! the full text of `rustc --explain`, lints the description and rationale of
+//! `cargo clippy --explain`.
+//!
+//! The embedded text adds about 750 KB to the crate, so this module is only
+//! available with the `explain` feature.
+
+use crate::{
+    diagnostics::{self, Diagnostic},
+    lints::Lint,
+};
+use serde::{Deserialize, Serialize};
+
+/// The Rust version the explanations were taken from.
+pub const VERSION: &str = "1.95.0";
+
+const ERROR_CODES: &str = include_str!("explain/error_codes.txt");
+const CLIPPY_LINTS: &str = include_str!("explain/clippy_lints.txt");
+
+/// What an [`Explanation`] explains.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ExplanationKind {
+    /// A rustc error code like `E0382`.
+    ErrorCode,
+
+    /// A Clippy lint like `clippy::redundant_clone`.
+    ClippyLint,
+}
+
+/// The explanation of an error code or lint.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Explanation {
+    /// Whether an error code or a lint is explained.
+    pub kind: ExplanationKind,
+
+    /// The error code, e.g. `E0382`, or the lint name, e.g. `clippy::redundant_clone`.
+    pub name: String,
+
+    /// A one-line summary.
+    pub summary: String,
+
+    /// The full explanation in Markdown.
+    pub text: String,
+
+    /// The group of a lint, e.g. `style`.
+    pub group: Option<String>,
+
+    /// The default level of a lint: `allow`, `warn` or `deny`.
+    pub default_level: Option<String>,
+
+    /// The link to the online documentation for [`VERSION`].
+    pub url: String,
+}
+
+/// Looks up the explanation of a rustc error code like `E0382`.
+///
+/// # Example
+///
+/// ```
+/// let explanation = playground_api::explain::error_code("E0382").unwrap();
+/// assert!(explanation.summary.contains("moved"));
+/// ```
+pub fn error_code(code: &str) -> Option<Explanation> {
+    let code = code.trim().to_uppercase();
+    let (_, text) = find_entry(ERROR_CODES, &code)?;
+
+    let summary = text
+        .split("\n\n")
+        .find(|paragraph| !paragraph.starts_with('#'))
+        .unwrap_or_default()
+        .replace('\n', " ");
+    Some(Explanation {
+        kind: ExplanationKind::ErrorCode,
+        url: format!("https://doc.rust-lang.org/{VERSION}/error_codes/{code}.html"),
+        name: code,
+        summary,
+        text: text.to_owned(),
+        group: None,
+        default_level: None,
+    })
+}
+
+/// Looks up the explanation of a Clippy lint.
+///
+/// Accepts the name with or without the `clippy::` prefix and with either
+/// underscores or dashes, e.g. `clippy::redundant_clone` or `redundant-clone`.
+pub fn clippy_lint(name: &str) -> Option<Explanation> {
+    let name = name.trim().trim_start_matches("clippy::").replace('-', "_");
+    let (fields, text) = find_entry(CLIPPY_LINTS, &name)?;
+
+    let mut fields = fields.split_whitespace();
+    let group = fields.next().filter(|group| *group != "-");
+    let default_level = fields.next();
+    let (summary, text) = text.split_once("\n\n").unwrap_or((text, ""));
+    Some(Explanation {
+        kind: ExplanationKind::ClippyLint,
+        url: format!("https://rust-lang.github.io/rust-clippy/rust-{VERSION}/index.html#{name}"),
+        name: format!("clippy::{name}"),
+        summary: summary.to_owned(),
+        text: text.to_owned(),
+        group: group.map(str::to_owned),
+        default_level: default_level.map(str::to_owned),
+    })
+}
+
+/// Looks up an error code (`E0382`) or a Clippy lint (`clippy::len_zero`).
+pub fn lookup(name: &str) -> Option<Explanation> {
+    error_code(name).or_else(|| clippy_lint(name))
+}
+
+/// Returns the explanation for the error code or Clippy lint of a diagnostic.
+pub fn for_diagnostic(diagnostic: &Diagnostic) -> Option<Explanation> {
+    if let Some(code) = &diagnostic.code {
+        return error_code(code);
+    }
+    Lint::from_diagnostic(diagnostic)
+        .filter(Lint::is_clippy)
+        .and_then(|lint| clippy_lint(&lint.name))
+}
+
+/// Returns the explanations for every error code and Clippy lint in the given
+/// compiler output, in order of first appearance and without duplicates.
+pub fn from_stderr(stderr: &str) -> Vec<Explanation> {
+    let mut explanations: Vec<Explanation> = Vec::new();
+    for explanation in diagnostics::parse(stderr).iter().filter_map(for_diagnostic) {
+        if !explanations.iter().any(|e| e.name == explanation.name) {
+            explanations.push(explanation);
+        }
+    }
+    explanations
+}
+
+/// Finds the entry with the given name in a database file.
+///
+/// Entries start with a header line `@@ <name> <fields...>` followed by the text.
+/// Returns the remaining header fields and the text.
+fn find_entry<'a>(data: &'a str, name: &str) -> Option<(&'a str, &'a str)> {
+    data.strip_prefix("@@ ")?.split("\n@@ ").find_map(|entry| {
+        let (header, text) = entry.split_once('\n').unwrap_or((entry, ""));
+        let (entry_name, fields) = header.split_once(' ').unwrap_or((header, ""));
+        (entry_name == name).then_some((fields, text.trim_end()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_codes() {
+        let explanation = error_code("e0382").unwrap();
+        assert_eq!(explanation.name, "E0382");
+        assert_eq!(
+            explanation.summary,
+            "A variable was used after its contents have been moved elsewhere."
+        );
+        assert!(explanation.text.contains("Erroneous code example"));
+        assert_eq!(
+            explanation.url,
+            format!("https://doc.rust-lang.org/{VERSION}/error_codes/E0382.html")
+        );
+
+        // Codes which are no longer emitted start with a note.
+        assert!(!error_code("E0001").unwrap().summary.starts_with('#'));
+        assert!(error_code("E9999").is_none());
+    }
+
+    #[test]
+    fn clippy_lints() {
+        let explanation = clippy_lint("redundant-clone").unwrap();
+        assert_eq!(explanation.name, "clippy::redundant_clone");
+        assert_eq!(explanation.group.as_deref(), Some("nursery"));
+        assert_eq!(explanation.default_level.as_deref(), Some("allow"));
+        assert!(explanation.text.starts_with("### What it does"));
+
+        assert_eq!(
+            lookup("clippy::needless_return").unwrap().group.as_deref(),
+            Some("style")
+        );
+        assert!(clippy_lint("not_a_lint").is_none());
+    }
+
+    #[test]
+    fn explains_stderr() {
+        let names: Vec<_> = from_stderr(include_str!("../test-outputs/errors.stderr"))
+            .into_iter()
+            .map(|explanation| explanation.name)
+            .collect();
+        assert_eq!(names, ["E0425", "E0433", "E0308"]);
+
+        let names: Vec<_> = from_stderr(include_str!("../test-outputs/clippy.stderr"))
+            .into_iter()
+            .map(|explanation| explanation.name)
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "clippy::needless_return",
+                "clippy::len_zero",
+                "clippy::useless_vec"
+            ]
+        );
+    }
+}
@@ -0,0 +1,290 @@
+//! Compiling the same code to several targets at once.
+//!
+//! [`ExploreOptions`] selects the targets and the shared settings, and
+//! [`ExploreResponse`] collects the result of each target. A failing target,
+//! e.g. Wasm for code the Wasm backend can't compile, doesn't affect the others.
+
+use crate::{endpoints::*, error::Error, outcome::Outcome, snippet::Snippet};
+
+/// All compile targets, in the order they are reported.
+pub const ALL_TARGETS: [CompileTarget; 5] = [
+    CompileTarget::Assembly,
+    CompileTarget::LlvmIr,
+    CompileTarget::Mir,
+    CompileTarget::Hir,
+    CompileTarget::Wasm,
+];
+
+/// The targets and settings for exploring a piece of code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExploreOptions {
+    /// The targets to compile to. Duplicates are compiled once.
+    pub targets: Vec<CompileTarget>,
+
+    /// The Rust release channel to use (stable, beta, nightly).
+    pub channel: Channel,
+
+    /// The compilation mode: debug or release.
+    pub mode: Mode,
+
+    /// The Rust edition to use (2015, 2018, 2021, 2024).
+    pub edition: Edition,
+
+    /// The crate type: binary or library.
+    pub crate_type: CrateType,
+
+    /// The flavor of the assembly output.
+    pub assembly_flavor: AssemblyFlavor,
+}
+
+impl ExploreOptions {
+    /// Creates options for the given targets with the default settings.
+    pub fn new(targets: impl IntoIterator<Item = CompileTarget>) -> Self {
+        Self {
+            targets: targets.into_iter().collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Returns the compile requests for `code`, one per distinct target.
+    ///
+    /// The HIR target requires nightly, so its request always uses the nightly
+    /// channel, like [`Client::desugar`](crate::Client::desugar).
+    pub fn requests(&self, code: &str) -> Vec<CompileRequest> {
+        let snippet = Snippet {
+            code: code.to_owned(),
+            channel: self.channel,
+            mode: self.mode,
+            edition: self.edition,
+            crate_type: self.crate_type,
+            ..Snippet::default()
+        };
+
+        let mut targets: Vec<CompileTarget> = Vec::new();
+        for target in &self.targets {
+            if !targets.contains(target) {
+                targets.push(*target);
+            }
+        }
+        targets
+            .into_iter()
+            .map(|target| match target {
+                CompileTarget::Assembly => snippet.assembly_request(self.assembly_flavor),
+                CompileTarget::Hir => CompileRequest {
+                    channel: Channel::Nightly,
+                    ..snippet.compile_request(CompileTarget::Hir)
+                },
+                target => snippet.compile_request(target),
+            })
+            .collect()
+    }
+}
+
+impl Default for ExploreOptions {
+    /// Provides options for [`ALL_TARGETS`] with the settings of [`Snippet::default`] and
+    /// AT&T assembly.
+    fn default() -> Self {
+        let snippet = Snippet::default();
+        Self {
+            targets: ALL_TARGETS.to_vec(),
+            channel: snippet.channel,
+            mode: snippet.mode,
+            edition: snippet.edition,
+            crate_type: snippet.crate_type,
+            assembly_flavor: AssemblyFlavor::Att,
+        }
+    }
+}
+
+/// The result of compiling code to each of the selected targets.
+///
+/// A field is `None` if its target wasn't selected. Each selected target has its
+/// own result, so one failing target doesn't hide the output of the others. The
+/// fields hold the raw results: a response for code which doesn't compile is
+/// still `Ok`, see [`CompileResponse::into_result`]. The accessors like
+/// [`responses`](Self::responses) and [`errors`](Self::errors) treat such a
+/// response as a failure.
+#[derive(Debug, Default)]
+pub struct ExploreResponse {
+    /// The result for [`CompileTarget::Assembly`].
+    pub assembly: Option<Result<CompileResponse, Error>>,
+
+    /// The result for [`CompileTarget::LlvmIr`].
+    pub llvm_ir: Option<Result<CompileResponse, Error>>,
+
+    /// The result for [`CompileTarget::Mir`].
+    pub mir: Option<Result<CompileResponse, Error>>,
+
+    /// The result for [`CompileTarget::Hir`].
+    pub hir: Option<Result<CompileResponse, Error>>,
+
+    /// The result for [`CompileTarget::Wasm`].
+    pub wasm: Option<Result<CompileResponse, Error>>,
+}
+
+impl ExploreResponse {
+    /// Collects the results of the requests returned by [`ExploreOptions::requests`].
+    pub(crate) fn new(
+        results: impl IntoIterator<Item = (CompileTarget, Result<CompileResponse, Error>)>,
+    ) -> Self {
+        let mut response = Self::default();
+        for (target, result) in results {
+            *response.slot(target) = Some(result);
+        }
+        response
+    }
+
+    /// Returns the result for a target, or `None` if it wasn't selected.
+    pub fn get(&self, target: CompileTarget) -> Option<&Result<CompileResponse, Error>> {
+        match target {
+            CompileTarget::Assembly => self.assembly.as_ref(),
+            CompileTarget::LlvmIr => self.llvm_ir.as_ref(),
+            CompileTarget::Mir => self.mir.as_ref(),
+            CompileTarget::Hir => self.hir.as_ref(),
+            CompileTarget::Wasm => self.wasm.as_ref(),
+        }
+    }
+
+    /// Returns the response for a target if it was selected and the code compiled.
+    pub fn response(&self, target: CompileTarget) -> Option<&CompileResponse> {
+        self.get(target)?
+            .as_ref()
+            .ok()
+            .filter(|response| response.success)
+    }
+
+    /// Returns the responses of the targets the code compiled to.
+    pub fn responses(&self) -> impl Iterator<Item = (CompileTarget, &CompileResponse)> {
+        ALL_TARGETS
+            .into_iter()
+            .filter_map(|target| Some((target, self.response(target)?)))
+    }
+
+    /// Returns the errors of the targets whose requests failed or whose code didn't
+    /// compile.
+    pub fn errors(&self) -> impl Iterator<Item = (CompileTarget, TargetError<'_>)> {
+        ALL_TARGETS.into_iter().filter_map(|target| {
+            let error = match self.get(target)? {
+                Err(error) => TargetError::Request(error),
+                Ok(response) if !response.success => TargetError::Compile(response),
+                Ok(_) => return None,
+            };
+            Some((target, error))
+        })
+    }
+
+    /// Returns `true` if the code compiled to every selected target.
+    pub fn is_complete(&self) -> bool {
+        self.errors().next().is_none()
+    }
+
+    fn slot(&mut self, target: CompileTarget) -> &mut Option<Result<CompileResponse, Error>> {
+        match target {
+            CompileTarget::Assembly => &mut self.assembly,
+            CompileTarget::LlvmIr => &mut self.llvm_ir,
+            CompileTarget::Mir => &mut self.mir,
+            CompileTarget::Hir => &mut self.hir,
+            CompileTarget::Wasm => &mut self.wasm,
+        }
+    }
+}
+
+/// Why a target of an [`ExploreResponse`] has no output.
+#[derive(Debug, Clone, Copy)]
+pub enum TargetError<'a> {
+    /// The request failed, e.g. on validation or a network error.
+    Request(&'a Error),
+
+    /// The code didn't compile to the target. The response holds the compiler output.
+    Compile(&'a CompileResponse),
+}
+
+impl TargetError<'_> {
+    /// Returns the outcome of a failed compilation, or `None` if the request failed.
+    pub fn outcome(&self) -> Option<Outcome> {
+        match self {
+            Self::Request(_) => None,
+            Self::Compile(response) => Some(response.outcome()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(success: bool, code: &str, stderr: &str) -> CompileResponse {
+        serde_json::from_value(serde_json::json!({
+            "success": success,
+            "exitDetail": "",
+            "code": code,
+            "stdout": "",
+            "stderr": stderr,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn builds_one_request_per_target() {
+        let options = ExploreOptions {
+            assembly_flavor: AssemblyFlavor::Intel,
+            mode: Mode::Release,
+            ..ExploreOptions::new([
+                CompileTarget::Mir,
+                CompileTarget::Assembly,
+                CompileTarget::Mir,
+            ])
+        };
+        let requests = options.requests("fn main() {}");
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].target, CompileTarget::Mir);
+        assert_eq!(requests[0].assembly_flavor, None);
+        assert_eq!(requests[1].target, CompileTarget::Assembly);
+        assert_eq!(requests[1].assembly_flavor, Some(AssemblyFlavor::Intel));
+        assert!(requests
+            .iter()
+            .all(|r| r.mode == Mode::Release && r.code == "fn main() {}"));
+
+        let requests = ExploreOptions::default().requests("fn main() {}");
+        assert_eq!(requests.len(), 5);
+        assert!(requests.iter().all(|r| r.validate().is_ok()));
+        assert_eq!(requests[3].target, CompileTarget::Hir);
+        assert_eq!(requests[3].channel, Channel::Nightly);
+        assert_eq!(requests[2].channel, Channel::Stable);
+    }
+
+    #[test]
+    fn keeps_partial_results() {
+        let response = ExploreResponse::new([
+            (
+                CompileTarget::Mir,
+                Ok(response(true, "fn main() -> () {}", "")),
+            ),
+            (
+                CompileTarget::Hir,
+                Ok(response(
+                    false,
+                    "",
+                    include_str!("../test-outputs/errors.stderr"),
+                )),
+            ),
+        ]);
+
+        assert!(response.assembly.is_none());
+        assert_eq!(
+            response.response(CompileTarget::Mir).unwrap().code,
+            "fn main() -> () {}"
+        );
+        assert!(response.response(CompileTarget::Hir).is_none());
+        assert!(response.get(CompileTarget::Hir).is_some());
+        assert!(!response.is_complete());
+
+        let ok: Vec<_> = response.responses().map(|(target, _)| target).collect();
+        assert_eq!(ok, [CompileTarget::Mir]);
+        let failed: Vec<_> = response
+            .errors()
+            .map(|(target, error)| (target, error.outcome()))
+            .collect();
+        assert_eq!(failed, [(CompileTarget::Hir, Some(Outcome::CompileError))]);
+    }
+}
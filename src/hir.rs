@@ -0,0 +1,709 @@
+//! Exploring the HIR output of [`CompileTarget::Hir`](crate::endpoints::CompileTarget::Hir).
+//!
+//! The HIR shows how `for` loops, `?`, `async`/`await` and closures desugar, but
+//! the output for a whole snippet is long. [`select`] finds the HIR of a single
+//! item or of the items in a range of source lines and cleans it up: absolute
+//! paths are shortened and the lang items introduced by desugaring are listed
+//! with what they desugar from.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Which part of the HIR to select.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Selection {
+    /// An item by name, e.g. `parse_sum` or `Point`. Selecting a type also
+    /// selects its `impl` blocks, including derived ones.
+    Item(String),
+
+    /// The items overlapping a range of source lines, 1-based and inclusive.
+    Lines {
+        /// The first line.
+        start: usize,
+
+        /// The last line.
+        end: usize,
+    },
+}
+
+/// A top-level item of Rust source or HIR output.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Item {
+    /// The item keyword, e.g. `fn`, `struct` or `impl`.
+    pub kind: String,
+
+    /// The item name, e.g. `parse_sum`. For `impl` blocks, the header after
+    /// `impl`, e.g. `Display for Point`.
+    pub name: String,
+
+    /// The first line of the item including its attributes, 1-based.
+    pub start_line: usize,
+
+    /// The last line of the item.
+    pub end_line: usize,
+
+    /// The item as printed, including its attributes and doc comments.
+    pub text: String,
+}
+
+impl Item {
+    /// Returns `true` for impls generated by `#[derive]`.
+    pub fn is_derived(&self) -> bool {
+        self.text
+            .lines()
+            .take_while(|line| !line.starts_with("impl"))
+            .any(|line| {
+                line.contains("AutomaticallyDerived") || line.contains("automatically_derived")
+            })
+    }
+
+    /// Returns the type an `impl` block is for, e.g. `Point` for
+    /// `Display for Point` or `Point<T>`.
+    fn self_type(&self) -> Option<&str> {
+        if self.kind != "impl" {
+            return None;
+        }
+        let ty = self.name.rsplit(" for ").next().unwrap_or(&self.name);
+        // Skip generic parameters like `<T>` in `impl<T> Foo<T>`.
+        let ty = match ty.strip_prefix('<') {
+            Some(rest) => rest.split_once("> ").map_or(rest, |(_, ty)| ty),
+            None => ty,
+        };
+        let ty = ty.rsplit("::").next().unwrap_or(ty);
+        Some(ty.split(['<', ' ']).next().unwrap_or(ty))
+    }
+}
+
+/// A lang item which desugaring introduced into the HIR.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LangItem {
+    /// The name as printed in the HIR, e.g. `branch`.
+    pub name: String,
+
+    /// The full path, e.g. `core::ops::Try::branch`.
+    pub path: String,
+
+    /// The syntax it comes from, e.g. "the `?` operator".
+    pub desugars: String,
+}
+
+/// The cleaned-up HIR of an item.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HirFragment {
+    /// The item keyword, e.g. `fn`.
+    pub kind: String,
+
+    /// The item name with shortened paths, see [`Item::name`].
+    pub name: String,
+
+    /// The cleaned-up HIR, see [`clean`].
+    pub hir: String,
+
+    /// The lang items used in the HIR, in order of first appearance.
+    pub lang_items: Vec<LangItem>,
+}
+
+impl HirFragment {
+    fn new(item: &Item) -> Self {
+        let hir = clean(&item.text);
+        Self {
+            kind: item.kind.clone(),
+            name: shorten_paths(&item.name),
+            lang_items: lang_items(&hir),
+            hir,
+        }
+    }
+}
+
+impl fmt::Display for HirFragment {
+    /// Renders the HIR preceded by a comment for each lang item, e.g.
+    /// `` // branch: core::ops::Try::branch (from the `?` operator) ``.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for item in &self.lang_items {
+            writeln!(
+                f,
+                "// {}: {} (from {})",
+                item.name, item.path, item.desugars
+            )?;
+        }
+        f.write_str(&self.hir)
+    }
+}
+
+/// Lang items inserted by desugaring: the text to look for, the name, the path
+/// and what it desugars from.
+const LANG_ITEMS: &[(&str, &str, &str, &str)] = &[
+    (
+        "into_iter(",
+        "into_iter",
+        "core::iter::IntoIterator::into_iter",
+        "a `for` loop",
+    ),
+    (
+        "next(",
+        "next",
+        "core::iter::Iterator::next",
+        "a `for` loop",
+    ),
+    (
+        "branch(",
+        "branch",
+        "core::ops::Try::branch",
+        "the `?` operator",
+    ),
+    (
+        "from_residual(",
+        "from_residual",
+        "core::ops::FromResidual::from_residual",
+        "the `?` operator",
+    ),
+    (
+        "from_output(",
+        "from_output",
+        "core::ops::Try::from_output",
+        "a `try` block",
+    ),
+    (
+        "into_future(",
+        "into_future",
+        "core::future::IntoFuture::into_future",
+        "`.await`",
+    ),
+    ("poll(", "poll", "core::future::Future::poll", "`.await`"),
+    (
+        "get_context(",
+        "get_context",
+        "core::future::get_context",
+        "`.await`",
+    ),
+    (
+        "new_unchecked(",
+        "new_unchecked",
+        "core::pin::Pin::new_unchecked",
+        "`.await`",
+    ),
+    (
+        "ResumeTy",
+        "ResumeTy",
+        "core::future::ResumeTy",
+        "an `async` fn or block",
+    ),
+    (
+        "format_argument::",
+        "format_argument",
+        "core::fmt::rt::Argument",
+        "`format_args!`",
+    ),
+    (
+        "format_arguments::",
+        "format_arguments",
+        "core::fmt::Arguments",
+        "`format_args!`",
+    ),
+    (
+        "range_inclusive_new(",
+        "range_inclusive_new",
+        "core::ops::RangeInclusive::new",
+        "a `..=` range",
+    ),
+];
+
+/// Splits Rust source or HIR output into its top-level items.
+///
+/// Attributes and doc comments are part of the item they precede.
+pub fn items(text: &str) -> Vec<Item> {
+    let mut items = Vec::new();
+    let mut lines: Vec<&str> = Vec::new();
+    let mut start_line = 0;
+    let mut depth = 0isize;
+
+    for (idx, line) in text.lines().enumerate() {
+        if lines.is_empty() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            start_line = idx + 1;
+        }
+        lines.push(line);
+
+        let trimmed = line.trim();
+        let is_attribute = depth == 0
+            && (trimmed.starts_with("#[") || trimmed.starts_with("#!["))
+            && trimmed.ends_with(']');
+        let is_comment = depth == 0 && trimmed.starts_with("//");
+        if is_attribute || is_comment {
+            continue;
+        }
+
+        depth += brace_depth(line);
+        let code = strip_line_comment(line).trim_end();
+        if depth <= 0 && (code.ends_with('}') || code.ends_with(';')) {
+            if let Some((kind, name)) = lines.iter().find_map(|line| item_header(line)) {
+                items.push(Item {
+                    kind,
+                    name,
+                    start_line,
+                    end_line: idx + 1,
+                    text: lines.join("\n"),
+                });
+            }
+            lines.clear();
+            depth = 0;
+        }
+    }
+    items
+}
+
+/// Cleans up HIR for reading.
+///
+/// Absolute paths are shortened to the type or function name, e.g.
+/// `::core::fmt::Formatter::debug_struct` to `Formatter::debug_struct`, old-style
+/// `#[lang = "branch"]` calls are printed like plain function calls and the
+/// `#[allow(unreachable_code)]` attributes of desugared `?` are removed.
+///
+/// # Example
+///
+/// ```
+/// let hir = "match #[lang = \"branch\"](x) { }";
+/// assert_eq!(playground_api::hir::clean(hir), "match branch(x) { }");
+/// ```
+pub fn clean(hir: &str) -> String {
+    let mut text = hir.replace("#[allow(unreachable_code)]\n", "\n");
+    text = text.replace("#[allow(unreachable_code)] ", "");
+    text = text.replace("#[allow(unreachable_code)]", "");
+
+    // `#[lang = "branch"](x)` becomes `branch(x)`.
+    while let Some(start) = text.find("#[lang = \"") {
+        let rest = &text[start + "#[lang = \"".len()..];
+        let Some(end) = rest.find("\"]") else {
+            break;
+        };
+        let name = rest[..end].to_owned();
+        text.replace_range(start..start + "#[lang = \"".len() + end + 2, &name);
+    }
+
+    // Remove the trailing whitespace left by the removed attributes.
+    let text: Vec<&str> = text.lines().map(str::trim_end).collect();
+    shorten_paths(&text.join("\n"))
+}
+
+/// Returns the lang items used in (cleaned) HIR, in order of first appearance.
+pub fn lang_items(hir: &str) -> Vec<LangItem> {
+    let mut found: Vec<_> = LANG_ITEMS
+        .iter()
+        .filter_map(|entry| {
+            let position = hir.match_indices(entry.0).find_map(|(idx, _)| {
+                let previous = hir[..idx].chars().next_back();
+                let is_path_start =
+                    previous.is_none_or(|c| !(c.is_alphanumeric() || c == '_' || c == '.'));
+                is_path_start.then_some(idx)
+            })?;
+            Some((position, entry))
+        })
+        .collect();
+    found.sort_by_key(|(position, _)| *position);
+    found
+        .into_iter()
+        .map(|(_, (_, name, path, desugars))| LangItem {
+            name: (*name).to_owned(),
+            path: (*path).to_owned(),
+            desugars: (*desugars).to_owned(),
+        })
+        .collect()
+}
+
+/// Selects the HIR of an item or a range of source lines.
+///
+/// HIR output has no spans, so source lines are mapped to items by name, and
+/// `impl` blocks by their order. Selecting a type includes its `impl` blocks and
+/// the impls generated by `#[derive]`.
+///
+/// # Arguments
+///
+/// * `hir` - The HIR output.
+/// * `source` - The code the HIR was generated from. Only used for [`Selection::Lines`].
+/// * `selection` - The item or lines to select.
+///
+/// # Returns
+///
+/// The cleaned-up fragments in HIR order, empty if nothing matched.
+pub fn select(hir: &str, source: &str, selection: &Selection) -> Vec<HirFragment> {
+    let hir_items = items(hir);
+    let impls: Vec<&Item> = hir_items
+        .iter()
+        .filter(|item| item.kind == "impl" && !item.is_derived())
+        .collect();
+    let mut selected: Vec<&Item> = Vec::new();
+
+    match selection {
+        Selection::Item(name) => {
+            let name = name.trim();
+            for item in hir_items.iter().filter(|item| item.kind != "impl") {
+                if item.name == name {
+                    select_matching(&hir_items, item, &mut selected);
+                }
+            }
+            for item in &impls {
+                if item.name == name && !selected.contains(item) {
+                    selected.push(item);
+                }
+            }
+        }
+        Selection::Lines { start, end } => {
+            let source_items = items(source);
+            let mut source_impls = 0;
+            for item in &source_items {
+                let overlaps = item.start_line <= *end && item.end_line >= *start;
+                if item.kind == "impl" {
+                    if overlaps {
+                        if let Some(hir_impl) = impls.get(source_impls) {
+                            if !selected.contains(hir_impl) {
+                                selected.push(hir_impl);
+                            }
+                        }
+                    }
+                    source_impls += 1;
+                } else if overlaps {
+                    select_matching(&hir_items, item, &mut selected);
+                }
+            }
+        }
+    }
+
+    hir_items
+        .iter()
+        .filter(|item| selected.contains(item))
+        .map(HirFragment::new)
+        .collect()
+}
+
+/// Adds the HIR items matching a source item to `selected`: items of the same kind and
+/// name and, for types, all their `impl` blocks.
+fn select_matching<'a>(hir_items: &'a [Item], item: &Item, selected: &mut Vec<&'a Item>) {
+    let is_type = matches!(item.kind.as_str(), "struct" | "enum" | "union" | "trait");
+    for candidate in hir_items {
+        let matches = if item.kind == "impl" {
+            false
+        } else if candidate.kind == "impl" {
+            is_type && candidate.self_type() == Some(item.name.as_str())
+        } else {
+            candidate.kind == item.kind && candidate.name == item.name
+        };
+        if matches && !selected.contains(&candidate) {
+            selected.push(candidate);
+        }
+    }
+}
+
+/// Returns the item keyword and name of an item's first line, or `None` for
+/// attributes, comments and lines which don't start an item.
+fn item_header(line: &str) -> Option<(String, String)> {
+    let line = line.trim();
+    if line.starts_with('#') || line.starts_with("//") {
+        return None;
+    }
+
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let is_modifier = |idx: usize| {
+        let word = words[idx];
+        matches!(word, "pub" | "async" | "unsafe" | "default" | "safe")
+            || word.starts_with("pub(")
+            || word.starts_with('"')
+            || (word == "extern" && line.contains("extern \"") && !line.contains(" crate "))
+            // `const fn` and `const unsafe fn`, as opposed to a constant item.
+            || (word == "const"
+                && matches!(
+                    words.get(idx + 1),
+                    Some(&("fn" | "unsafe" | "async" | "extern"))
+                ))
+    };
+    let start = (0..words.len())
+        .find(|&idx| !is_modifier(idx))
+        .unwrap_or(words.len());
+    let mut words = words[start..].iter().copied();
+
+    let kind = words.next()?;
+    let identifier = |word: Option<&str>| -> Option<String> {
+        let word = word?;
+        let end = word
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(word.len());
+        (end > 0).then(|| word[..end].to_owned())
+    };
+
+    let name = match kind {
+        "fn" | "struct" | "enum" | "union" | "trait" | "mod" | "type" | "const" => {
+            identifier(words.next())?
+        }
+        "static" => {
+            let word = words.next();
+            identifier(if word == Some("mut") {
+                words.next()
+            } else {
+                word
+            })?
+        }
+        "impl" => {
+            let header = line[line.find("impl")? + "impl".len()..]
+                .split('{')
+                .next()
+                .unwrap_or_default();
+            header.split_whitespace().collect::<Vec<_>>().join(" ")
+        }
+        "use" => line["use".len()..].trim().trim_end_matches(';').to_owned(),
+        "extern" => {
+            // `extern crate std;`
+            words.next();
+            identifier(words.next())?
+        }
+        "macro_rules!" => identifier(words.next())?,
+        _ => return None,
+    };
+    Some((kind.trim_end_matches('!').to_owned(), name))
+}
+
+/// Returns the change in brace depth of a line, ignoring braces in strings,
+/// character literals and comments.
+fn brace_depth(line: &str) -> isize {
+    let mut depth = 0;
+    let mut chars = strip_line_comment(line).chars().peekable();
+    let mut in_string = false;
+    let mut previous = ' ';
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if in_string => {
+                chars.next();
+            }
+            '"' => in_string = !in_string,
+            '\'' if !in_string && matches!(chars.peek(), Some('{' | '}')) => {
+                // A character literal like `'{'`.
+                chars.next();
+            }
+            '{' if !in_string && previous != '\'' => depth += 1,
+            '}' if !in_string && previous != '\'' => depth -= 1,
+            _ => {}
+        }
+        previous = c;
+    }
+    depth
+}
+
+/// Removes a `//` comment outside of string literals.
+fn strip_line_comment(line: &str) -> &str {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (idx, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            '/' if !in_string && line[idx..].starts_with("//") => return &line[..idx],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Shortens absolute paths like `::core::fmt::Formatter::new` to the part starting
+/// at the first type, `Formatter::new`, or to the last segment if there is no type.
+fn shorten_paths(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("::") {
+        let previous = rest[..start]
+            .chars()
+            .next_back()
+            .or_else(|| output.chars().next_back());
+        let is_absolute = previous.is_none_or(|c| !(c.is_alphanumeric() || c == '_' || c == '>'));
+        if !is_absolute {
+            output.push_str(&rest[..start + 2]);
+            rest = &rest[start + 2..];
+            continue;
+        }
+        output.push_str(&rest[..start]);
+
+        let mut segments = Vec::new();
+        let mut end = start;
+        while let Some(after) = rest[end..].strip_prefix("::") {
+            let length = after
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(after.len());
+            if length == 0 {
+                break;
+            }
+            segments.push(&after[..length]);
+            end += 2 + length;
+        }
+        if segments.is_empty() {
+            output.push_str("::");
+            rest = &rest[start + 2..];
+            continue;
+        }
+
+        let first_type = segments
+            .iter()
+            .position(|segment| segment.starts_with(|c: char| c.is_uppercase()))
+            .unwrap_or(segments.len() - 1);
+        output.push_str(&segments[first_type..].join("::"));
+        rest = &rest[end..];
+    }
+    output.push_str(rest);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HIR: &str = include_str!("../test-outputs/hir.hir");
+    const SOURCE: &str = include_str!("../test-outputs/hir-source.rs");
+
+    #[test]
+    fn splits_items() {
+        let kinds: Vec<_> = items(HIR)
+            .into_iter()
+            .map(|item| format!("{} {}", item.kind, item.name))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                "extern std",
+                "use std::prelude::rust_2024::*",
+                "use std::num::ParseIntError",
+                "struct Point",
+                "impl ::core::fmt::Debug for Point",
+                "impl Point",
+                "fn parse_sum",
+                "fn double",
+                "fn run",
+                "fn main",
+                "fn add"
+            ]
+        );
+
+        let source = items(SOURCE);
+        let point = source.iter().find(|item| item.name == "Point").unwrap();
+        assert_eq!((point.start_line, point.end_line), (3, 8));
+        assert!(point
+            .text
+            .starts_with("/// A point in 2D.\n#[derive(Debug)]\nstruct Point {"));
+    }
+
+    #[test]
+    fn selects_items() {
+        let fragments = select(HIR, SOURCE, &Selection::Item("parse_sum".to_owned()));
+        assert_eq!(fragments.len(), 1);
+        let parse_sum = &fragments[0];
+        assert!(parse_sum.hir.contains("Break {  0: residual } =>\n"));
+        assert!(parse_sum.hir.contains("return from_residual(residual),"));
+        assert!(!parse_sum.hir.contains("unreachable_code"));
+
+        let names: Vec<_> = parse_sum
+            .lang_items
+            .iter()
+            .map(|i| i.name.as_str())
+            .collect();
+        assert_eq!(names, ["into_iter", "next", "branch", "from_residual"]);
+        assert!(parse_sum.to_string().starts_with(
+            "// into_iter: core::iter::IntoIterator::into_iter (from a `for` loop)\n"
+        ));
+
+        let run = &select(HIR, SOURCE, &Selection::Item("run".to_owned()))[0];
+        let names: Vec<_> = run.lang_items.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "ResumeTy",
+                "into_future",
+                "poll",
+                "new_unchecked",
+                "get_context"
+            ]
+        );
+
+        // Types come with their impls, derived ones included.
+        let point = select(HIR, SOURCE, &Selection::Item("Point".to_owned()));
+        let names: Vec<_> = point.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["Point", "Debug for Point", "Point"]);
+        assert!(point[1]
+            .hir
+            .contains("Formatter::debug_struct_field2_finish(f, \"Point\""));
+        assert!(point[1]
+            .hir
+            .contains("fn fmt(&self, f: &'_ mut Formatter<'_>)"));
+
+        let add = select(HIR, SOURCE, &Selection::Item("add".to_owned()));
+        assert_eq!(add.len(), 1);
+        assert_eq!(add[0].kind, "fn");
+        assert_eq!(add[0].hir, "const fn add(a: i32, b: i32) -> i32 { a + b }");
+    }
+
+    #[test]
+    fn selects_lines() {
+        // The body of `Point::sum`.
+        let fragments = select(HIR, SOURCE, &Selection::Lines { start: 12, end: 12 });
+        assert_eq!(fragments.len(), 1);
+        assert_eq!(
+            fragments[0].hir,
+            "impl Point {\n    fn sum(&self) -> i32 { self.x + self.y }\n}"
+        );
+
+        // From the end of `double` to the start of `run`.
+        let fragments = select(HIR, SOURCE, &Selection::Lines { start: 25, end: 29 });
+        let names: Vec<_> = fragments.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["double", "run"]);
+
+        // The body of the `const fn`.
+        let fragments = select(HIR, SOURCE, &Selection::Lines { start: 41, end: 41 });
+        let names: Vec<_> = fragments.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["add"]);
+
+        assert!(select(
+            HIR,
+            SOURCE,
+            &Selection::Lines {
+                start: 200,
+                end: 300
+            }
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn parses_item_headers() {
+        let header = |line| item_header(line).map(|(kind, name)| format!("{kind} {name}"));
+        assert_eq!(
+            header("const fn add(a: i32, b: i32) -> i32 {").as_deref(),
+            Some("fn add")
+        );
+        assert_eq!(
+            header("pub const unsafe fn raw() {}").as_deref(),
+            Some("fn raw")
+        );
+        assert_eq!(
+            header("const extern \"C\" fn callback() {}").as_deref(),
+            Some("fn callback")
+        );
+        assert_eq!(
+            header("const MAX: usize = 3;").as_deref(),
+            Some("const MAX")
+        );
+        assert_eq!(header("pub const F: fn() = f;").as_deref(), Some("const F"));
+    }
+
+    #[test]
+    fn cleans_old_syntax() {
+        let hir = "match ::core::ops::Try::branch(x) {\n    ::core::ops::ControlFlow::Break { 0: residual } => #[allow(unreachable_code)]\n        return ::std::io::_print(#[lang = \"format_arguments\"]::new(a)),\n}";
+        let cleaned = clean(hir);
+        assert_eq!(
+            cleaned,
+            "match Try::branch(x) {\n    ControlFlow::Break { 0: residual } =>\n        return _print(format_arguments::new(a)),\n}"
+        );
+        let names: Vec<_> = lang_items(&cleaned).into_iter().map(|i| i.name).collect();
+        assert_eq!(names, ["branch", "format_arguments"]);
+        assert_eq!(
+            shorten_paths("Vec::<u8>::new() ::core::option::Option::<u8>::None"),
+            "Vec::<u8>::new() Option::<u8>::None"
+        );
+    }
+}
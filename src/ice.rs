@@ -0,0 +1,308 @@
+//! Detection of internal compiler errors (ICEs) and bug report generation.
+//!
+//! When rustc crashes, it prints the ICE message, the panic with a backtrace, its
+//! version and the query stack instead of regular diagnostics. [`detect`] parses
+//! that output into an [`Ice`], and [`Ice::bug_report`] renders a Markdown report
+//! which can be filed at <https://github.com/rust-lang/rust/issues>.
+
+use crate::{
+    cargo_output,
+    diagnostics::{self, Span},
+    endpoints::ChannelVersion,
+    panics::{self, Frame, Location},
+    snippet::Snippet,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+/// The start of the message of the diagnostic rustc emits for an ICE.
+const ICE_PREFIX: &str = "internal compiler error";
+
+/// A frame of the query stack rustc prints after an ICE.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QueryFrame {
+    /// The query name, e.g. `typeck`.
+    pub query: String,
+
+    /// What the query was doing, e.g. "type-checking `main`".
+    pub description: String,
+}
+
+/// A parsed internal compiler error.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Ice {
+    /// The ICE message, or the panic message if rustc panicked without one.
+    pub message: String,
+
+    /// The code rustc was compiling when it crashed, if it was reported.
+    pub span: Option<Span>,
+
+    /// Where in the compiler the panic happened.
+    pub location: Option<Location>,
+
+    /// The compiler backtrace, empty if it wasn't printed.
+    pub backtrace: Vec<Frame>,
+
+    /// The query stack, innermost query first.
+    pub query_stack: Vec<QueryFrame>,
+
+    /// The rustc version printed with the ICE, e.g. `1.95.0 (59807616e 2026-04-14)`.
+    pub rustc_version: Option<String>,
+
+    /// The compiler flags printed with the ICE.
+    pub compiler_flags: Option<String>,
+}
+
+impl Ice {
+    /// Returns a title for the bug report, e.g. "ICE: struct or tuple struct pattern
+    /// not applied to an ADT".
+    pub fn title(&self) -> String {
+        // Strip the compiler source location rustc puts in front of `bug!` messages.
+        let message = match self.message.split_once(": ") {
+            Some((location, message)) if location.starts_with("compiler/") => message,
+            _ => &self.message,
+        };
+        format!("ICE: {}", message.lines().next().unwrap_or_default())
+    }
+
+    /// Renders a Markdown bug report following the rustc ICE issue template.
+    ///
+    /// # Arguments
+    ///
+    /// * `snippet` - The code and settings which caused the ICE.
+    /// * `versions` - The versions of the snippet's channel, see
+    ///   [`VersionsResponse::channel`](crate::endpoints::VersionsResponse::channel). If `None`,
+    ///   the version printed with the ICE is used.
+    ///
+    /// # Returns
+    ///
+    /// The body of the bug report. Use [`Ice::title`] for the issue title.
+    pub fn bug_report(&self, snippet: &Snippet, versions: Option<&ChannelVersion>) -> String {
+        let mut report = String::new();
+        let _ = writeln!(
+            report,
+            "### Code\n\n```rust\n{}\n```\n",
+            snippet.code.trim_end()
+        );
+
+        let _ = writeln!(
+            report,
+            "### Meta\n\n\
+             Reproduced on the [Rust playground](https://play.rust-lang.org/) using the \
+             `{}` channel, edition `{}` and `{}` mode.\n",
+            snippet.channel, snippet.edition, snippet.mode
+        );
+        match (versions, &self.rustc_version) {
+            (Some(versions), _) => {
+                let rustc = &versions.rustc;
+                let _ = writeln!(
+                    report,
+                    "`rustc --version --verbose`:\n```\nrustc {}\ncommit-hash: {}\ncommit-date: {}\n```\n",
+                    rustc.version, rustc.hash, rustc.date
+                );
+            }
+            (None, Some(version)) => {
+                let _ = writeln!(report, "`rustc --version`:\n```\nrustc {version}\n```\n");
+            }
+            (None, None) => {}
+        }
+
+        let _ = writeln!(report, "### Error output\n\n```");
+        let _ = writeln!(report, "error: internal compiler error: {}", self.message);
+        if let Some(span) = &self.span {
+            let _ = writeln!(report, " --> {}:{}:{}", span.file, span.line, span.column);
+        }
+        if let Some(location) = &self.location {
+            let _ = writeln!(report, "\nthread 'rustc' panicked at {location}");
+        }
+        if let Some(flags) = &self.compiler_flags {
+            let _ = writeln!(report, "\nnote: compiler flags: {flags}");
+        }
+        if !self.query_stack.is_empty() {
+            let _ = writeln!(report, "\nquery stack during panic:");
+            for (idx, frame) in self.query_stack.iter().enumerate() {
+                let _ = writeln!(report, "#{idx} [{}] {}", frame.query, frame.description);
+            }
+            let _ = writeln!(report, "end of query stack");
+        }
+        let _ = writeln!(report, "```");
+
+        if !self.backtrace.is_empty() {
+            let _ = writeln!(
+                report,
+                "\n<details><summary><strong>Backtrace</strong></summary>\n<p>\n\n```"
+            );
+            for (idx, frame) in self.backtrace.iter().enumerate() {
+                let _ = writeln!(report, "{idx:>4}: {}", frame.function);
+            }
+            let _ = writeln!(report, "```\n\n</p>\n</details>");
+        }
+
+        report
+    }
+}
+
+/// Returns `true` if the output contains an internal compiler error.
+pub(crate) fn is_ice(stderr: &str) -> bool {
+    stderr.contains("error: internal compiler error")
+        || stderr.contains("error: the compiler unexpectedly panicked")
+        || stderr
+            .lines()
+            .any(|line| line.starts_with("thread 'rustc' ") && line.contains(" panicked at "))
+}
+
+/// Detects an internal compiler error in the stderr of a response.
+///
+/// Returns `None` if the compiler didn't crash.
+///
+/// # Example
+///
+/// ```
+/// let stderr = "error: internal compiler error: unexpected type\n\nquery stack during panic:\n#0 [typeck] type-checking `main`\nend of query stack\n";
+/// let ice = playground_api::ice::detect(stderr).unwrap();
+///
+/// assert_eq!(ice.message, "unexpected type");
+/// assert_eq!(ice.query_stack[0].query, "typeck");
+/// ```
+pub fn detect(stderr: &str) -> Option<Ice> {
+    if !is_ice(stderr) {
+        return None;
+    }
+
+    let compiler = cargo_output::split(stderr).compiler.join("\n");
+    let diagnostic = diagnostics::parse(&compiler)
+        .into_iter()
+        .find(|diagnostic| diagnostic.message.starts_with(ICE_PREFIX));
+    let panic = panics::parse(&compiler)
+        .into_iter()
+        .find(|panic| panic.thread == "rustc");
+
+    let message = match (&diagnostic, &panic) {
+        (Some(diagnostic), _) => ice_message(&diagnostic.message).to_owned(),
+        (None, Some(panic)) => panic.message.clone(),
+        (None, None) => String::new(),
+    };
+    let note = |prefix: &str| {
+        compiler
+            .lines()
+            .find_map(|line| line.strip_prefix(prefix))
+            .map(str::to_owned)
+    };
+
+    Some(Ice {
+        message,
+        span: diagnostic.and_then(|diagnostic| diagnostic.span),
+        location: panic.as_ref().and_then(|panic| panic.location.clone()),
+        backtrace: panic.map(|panic| panic.frames).unwrap_or_default(),
+        query_stack: parse_query_stack(&compiler),
+        rustc_version: note("note: rustc ").map(|version| {
+            match version.split_once(" running on ") {
+                Some((version, _)) => version.to_owned(),
+                None => version,
+            }
+        }),
+        compiler_flags: note("note: compiler flags: "),
+    })
+}
+
+/// Strips the prefix of a message like "internal compiler error[E0308]: mismatched
+/// types", which has an error code if the ICE was forced with `-Z treat-err-as-bug`.
+fn ice_message(message: &str) -> &str {
+    let rest = &message[ICE_PREFIX.len()..];
+    let rest = match rest.strip_prefix('[') {
+        Some(code) => code.split_once(']').map_or(rest, |(_, rest)| rest),
+        None => rest,
+    };
+    rest.strip_prefix(": ").unwrap_or(rest)
+}
+
+/// Parses the lines between `query stack during panic:` and `end of query stack`.
+fn parse_query_stack(output: &str) -> Vec<QueryFrame> {
+    output
+        .lines()
+        .skip_while(|line| line.trim() != "query stack during panic:")
+        .skip(1)
+        .take_while(|line| line.trim() != "end of query stack")
+        .filter_map(|line| {
+            let (_, rest) = line.trim().strip_prefix('#')?.split_once(" [")?;
+            let (query, description) = rest.split_once("] ")?;
+            Some(QueryFrame {
+                query: query.to_owned(),
+                description: description.to_owned(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::{Channel, Version};
+
+    #[test]
+    fn detects_ice() {
+        let ice = detect(include_str!("../test-outputs/ice.stderr")).unwrap();
+        assert_eq!(ice.message, "mismatched types");
+        assert_eq!(ice.title(), "ICE: mismatched types");
+        let span = ice.span.as_ref().unwrap();
+        assert_eq!((span.line, span.column), (5, 18));
+        let location = ice.location.as_ref().unwrap();
+        assert!(location.file.ends_with("/compiler/rustc_errors/src/lib.rs"));
+        assert_eq!(location.line, 1531);
+
+        // The frame hidden by `[... omitted 1 frame ...]` doesn't end the backtrace.
+        assert_eq!(ice.backtrace.len(), 18);
+        assert_eq!(
+            ice.backtrace[8].function,
+            "<rustc_hir_typeck::fn_ctxt::FnCtxt>::check_decl"
+        );
+        assert_eq!(
+            ice.backtrace[17].function,
+            "rustc_interface::interface::run_compiler::<(), rustc_driver_impl::run_compiler::{closure#0}>::{closure#1}"
+        );
+        assert_eq!(ice.query_stack.len(), 2);
+        assert_eq!(ice.query_stack[0].description, "type-checking `main`");
+        assert_eq!(
+            ice.rustc_version.as_deref(),
+            Some("1.95.0 (59807616e 2026-04-14)")
+        );
+        assert!(ice
+            .compiler_flags
+            .unwrap()
+            .ends_with("-Z treat-err-as-bug=1"));
+
+        let bug = "error: internal compiler error: compiler/rustc_middle/src/ty/mod.rs:1:2: boom\n";
+        assert_eq!(detect(bug).unwrap().title(), "ICE: boom");
+        assert!(detect(include_str!("../test-outputs/errors.stderr")).is_none());
+    }
+
+    #[test]
+    fn renders_bug_report() {
+        let ice = detect(include_str!("../test-outputs/ice.stderr")).unwrap();
+        let mut snippet = Snippet::new("struct Foo(u8);\nfn main() {}\n");
+        snippet.channel = Channel::Nightly;
+
+        let version = |version: &str| Version {
+            version: version.to_owned(),
+            hash: "59807616e1fa2540724bfbac14d7976d7e4a3860".to_owned(),
+            date: "2026-04-14".to_owned(),
+        };
+        let versions = ChannelVersion {
+            rustc: version("1.95.0"),
+            rustfmt: version("1.9.0"),
+            clippy: version("0.1.95"),
+            miri: None,
+        };
+        let report = ice.bug_report(&snippet, Some(&versions));
+
+        assert!(report.starts_with("### Code\n\n```rust\nstruct Foo(u8);\nfn main() {}\n```\n"));
+        assert!(report.contains("using the `nightly` channel, edition `2024` and `debug` mode"));
+        assert!(report
+            .contains("rustc 1.95.0\ncommit-hash: 59807616e1fa2540724bfbac14d7976d7e4a3860\n"));
+        assert!(report.contains("#0 [typeck] type-checking `main`\n"));
+        assert!(report.contains("<summary><strong>Backtrace</strong></summary>"));
+
+        let report = ice.bug_report(&snippet, None);
+        assert!(report.contains("rustc 1.95.0 (59807616e 2026-04-14)"));
+    }
+}
@@ -0,0 +1,452 @@
+//! Inference of crate type, channel and edition from source code.
+//!
+//! The checks are purely textual: comments and string literals are blanked out
+//! first, then the remaining code is scanned for tell-tale syntax such as
+//! `fn main`, `#![feature(...)]` or edition-specific keywords. Every inferred
+//! value comes with the reason it was chosen.
+
+use crate::{
+    endpoints::{Channel, CrateType, Edition},
+    validation,
+};
+use serde::{Deserialize, Serialize};
+
+/// A value inferred from source code together with the reason it was chosen.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Inferred<T> {
+    /// The inferred value.
+    pub value: T,
+
+    /// A short human-readable explanation, e.g. "no `fn main` found".
+    pub reason: String,
+}
+
+impl<T> Inferred<T> {
+    fn new(value: T, reason: &str) -> Self {
+        Self {
+            value,
+            reason: reason.to_owned(),
+        }
+    }
+}
+
+/// The settings inferred for a piece of code.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Inference {
+    /// Binary if the code has a `fn main`, library otherwise.
+    pub crate_type: Inferred<CrateType>,
+
+    /// Nightly if the code enables unstable features, stable otherwise.
+    pub channel: Inferred<Channel>,
+
+    /// The newest edition the code is compatible with.
+    pub edition: Inferred<Edition>,
+}
+
+/// Scans the code and infers the crate type, channel and edition it needs.
+///
+/// # Example
+///
+/// ```
+/// use playground_api::{endpoints::{Channel, CrateType}, inference::infer};
+///
+/// let inference = infer("#![feature(never_type)]\npub fn f() -> ! { loop {} }");
+/// assert_eq!(inference.crate_type.value, CrateType::Library);
+/// assert_eq!(inference.channel.value, Channel::Nightly);
+/// ```
+pub fn infer(code: &str) -> Inference {
+    let code = strip_comments_and_strings(code);
+
+    Inference {
+        crate_type: infer_crate_type(&code),
+        channel: infer_channel(&code),
+        edition: infer_edition(&code),
+    }
+}
+
+fn infer_crate_type(code: &str) -> Inferred<CrateType> {
+    if validation::has_main_fn(code) {
+        Inferred::new(CrateType::Binary, "found `fn main`")
+    } else {
+        Inferred::new(CrateType::Library, "no `fn main` found")
+    }
+}
+
+fn infer_channel(code: &str) -> Inferred<Channel> {
+    if contains_attribute(code, "#![feature") {
+        Inferred::new(Channel::Nightly, "uses `#![feature(...)]`")
+    } else {
+        Inferred::new(Channel::Stable, "no unstable features enabled")
+    }
+}
+
+fn infer_edition(code: &str) -> Inferred<Edition> {
+    let words = words(code);
+
+    if words
+        .windows(2)
+        .any(|w| w[0] == "gen" && w[1] != "{" && w[1] != "move")
+    {
+        return Inferred::new(
+            Edition::Edition2021,
+            "uses `gen` as an identifier, which is reserved in 2024",
+        );
+    }
+    if words.contains(&"async") && !is_used_as_keyword(&words, "async") {
+        return Inferred::new(
+            Edition::Edition2015,
+            "uses `async` as an identifier, which is reserved since 2018",
+        );
+    }
+    if let Some(reason) = rejected_by_2024(&words) {
+        return Inferred::new(Edition::Edition2021, reason);
+    }
+    if words
+        .windows(2)
+        .any(|w| w[0] == "gen" && (w[1] == "{" || w[1] == "move"))
+    {
+        return Inferred::new(Edition::Edition2024, "uses a `gen` block");
+    }
+    if has_let_chain(&words) {
+        return Inferred::new(Edition::Edition2024, "uses a `let` chain");
+    }
+    if words
+        .windows(2)
+        .any(|w| w[0] == "unsafe" && w[1] == "extern")
+    {
+        return Inferred::new(Edition::Edition2024, "uses an `unsafe extern` block");
+    }
+
+    Inferred::new(
+        Edition::Edition2024,
+        "no edition-specific syntax, using the latest",
+    )
+}
+
+/// Detects code which compiles on 2021 but is a hard error on 2024 and returns why.
+fn rejected_by_2024(words: &[&str]) -> Option<&'static str> {
+    let at = |idx: usize| words.get(idx).copied().unwrap_or("");
+
+    if (0..words.len())
+        .any(|idx| at(idx) == "extern" && at(idx + 1) == "{" && at(idx.wrapping_sub(1)) != "unsafe")
+    {
+        return Some("uses an `extern` block without `unsafe`, which is required in 2024");
+    }
+    if words
+        .windows(2)
+        .any(|w| w[0] == "[" && matches!(w[1], "no_mangle" | "export_name" | "link_section"))
+    {
+        return Some("uses an unsafe attribute without `unsafe(...)`, which is required in 2024");
+    }
+    if calls_outside_unsafe(words, &["set_var", "remove_var"]) {
+        return Some(
+            "calls `env::set_var` or `env::remove_var` outside `unsafe`, which is required in 2024",
+        );
+    }
+
+    let statics: Vec<&str> = words
+        .windows(3)
+        .filter(|w| w[0] == "static" && w[1] == "mut")
+        .map(|w| w[2])
+        .collect();
+    if words.windows(3).any(|w| {
+        w[0] == "&" && (statics.contains(&w[1]) || (w[1] == "mut" && statics.contains(&w[2])))
+    }) {
+        return Some("takes a reference to a `static mut`, which is denied in 2024");
+    }
+
+    None
+}
+
+/// Returns `true` if one of the functions is called outside an `unsafe` block or
+/// `unsafe fn`.
+fn calls_outside_unsafe(words: &[&str], functions: &[&str]) -> bool {
+    // Whether each open brace is inside unsafe code.
+    let mut braces: Vec<bool> = Vec::new();
+    let mut unsafe_fn = false;
+
+    for (idx, word) in words.iter().enumerate() {
+        let previous = idx.checked_sub(1).map_or("", |idx| words[idx]);
+        match *word {
+            "fn" => {
+                unsafe_fn = words[..idx]
+                    .iter()
+                    .rev()
+                    .take_while(|w| !matches!(**w, "{" | "}" | ";"))
+                    .any(|w| *w == "unsafe")
+            }
+            ";" => unsafe_fn = false,
+            "{" => {
+                let inside = braces.last().copied().unwrap_or(false);
+                braces.push(inside || previous == "unsafe" || unsafe_fn);
+                unsafe_fn = false;
+            }
+            "}" => {
+                braces.pop();
+            }
+            name if functions.contains(&name)
+                && previous != "."
+                && words.get(idx + 1) == Some(&"(")
+                && !braces.last().copied().unwrap_or(false) =>
+            {
+                return true;
+            }
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Returns `true` if every use of `keyword` is followed by something that makes it a keyword.
+fn is_used_as_keyword(words: &[&str], keyword: &str) -> bool {
+    words
+        .windows(2)
+        .all(|w| w[0] != keyword || matches!(w[1], "fn" | "{" | "move" | "unsafe" | "|" | "||"))
+}
+
+/// Detects `if let ... && ...` or `&& let` chains.
+fn has_let_chain(words: &[&str]) -> bool {
+    words.windows(2).any(|w| w[0] == "&&" && w[1] == "let")
+        || words.windows(2).enumerate().any(|(idx, w)| {
+            (w[0] == "if" || w[0] == "while")
+                && w[1] == "let"
+                && words[idx..]
+                    .iter()
+                    .take_while(|word| **word != "{")
+                    .any(|word| *word == "&&")
+        })
+}
+
+fn contains_attribute(code: &str, prefix: &str) -> bool {
+    code.replace(char::is_whitespace, "").contains(prefix)
+}
+
+/// Splits code into identifiers and punctuation tokens, treating `&&` and `||` as one token.
+fn words(code: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut chars = code.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        if c.is_whitespace() {
+            continue;
+        }
+
+        let mut end = start + c.len_utf8();
+        if is_ident_char(c) {
+            while let Some(&(idx, next)) = chars.peek() {
+                if !is_ident_char(next) {
+                    break;
+                }
+                end = idx + next.len_utf8();
+                chars.next();
+            }
+        } else if matches!(c, '&' | '|') && chars.peek().is_some_and(|&(_, next)| next == c) {
+            chars.next();
+            end += 1;
+        }
+        words.push(&code[start..end]);
+    }
+
+    words
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Replaces comments, string and char literals with spaces, keeping line breaks
+/// and byte offsets intact.
+pub(crate) fn strip_comments_and_strings(code: &str) -> String {
+    let bytes = code.as_bytes();
+    let mut out = bytes.to_vec();
+    let mut idx = 0;
+
+    let blank = |out: &mut Vec<u8>, from: usize, to: usize| {
+        for byte in &mut out[from..to] {
+            if *byte != b'\n' {
+                *byte = b' ';
+            }
+        }
+    };
+
+    while idx < bytes.len() {
+        let rest = &bytes[idx..];
+        let end = if rest.starts_with(b"//") {
+            rest.iter()
+                .position(|&b| b == b'\n')
+                .map_or(bytes.len(), |p| idx + p)
+        } else if rest.starts_with(b"/*") {
+            let mut depth = 0;
+            let mut pos = idx;
+            while pos < bytes.len() {
+                if bytes[pos..].starts_with(b"/*") {
+                    depth += 1;
+                    pos += 2;
+                } else if bytes[pos..].starts_with(b"*/") {
+                    depth -= 1;
+                    pos += 2;
+                    if depth == 0 {
+                        break;
+                    }
+                } else {
+                    pos += 1;
+                }
+            }
+            pos
+        } else if let Some(hashes) = raw_string_start(rest).filter(|_| !follows_ident(bytes, idx)) {
+            let body = idx + hashes + 2 + usize::from(rest[0] == b'b');
+            let closing = [b"\"".as_slice(), &vec![b'#'; hashes]].concat();
+            find(bytes, body, &closing).map_or(bytes.len(), |p| p + closing.len())
+        } else if rest[0] == b'"' {
+            string_end(bytes, idx + 1, b'"')
+        } else if rest[0] == b'\'' && is_char_literal(&code[idx..]) {
+            string_end(bytes, idx + 1, b'\'')
+        } else {
+            idx += 1;
+            continue;
+        };
+
+        blank(&mut out, idx, end);
+        idx = end;
+    }
+
+    String::from_utf8(out).unwrap_or_default()
+}
+
+/// Returns the number of `#`s if `rest` starts a raw string like `r#"` or `br"`.
+fn raw_string_start(rest: &[u8]) -> Option<usize> {
+    let rest = rest.strip_prefix(b"b").unwrap_or(rest);
+    let rest = rest.strip_prefix(b"r")?;
+    let hashes = rest.iter().take_while(|&&b| b == b'#').count();
+    (rest.get(hashes) == Some(&b'"')).then_some(hashes)
+}
+
+fn follows_ident(bytes: &[u8], idx: usize) -> bool {
+    idx > 0 && is_ident_char(char::from(bytes[idx - 1]))
+}
+
+/// Finds the end of a quoted literal, honouring backslash escapes.
+fn string_end(bytes: &[u8], mut idx: usize, quote: u8) -> usize {
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b'\\' => idx += 2,
+            b if b == quote => return idx + 1,
+            _ => idx += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// Tells a char literal like `'a'` or `'\n'` apart from a lifetime like `'a`.
+fn is_char_literal(rest: &str) -> bool {
+    let mut chars = rest.chars().skip(1);
+    match chars.next() {
+        Some('\\') => true,
+        Some(_) => chars.next() == Some('\''),
+        None => false,
+    }
+}
+
+fn find(bytes: &[u8], from: usize, needle: &[u8]) -> Option<usize> {
+    bytes[from..]
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|p| from + p)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crate_type() {
+        assert_eq!(infer("fn main() {}").crate_type.value, CrateType::Binary);
+        assert_eq!(
+            infer("pub fn add() {}").crate_type.value,
+            CrateType::Library
+        );
+        assert_eq!(
+            infer("// fn main() {}\npub fn add() {}").crate_type.value,
+            CrateType::Library
+        );
+    }
+
+    #[test]
+    fn channel() {
+        assert_eq!(
+            infer("#! [feature(let_chains)]\nfn main() {}")
+                .channel
+                .value,
+            Channel::Nightly
+        );
+        assert_eq!(
+            infer(r##"fn main() { println!("#![feature(x)]"); }"##)
+                .channel
+                .value,
+            Channel::Stable
+        );
+    }
+
+    #[test]
+    fn edition() {
+        let let_chain = "fn main() { if let Some(x) = y && x > 1 {} }";
+        assert_eq!(infer(let_chain).edition.value, Edition::Edition2024);
+
+        let gen_ident = "fn main() { let gen = 1; }";
+        assert_eq!(infer(gen_ident).edition.value, Edition::Edition2021);
+
+        let async_ident = "fn async() {} fn main() { async(); }";
+        assert_eq!(infer(async_ident).edition.value, Edition::Edition2015);
+
+        let async_fn = "async fn foo() {} fn main() { let f = async move { 1 }; }";
+        assert_eq!(infer(async_fn).edition.value, Edition::Edition2024);
+    }
+
+    #[test]
+    fn edition_2024_errors() {
+        for code in [
+            "extern \"C\" { fn abs(x: i32) -> i32; }\nfn main() {}",
+            "#[no_mangle]\npub extern \"C\" fn f() {}",
+            "#[export_name = \"g\"]\npub fn f() {}",
+            "fn main() { std::env::set_var(\"A\", \"1\"); }",
+            "static mut N: u32 = 0;\nfn main() { let n = unsafe { &N }; }",
+            "static mut N: u32 = 0;\nfn main() { unsafe { let n = &mut N; } }",
+        ] {
+            let edition = infer(code).edition;
+            assert_eq!(edition.value, Edition::Edition2021, "{code}");
+            assert!(edition.reason.contains("2024"), "{code}");
+        }
+
+        for code in [
+            "unsafe extern \"C\" { fn abs(x: i32) -> i32; }\nfn main() {}",
+            "#[unsafe(no_mangle)]\npub extern \"C\" fn f() {}",
+            "fn main() { unsafe { std::env::set_var(\"A\", \"1\"); } }",
+            "unsafe fn f() { std::env::remove_var(\"A\"); }",
+            "static mut N: u32 = 0;\nfn main() { let n = unsafe { &raw const N }; }",
+        ] {
+            assert_eq!(infer(code).edition.value, Edition::Edition2024, "{code}");
+        }
+    }
+
+    #[test]
+    fn builder_applies_inference() {
+        let req = crate::endpoints::ExecuteRequest::builder()
+            .code("#![feature(gen_blocks)]\npub fn f() { let gen = 1; }")
+            .infer(true)
+            .build();
+
+        assert_eq!(req.crate_type, CrateType::Library);
+        assert_eq!(req.channel, Channel::Nightly);
+        assert_eq!(req.edition, Edition::Edition2021);
+    }
+
+    #[test]
+    fn strips_literals() {
+        let code = "let a = 'x'; let b: &'static str = r#\"fn main\"#; /* /* fn */ main */";
+        let stripped = strip_comments_and_strings(code);
+        assert_eq!(stripped.len(), code.len());
+        assert!(stripped.contains("&'static str"));
+        assert!(!stripped.contains('x'));
+        assert!(!stripped.contains("main"));
+    }
+}
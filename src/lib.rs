@@ -13,6 +13,10 @@
 //!   for environments where async is not desired or available.
 //! - **Poise support** (`poise-bot` feature): makes all enums derive the
 //!   `poise::ChoiceParameter` macro.
+//! - **Offline explanations** (`explain` feature): embeds the explanations of rustc
+//!   error codes and Clippy lints in the `explain` module.
+//! - **Wasm interpreter** (`wasm` feature): runs the exported functions of
+//!   [`CompileTarget::Wasm`](endpoints::CompileTarget::Wasm) output with `wasmi`.
 //!
 //! ## Installation
 //!
@@ -34,22 +38,20 @@
 //!
 //! ### Async (default)
 //!
-//! ```rust
-//! use playground_api::{Client, ExecuteRequest, Channel, Mode, Edition, Error};
+//! ```rust,no_run
+//! use playground_api::{Client, Error};
+//! use playground_api::endpoints::{Channel, ExecuteRequest};
 //!
-//! #[tokio::main]
+//! #[tokio::main(flavor = "current_thread")]
 //! async fn main() -> Result<(), Error> {
 //!     // Uses the official https://play.rust-lang.org/ by default
 //!     let client = Client::default();
 //!
-//!     let req = ExecuteRequest::new(
-//!         Channel::Stable,
-//!         Mode::Release,
-//!         Edition::Edition2021,
-//!         false,
-//!         false,
-//!         r#"println!("Hello, async world!");"#.into(),
-//!     );
+//!     let req = ExecuteRequest::builder()
+//!         .channel(Channel::Stable)
+//!         .release()
+//!         .code(r#"fn main() { println!("Hello, async world!"); }"#)
+//!         .build();
 //!
 //!     let res = client.execute(&req).await?;
 //!     println!("{}", res.stdout);
@@ -59,21 +61,19 @@
 //!
 //! ### Blocking (with `blocking` feature)
 //!
-//! ```rust
-//! use playground_api::{blocking::Client, ExecuteRequest, Channel, Mode, Edition, Error};
+//! ```rust,ignore
+//! use playground_api::{blocking::Client, Error};
+//! use playground_api::endpoints::{Channel, ExecuteRequest};
 //!
 //! fn main() -> Result<(), Error> {
 //!     // Compile your crate with `--features blocking`
 //!     let client = Client::default();
 //!
-//!     let req = ExecuteRequest::new(
-//!         Channel::Stable,
-//!         Mode::Release,
-//!         Edition::Edition2021,
-//!         false,
-//!         false,
-//!         r#"println!("Hello, blocking world!");"#.into(),
-//!     );
+//!     let req = ExecuteRequest::builder()
+//!         .channel(Channel::Stable)
+//!         .release()
+//!         .code(r#"fn main() { println!("Hello, blocking world!"); }"#)
+//!         .build();
 //!
 //!     let res = client.execute(&req)?;
 //!     println!("{}", res.stdout);
@@ -88,9 +88,35 @@
 #[cfg(feature = "blocking")]
 pub mod blocking;
 
+pub mod asm;
+pub mod asm_diff;
+pub mod autofix;
+mod cargo_output;
 mod client;
+pub mod codegen;
+pub mod diagnostics;
 pub mod endpoints;
 mod error;
+pub mod eval;
+#[cfg(feature = "explain")]
+pub mod explain;
+pub mod explore;
+pub mod hir;
+pub mod ice;
+pub mod inference;
+pub mod libtest;
+pub mod lints;
+pub mod llvm_ir;
+pub mod mir;
+pub mod miri;
+pub mod outcome;
+pub mod panics;
+pub mod rustfmt;
+pub mod snippet;
+pub mod validation;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 pub use client::Client;
 pub use error::Error;
+pub use snippet::Snippet;
@@ -0,0 +1,306 @@
+//! Parsing of libtest output for requests run with `tests: true`.
+//!
+//! When tests are enabled the playground runs `cargo test`, which prints one line
+//! per test, the captured output of failing tests and a summary per test binary.
+//! [`parse`] turns that output into a [`TestReport`].
+
+use crate::panics;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Whether a test is a regular `#[test]` function or a documentation test.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum TestKind {
+    /// A `#[test]` function.
+    Unit,
+
+    /// A code example in a doc comment.
+    Doc,
+}
+
+/// The result of a single test.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TestStatus {
+    /// The test passed.
+    Passed,
+
+    /// The test failed.
+    Failed,
+
+    /// The test was skipped, with the reason from `#[ignore = "..."]` if given.
+    Ignored(Option<String>),
+}
+
+/// A single test and its result.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TestCase {
+    /// The full name of the test, e.g. `tests::it_works` or
+    /// `src/lib.rs - add (line 3)` for a doctest.
+    pub name: String,
+
+    /// Whether this is a unit test or a doctest.
+    pub kind: TestKind,
+
+    /// Whether the test passed, failed or was ignored.
+    pub status: TestStatus,
+
+    /// What the test printed to stdout. Only captured for failing tests.
+    pub stdout: Option<String>,
+
+    /// The panic message of a failing test.
+    pub failure: Option<String>,
+}
+
+/// The summary line printed after each test binary, e.g. for the unit tests and
+/// the doctests.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TestSummary {
+    /// Whether the summary is for unit tests or doctests.
+    pub kind: TestKind,
+
+    /// The number of passed tests.
+    pub passed: usize,
+
+    /// The number of failed tests.
+    pub failed: usize,
+
+    /// The number of ignored tests.
+    pub ignored: usize,
+
+    /// The number of benchmarks.
+    pub measured: usize,
+
+    /// The number of tests that were filtered out.
+    pub filtered_out: usize,
+
+    /// How long the tests took, if reported.
+    pub duration: Option<Duration>,
+}
+
+/// Every test found in the output, together with the summaries.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TestReport {
+    /// The individual tests in the order they were reported.
+    pub tests: Vec<TestCase>,
+
+    /// One summary per test binary.
+    pub summaries: Vec<TestSummary>,
+}
+
+impl TestReport {
+    /// Returns the tests that passed.
+    pub fn passed(&self) -> impl Iterator<Item = &TestCase> {
+        self.with_status(|status| *status == TestStatus::Passed)
+    }
+
+    /// Returns the tests that failed.
+    pub fn failed(&self) -> impl Iterator<Item = &TestCase> {
+        self.with_status(|status| *status == TestStatus::Failed)
+    }
+
+    /// Returns the tests that were ignored.
+    pub fn ignored(&self) -> impl Iterator<Item = &TestCase> {
+        self.with_status(|status| matches!(status, TestStatus::Ignored(_)))
+    }
+
+    /// Returns `true` if no test failed.
+    pub fn is_success(&self) -> bool {
+        self.failed().next().is_none()
+    }
+
+    /// Returns the total time of all test binaries.
+    pub fn duration(&self) -> Duration {
+        self.summaries.iter().filter_map(|s| s.duration).sum()
+    }
+
+    fn with_status(&self, f: impl Fn(&TestStatus) -> bool) -> impl Iterator<Item = &TestCase> {
+        self.tests.iter().filter(move |test| f(&test.status))
+    }
+}
+
+/// Parses libtest's output into a [`TestReport`].
+///
+/// Returns `None` if the output doesn't contain any test run.
+///
+/// # Example
+///
+/// ```
+/// use playground_api::libtest::{parse, TestStatus};
+///
+/// let stdout = "running 1 test\ntest tests::it_works ... ok\n\n\
+///               test result: ok. 1 passed; 0 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s\n";
+/// let report = parse(stdout).unwrap();
+///
+/// assert_eq!(report.tests[0].name, "tests::it_works");
+/// assert_eq!(report.tests[0].status, TestStatus::Passed);
+/// ```
+pub fn parse(stdout: &str) -> Option<TestReport> {
+    let mut report = TestReport::default();
+    let mut found = false;
+    let mut suite_start = 0;
+    let mut lines = stdout.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        if line.starts_with("running ") && line.ends_with(" tests") || line == "running 1 test" {
+            found = true;
+            suite_start = report.tests.len();
+        } else if let Some(test) = parse_test_line(line) {
+            report.tests.push(test);
+        } else if let Some(name) = line
+            .strip_prefix("---- ")
+            .and_then(|rest| rest.strip_suffix(" stdout ----"))
+        {
+            let mut block = Vec::new();
+            while let Some(next) = lines.peek() {
+                if next.starts_with("---- ") || *next == "failures:" {
+                    break;
+                }
+                block.push(lines.next().unwrap_or_default());
+            }
+            if let Some(test) = report.tests[suite_start..]
+                .iter_mut()
+                .find(|test| test.name == name)
+            {
+                attach_output(test, &block.join("\n"));
+            }
+        } else if let Some(summary) = line.strip_prefix("test result: ") {
+            let kind = report.tests[suite_start..]
+                .first()
+                .map_or(TestKind::Unit, |test| test.kind);
+            report.summaries.push(parse_summary(summary, kind));
+        }
+    }
+
+    found.then_some(report)
+}
+
+/// Parses a line like `test tests::it_works ... ok`.
+fn parse_test_line(line: &str) -> Option<TestCase> {
+    let (name, result) = line.strip_prefix("test ")?.rsplit_once(" ... ")?;
+    let status = match result {
+        "ok" => TestStatus::Passed,
+        "FAILED" => TestStatus::Failed,
+        "ignored" => TestStatus::Ignored(None),
+        _ => TestStatus::Ignored(Some(result.strip_prefix("ignored, ")?.to_owned())),
+    };
+
+    Some(TestCase {
+        kind: test_kind(name),
+        name: name.to_owned(),
+        status,
+        stdout: None,
+        failure: None,
+    })
+}
+
+/// Doctests are named after their location, e.g. `src/lib.rs - add (line 3)`.
+fn test_kind(name: &str) -> TestKind {
+    if name.contains(" - ") && name.contains("(line ") {
+        TestKind::Doc
+    } else {
+        TestKind::Unit
+    }
+}
+
+/// Splits the captured block of a failing test into its stdout and panic message.
+fn attach_output(test: &mut TestCase, block: &str) {
+    let panic_start = block
+        .lines()
+        .position(|line| line.starts_with("thread '"))
+        .unwrap_or(usize::MAX);
+    let stdout: Vec<&str> = block.lines().take(panic_start).collect();
+    let stdout = stdout.join("\n");
+    let stdout = stdout.trim_end();
+
+    let panic = panics::parse(block).into_iter().next();
+    test.failure = match panic {
+        Some(panic) => Some(panic.message),
+        None => Some(block.trim().to_owned()).filter(|block| !block.is_empty()),
+    };
+    test.stdout = Some(stdout.to_owned()).filter(|stdout| !stdout.is_empty());
+}
+
+/// Parses `ok. 1 passed; 0 failed; ... finished in 0.00s`.
+fn parse_summary(summary: &str, kind: TestKind) -> TestSummary {
+    let mut result = TestSummary {
+        kind,
+        passed: 0,
+        failed: 0,
+        ignored: 0,
+        measured: 0,
+        filtered_out: 0,
+        duration: None,
+    };
+    let counts = summary
+        .split_once(". ")
+        .map_or(summary, |(_, counts)| counts);
+
+    for part in counts.split("; ") {
+        if let Some(time) = part.strip_prefix("finished in ") {
+            result.duration = time
+                .strip_suffix('s')
+                .and_then(|secs| secs.parse().ok())
+                .map(Duration::from_secs_f64);
+            continue;
+        }
+        let Some((count, what)) = part.split_once(' ') else {
+            continue;
+        };
+        let Ok(count) = count.parse() else {
+            continue;
+        };
+        match what {
+            "passed" => result.passed = count,
+            "failed" => result.failed = count,
+            "ignored" => result.ignored = count,
+            "measured" => result.measured = count,
+            "filtered out" => result.filtered_out = count,
+            _ => {}
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_unit_and_doc_tests() {
+        let report = parse(include_str!("../test-outputs/libtest.stdout")).unwrap();
+        assert_eq!(report.tests.len(), 6);
+        assert_eq!(report.passed().count(), 2);
+        assert_eq!(report.failed().count(), 2);
+        assert!(!report.is_success());
+
+        let fails = &report.tests[0];
+        assert_eq!(fails.name, "tests::fails");
+        assert_eq!(fails.kind, TestKind::Unit);
+        assert_eq!(fails.stdout.as_deref(), Some("captured output"));
+        assert_eq!(
+            fails.failure.as_deref(),
+            Some("assertion `left == right` failed: math is hard\n  left: 2\n right: 3")
+        );
+
+        assert_eq!(report.tests[1].status, TestStatus::Ignored(None));
+        assert_eq!(
+            report.tests[2].status,
+            TestStatus::Ignored(Some("too slow".to_owned()))
+        );
+
+        let doctest = &report.tests[5];
+        assert_eq!(doctest.name, "src/lib.rs - add_one (line 7)");
+        assert_eq!(doctest.kind, TestKind::Doc);
+        assert!(doctest.failure.as_deref().unwrap().starts_with("assertion"));
+
+        assert_eq!(report.summaries.len(), 2);
+        assert_eq!(report.summaries[0].ignored, 2);
+        assert_eq!(report.summaries[1].kind, TestKind::Doc);
+    }
+
+    #[test]
+    fn no_tests() {
+        assert_eq!(parse("Hello, world!\n"), None);
+    }
+}
@@ -0,0 +1,293 @@
+//! Lint results of rustc and Clippy with their suggested fixes.
+//!
+//! A [`Lint`] is a warning or error [`Diagnostic`] which was emitted by a named
+//! lint like `clippy::needless_return` or `unused_variables`. [`apply_suggestions`]
+//! rewrites the submitted code using the fixes the lints suggest.
+
+use crate::diagnostics::{self, Applicability, Diagnostic, Level, Span, Suggestion};
+#[cfg(feature = "explain")]
+use crate::explain::{self, Explanation};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A diagnostic emitted by a named lint.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Lint {
+    /// The lint name, e.g. `clippy::needless_return` or `unused_variables`.
+    pub name: String,
+
+    /// The level the lint was emitted at.
+    pub level: Level,
+
+    /// The lint group, e.g. `style` for Clippy lints or `unused` for rustc lints,
+    /// if it is known. The groups of Clippy lints are mostly looked up offline and
+    /// only known with the `explain` feature.
+    pub group: Option<String>,
+
+    /// The lint message, e.g. "unneeded `return` statement".
+    pub message: String,
+
+    /// The code the lint points at.
+    pub span: Option<Span>,
+
+    /// The first fix suggested by the lint, if any.
+    pub suggestion: Option<Suggestion>,
+}
+
+impl Lint {
+    /// Extracts the lint from a diagnostic.
+    ///
+    /// The name is taken from a note like "`#[warn(clippy::len_zero)]` on by default"
+    /// or, for Clippy lints whose level was set explicitly, from the link to the
+    /// lint documentation. Returns `None` for diagnostics which aren't lints, like
+    /// type errors.
+    pub fn from_diagnostic(diagnostic: &Diagnostic) -> Option<Self> {
+        if !matches!(diagnostic.level, Level::Warning | Level::Error) {
+            return None;
+        }
+
+        let (name, group) = diagnostic
+            .notes
+            .iter()
+            .find_map(|note| parse_level_note(note))
+            .or_else(|| {
+                let name = diagnostic
+                    .help
+                    .iter()
+                    .find_map(|help| help.split_once("rust-clippy/")?.1.split_once(".html#"))?
+                    .1;
+                Some((format!("clippy::{name}"), None))
+            })?;
+        let group = group.or_else(|| clippy_group(name.strip_prefix("clippy::")?));
+        Some(Self::new(diagnostic, name, group))
+    }
+
+    fn new(diagnostic: &Diagnostic, name: String, group: Option<String>) -> Self {
+        Self {
+            name,
+            level: diagnostic.level,
+            group,
+            message: diagnostic.message.clone(),
+            span: diagnostic.span.clone(),
+            suggestion: diagnostic.suggestions.first().cloned(),
+        }
+    }
+
+    /// Returns `true` for Clippy lints, `false` for rustc lints.
+    pub fn is_clippy(&self) -> bool {
+        self.name.starts_with("clippy::")
+    }
+
+    /// Returns the offline explanation of a Clippy lint, see [`explain::clippy_lint`].
+    #[cfg(feature = "explain")]
+    pub fn explanation(&self) -> Option<Explanation> {
+        if !self.is_clippy() {
+            return None;
+        }
+        explain::clippy_lint(&self.name)
+    }
+}
+
+/// Parses the lints in the output of rustc or Clippy.
+///
+/// rustc only prints the note naming a lint for its first occurrence. Later
+/// diagnostics without a name get the name of an earlier lint with the same
+/// message apart from the quoted code, e.g. "unused variable: `w`" and "unused
+/// variable: `x`".
+///
+/// # Example
+///
+/// ```
+/// let stderr = "warning: unused variable: `x`\n --> src/main.rs:2:9\n  |\n  = note: `#[warn(unused_variables)]` (part of `#[warn(unused)]`) on by default\n";
+/// let lints = playground_api::lints::parse(stderr);
+///
+/// assert_eq!(lints[0].name, "unused_variables");
+/// assert_eq!(lints[0].group.as_deref(), Some("unused"));
+/// ```
+pub fn parse(stderr: &str) -> Vec<Lint> {
+    let mut known: HashMap<String, (String, Option<String>)> = HashMap::new();
+    diagnostics::parse(stderr)
+        .iter()
+        .filter_map(|diagnostic| {
+            let template = message_template(&diagnostic.message);
+            if let Some(lint) = Lint::from_diagnostic(diagnostic) {
+                known.insert(template, (lint.name.clone(), lint.group.clone()));
+                return Some(lint);
+            }
+            if diagnostic.code.is_some()
+                || !matches!(diagnostic.level, Level::Warning | Level::Error)
+            {
+                return None;
+            }
+            let (name, group) = known.get(&template)?;
+            Some(Lint::new(diagnostic, name.clone(), group.clone()))
+        })
+        .collect()
+}
+
+/// Applies the suggestions of the given lints to `code`.
+///
+/// Only suggestions at least as certain as `applicability` are applied, e.g.
+/// [`Applicability::MachineApplicable`] only applies the fixes which are safe
+/// to apply automatically. Suggestions overlapping an earlier one are skipped.
+///
+/// # Arguments
+///
+/// * `code` - The code the lints were emitted for.
+/// * `lints` - The lints, usually from [`parse`].
+/// * `applicability` - The least certain applicability to apply.
+///
+/// # Returns
+///
+/// The rewritten code.
+pub fn apply_suggestions(code: &str, lints: &[Lint], applicability: Applicability) -> String {
+    let suggestions: Vec<&Suggestion> = lints
+        .iter()
+        .filter_map(|lint| lint.suggestion.as_ref())
+        .filter(|suggestion| suggestion.applicability <= applicability)
+        .collect();
+    diagnostics::apply(code, &suggestions).0
+}
+
+/// Looks up the group of a Clippy lint in the offline lint explanations.
+#[cfg(feature = "explain")]
+fn clippy_group(name: &str) -> Option<String> {
+    explain::clippy_lint(name)?.group
+}
+
+/// Without the `explain` feature the groups of Clippy lints are unknown.
+#[cfg(not(feature = "explain"))]
+fn clippy_group(_name: &str) -> Option<String> {
+    None
+}
+
+/// Removes the quoted parts of a message like "unused variable: `w`", leaving
+/// the text which is the same for every occurrence of a lint.
+fn message_template(message: &str) -> String {
+    message.split('`').step_by(2).collect::<Vec<_>>().join("``")
+}
+
+/// Parses a note like "`#[warn(unused_variables)]` (part of `#[warn(unused)]`) on
+/// by default" or "`#[warn(clippy::len_zero)]` implied by `#[warn(clippy::style)]`"
+/// into the lint name and group.
+fn parse_level_note(note: &str) -> Option<(String, Option<String>)> {
+    let attribute_lint = |text: &str| -> Option<(String, usize)> {
+        let start = text.find("`#[")?;
+        let rest = &text[start..];
+        let open = rest.find('(')?;
+        let close = rest.find(")]`")?;
+        Some((rest[open + 1..close].to_owned(), start + close + 3))
+    };
+
+    let (name, end) = attribute_lint(note)?;
+    let group = attribute_lint(&note[end..])
+        .map(|(group, _)| group.trim_start_matches("clippy::").to_owned())
+        .filter(|group| group != "all" && group != "warnings");
+    Some((name, group))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CODE: &str = "fn add(a: i32, b: i32) -> i32 {
+    return a + b;
+}
+
+fn main() {
+    let v = vec![1, 2, 3];
+    if v.len() == 0 {
+        println!(\"{}\", add(1, 2));
+    }
+}
+";
+
+    #[test]
+    fn parses_clippy_lints() {
+        let lints = parse(include_str!("../test-outputs/clippy.stderr"));
+        let names: Vec<_> = lints.iter().map(|lint| lint.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "clippy::needless_return",
+                "clippy::len_zero",
+                "clippy::useless_vec"
+            ]
+        );
+
+        #[cfg(feature = "explain")]
+        {
+            let groups: Vec<_> = lints.iter().map(|lint| lint.group.as_deref()).collect();
+            assert_eq!(groups, [Some("style"), Some("style"), Some("perf")]);
+        }
+        assert!(lints.iter().all(Lint::is_clippy));
+        assert_eq!(lints[1].span.as_ref().unwrap().line, 7);
+        assert_eq!(
+            lints[2].suggestion.as_ref().unwrap().edits[0].replacement,
+            "[1, 2, 3]"
+        );
+    }
+
+    #[test]
+    fn parses_rustc_lints() {
+        let lints = parse(include_str!("../test-outputs/borrow.stderr"));
+        let names: Vec<_> = lints
+            .iter()
+            .map(|lint| (lint.name.as_str(), lint.message.as_str()))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                ("unused_variables", "unused variable: `w`"),
+                ("unused_variables", "unused variable: `unused`"),
+                ("unused_assignments", "value assigned to `x` is never read"),
+            ]
+        );
+        assert!(lints
+            .iter()
+            .all(|lint| lint.group.as_deref() == Some("unused")
+                && lint.level == Level::Warning
+                && !lint.is_clippy()));
+        assert_eq!(
+            lints[1].suggestion.as_ref().unwrap().edits[0].replacement,
+            "_unused"
+        );
+    }
+
+    #[test]
+    fn applies_suggestions() {
+        let lints = parse(include_str!("../test-outputs/clippy.stderr"));
+        let fixed = apply_suggestions(CODE, &lints, Applicability::MachineApplicable);
+        assert_eq!(
+            fixed,
+            "fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+fn main() {
+    let v = [1, 2, 3];
+    if v.is_empty() {
+        println!(\"{}\", add(1, 2));
+    }
+}
+"
+        );
+    }
+
+    #[test]
+    fn explicit_level_and_overlaps() {
+        let stderr = "error: unneeded `return` statement\n --> src/main.rs:2:5\n  |\n  \
+                      = help: for further information visit https://rust-lang.github.io/rust-clippy/master/index.html#needless_return\n";
+        let lint = &parse(stderr)[0];
+        assert_eq!(lint.name, "clippy::needless_return");
+        assert_eq!(lint.level, Level::Error);
+        #[cfg(feature = "explain")]
+        assert_eq!(lint.group.as_deref(), Some("style"));
+
+        let mut lints = parse(include_str!("../test-outputs/clippy.stderr"));
+        let duplicate = lints[1].clone();
+        lints.push(duplicate);
+        let fixed = apply_suggestions(CODE, &lints, Applicability::MachineApplicable);
+        assert!(fixed.contains("if v.is_empty() {"));
+    }
+}
@@ -0,0 +1,788 @@
+//! Parsing of the LLVM IR output of [`CompileTarget::LlvmIr`](crate::endpoints::CompileTarget::LlvmIr).
+//!
+//! [`Module::parse`] splits the textual IR into functions, basic blocks and
+//! instructions, which is enough to extract a single function, report
+//! [`Statistics`] or remove debug information with [`strip_debug_info`]. It is
+//! not a validating parser: types and operands are kept as text.
+
+use crate::asm;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt,
+};
+
+/// A metadata attachment of an instruction, e.g. `!dbg !24`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct MetadataRef {
+    /// The attachment kind without `!`, e.g. `dbg` or `alias.scope`.
+    pub kind: String,
+
+    /// The referenced node, e.g. `!24`.
+    pub node: String,
+}
+
+/// A single instruction.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Instruction {
+    /// The value the instruction defines, e.g. `%_0`, if any.
+    pub result: Option<String>,
+
+    /// The opcode, e.g. `add`, `call` or `br`. `tail call` is reported as `call`.
+    pub opcode: String,
+
+    /// The instruction without its result and metadata attachments, e.g.
+    /// `add i32 %_3, %_2`.
+    pub text: String,
+
+    /// The demangled name of the called function for direct `call` and `invoke`
+    /// instructions.
+    pub callee: Option<String>,
+
+    /// The metadata attachments, e.g. `!dbg !24`.
+    pub metadata: Vec<MetadataRef>,
+}
+
+impl Instruction {
+    /// Parses an instruction line like `%_0 = add i32 %_3, %_2, !dbg !24`.
+    fn parse(line: &str) -> Self {
+        let line = line.trim();
+        let (text, metadata) = split_attachments(line);
+        let (result, text) = match text.split_once(" = ") {
+            Some((result, rest)) if result.starts_with('%') && !result.contains(' ') => {
+                (Some(result.to_owned()), rest)
+            }
+            _ => (None, text),
+        };
+
+        let mut words = text.split_whitespace();
+        let mut opcode = words.next().unwrap_or_default();
+        if matches!(opcode, "tail" | "musttail" | "notail") {
+            opcode = words.next().unwrap_or_default();
+        }
+        let callee = matches!(opcode, "call" | "invoke" | "callbr")
+            .then(|| direct_callee(text))
+            .flatten()
+            .map(|symbol| asm::demangle(&symbol));
+
+        Self {
+            result,
+            opcode: opcode.to_owned(),
+            text: text.to_owned(),
+            callee,
+            metadata,
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(result) = &self.result {
+            write!(f, "{result} = ")?;
+        }
+        f.write_str(&self.text)?;
+        for attachment in &self.metadata {
+            write!(f, ", !{} {}", attachment.kind, attachment.node)?;
+        }
+        Ok(())
+    }
+}
+
+/// A basic block.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BasicBlock {
+    /// The label without `%` and quotes, e.g. `start`. Empty for an unnamed
+    /// entry block.
+    pub label: String,
+
+    /// The labels of the predecessors as listed in the `; preds =` comment.
+    pub predecessors: Vec<String>,
+
+    /// The instructions, ending with the terminator.
+    pub instructions: Vec<Instruction>,
+}
+
+/// A function definition.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Function {
+    /// The demangled name without hash, e.g. `playground::main`.
+    pub name: String,
+
+    /// The symbol as printed, e.g. `_ZN10playground4main17h0123456789abcdefE`.
+    pub symbol: String,
+
+    /// The `define` line without the opening brace.
+    pub signature: String,
+
+    /// The function attributes of the referenced attribute groups, e.g. `uwtable`.
+    pub attributes: Vec<String>,
+
+    /// The basic blocks, starting with the entry block.
+    pub blocks: Vec<BasicBlock>,
+
+    /// The definition as printed, from the `define` line to the closing brace.
+    pub text: String,
+}
+
+impl Function {
+    /// Returns the instructions of all blocks.
+    pub fn instructions(&self) -> impl Iterator<Item = &Instruction> {
+        self.blocks
+            .iter()
+            .flat_map(|block| block.instructions.iter())
+    }
+
+    /// Returns the demangled names of the directly called functions, in order of
+    /// their first call and without duplicates.
+    pub fn callees(&self) -> Vec<&str> {
+        let mut callees: Vec<&str> = Vec::new();
+        for callee in self.instructions().filter_map(|i| i.callee.as_deref()) {
+            if !callees.contains(&callee) {
+                callees.push(callee);
+            }
+        }
+        callees
+    }
+
+    /// Counts blocks, instructions, opcodes, allocas and calls.
+    pub fn statistics(&self) -> Statistics {
+        let mut statistics = Statistics {
+            basic_blocks: self.blocks.len(),
+            ..Statistics::default()
+        };
+        for instruction in self.instructions() {
+            statistics.add(instruction);
+        }
+        statistics
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// A function declaration like `declare void @llvm.trap()`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Declaration {
+    /// The demangled name.
+    pub name: String,
+
+    /// The symbol as printed.
+    pub symbol: String,
+
+    /// The `declare` line.
+    pub text: String,
+}
+
+/// A metadata definition like `!4 = !{!"rustc version 1.95.0"}`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MetadataNode {
+    /// The node name, e.g. `!4` or `!llvm.ident`.
+    pub name: String,
+
+    /// Everything after ` = `.
+    pub value: String,
+}
+
+/// Instruction statistics of a function or module.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Statistics {
+    /// The number of basic blocks.
+    pub basic_blocks: usize,
+
+    /// The number of instructions.
+    pub instructions: usize,
+
+    /// The number of instructions per opcode.
+    pub opcodes: BTreeMap<String, usize>,
+
+    /// The number of `alloca` instructions, i.e. stack slots.
+    pub allocas: usize,
+
+    /// The number of `call` and `invoke` instructions, including intrinsics.
+    pub calls: usize,
+
+    /// The number of direct calls per demangled callee.
+    pub callees: BTreeMap<String, usize>,
+}
+
+impl Statistics {
+    fn add(&mut self, instruction: &Instruction) {
+        self.instructions += 1;
+        *self.opcodes.entry(instruction.opcode.clone()).or_default() += 1;
+        match instruction.opcode.as_str() {
+            "alloca" => self.allocas += 1,
+            "call" | "invoke" | "callbr" => self.calls += 1,
+            _ => {}
+        }
+        if let Some(callee) = &instruction.callee {
+            *self.callees.entry(callee.clone()).or_default() += 1;
+        }
+    }
+
+    fn merge(&mut self, other: &Statistics) {
+        self.basic_blocks += other.basic_blocks;
+        self.instructions += other.instructions;
+        self.allocas += other.allocas;
+        self.calls += other.calls;
+        for (opcode, count) in &other.opcodes {
+            *self.opcodes.entry(opcode.clone()).or_default() += count;
+        }
+        for (callee, count) in &other.callees {
+            *self.callees.entry(callee.clone()).or_default() += count;
+        }
+    }
+}
+
+impl fmt::Display for Statistics {
+    /// Renders a one-line summary like `3 blocks, 7 instructions (2 br, 1 ret, ...),
+    /// 0 allocas, 1 call`, with opcodes sorted by frequency.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut opcodes: Vec<_> = self.opcodes.iter().collect();
+        opcodes.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+        let opcodes: Vec<String> = opcodes
+            .into_iter()
+            .map(|(opcode, count)| format!("{count} {opcode}"))
+            .collect();
+        let plural = |count: usize, word: &str| {
+            format!("{count} {word}{}", if count == 1 { "" } else { "s" })
+        };
+
+        write!(
+            f,
+            "{}, {}",
+            plural(self.basic_blocks, "block"),
+            plural(self.instructions, "instruction")
+        )?;
+        if !opcodes.is_empty() {
+            write!(f, " ({})", opcodes.join(", "))?;
+        }
+        write!(
+            f,
+            ", {}, {}",
+            plural(self.allocas, "alloca"),
+            plural(self.calls, "call")
+        )
+    }
+}
+
+/// A parsed LLVM IR module.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Module {
+    /// The `source_filename`, e.g. `playground.d3871e328ee0cb96-cgu.0`.
+    pub source_filename: Option<String>,
+
+    /// The `target triple`, e.g. `x86_64-unknown-linux-gnu`.
+    pub target_triple: Option<String>,
+
+    /// The global variable definitions as printed.
+    pub globals: Vec<String>,
+
+    /// The function definitions in order of appearance.
+    pub functions: Vec<Function>,
+
+    /// The declarations of external functions.
+    pub declarations: Vec<Declaration>,
+
+    /// The attribute groups, e.g. `#0` to `["nonlazybind", "uwtable"]`.
+    pub attribute_groups: BTreeMap<String, Vec<String>>,
+
+    /// The metadata definitions, including named metadata like `!llvm.ident`.
+    pub metadata: Vec<MetadataNode>,
+}
+
+impl Module {
+    /// Parses textual LLVM IR.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use playground_api::llvm_ir::Module;
+    ///
+    /// let ir = "define i32 @add(i32 %a, i32 %b) {\nstart:\n  %_0 = add i32 %a, %b\n  ret i32 %_0\n}\n";
+    /// let module = Module::parse(ir);
+    ///
+    /// let add = module.function("add").unwrap();
+    /// assert_eq!(add.statistics().opcodes["add"], 1);
+    /// ```
+    pub fn parse(ir: &str) -> Self {
+        let mut module = Self::default();
+        let mut groups: Vec<(usize, Vec<String>)> = Vec::new();
+        let mut lines = ir.lines();
+
+        while let Some(line) = lines.next() {
+            if let Some(rest) = line.strip_prefix("define ") {
+                let Some(symbol) = symbol(rest) else {
+                    continue;
+                };
+                let body: Vec<&str> = lines.by_ref().take_while(|line| *line != "}").collect();
+                let signature = line.trim_end().trim_end_matches('{').trim_end();
+                groups.push((
+                    module.functions.len(),
+                    signature
+                        .split_whitespace()
+                        .filter(|word| is_attribute_group(word))
+                        .map(str::to_owned)
+                        .collect(),
+                ));
+                module.functions.push(Function {
+                    name: asm::demangle(&symbol),
+                    symbol,
+                    signature: signature.to_owned(),
+                    attributes: Vec::new(),
+                    blocks: parse_blocks(&body),
+                    text: format!("{line}\n{}\n}}", body.join("\n")),
+                });
+            } else if let Some(rest) = line.strip_prefix("declare ") {
+                if let Some(symbol) = symbol(rest) {
+                    module.declarations.push(Declaration {
+                        name: asm::demangle(&symbol),
+                        symbol,
+                        text: line.to_owned(),
+                    });
+                }
+            } else if let Some(rest) = line.strip_prefix("attributes ") {
+                if let Some((group, attributes)) = rest.split_once(" = ") {
+                    let attributes = attributes
+                        .trim()
+                        .trim_start_matches('{')
+                        .trim_end_matches('}');
+                    module
+                        .attribute_groups
+                        .insert(group.to_owned(), split_attributes(attributes));
+                }
+            } else if line.starts_with('!') {
+                if let Some((name, value)) = line.split_once(" = ") {
+                    module.metadata.push(MetadataNode {
+                        name: name.to_owned(),
+                        value: value.to_owned(),
+                    });
+                }
+            } else if line.starts_with('@') {
+                module.globals.push(line.to_owned());
+            } else if let Some(name) = line.strip_prefix("source_filename = ") {
+                module.source_filename = Some(name.trim_matches('"').to_owned());
+            } else if let Some(triple) = line.strip_prefix("target triple = ") {
+                module.target_triple = Some(triple.trim_matches('"').to_owned());
+            }
+        }
+
+        for (idx, groups) in groups {
+            module.functions[idx].attributes = groups
+                .iter()
+                .filter_map(|group| module.attribute_groups.get(group))
+                .flatten()
+                .cloned()
+                .collect();
+        }
+        module
+    }
+
+    /// Finds a function by its full name, a path suffix like `sum` or its symbol.
+    pub fn function(&self, name: &str) -> Option<&Function> {
+        let suffix = format!("::{name}");
+        self.functions
+            .iter()
+            .find(|function| function.name == name || function.symbol == name)
+            .or_else(|| {
+                self.functions
+                    .iter()
+                    .find(|function| function.name.ends_with(&suffix))
+            })
+    }
+
+    /// Extracts a function with the declarations of the functions it calls.
+    ///
+    /// Metadata and attribute groups aren't included, so the result is meant for
+    /// reading rather than for passing to LLVM. Combine with [`strip_debug_info`]
+    /// to also remove the `!dbg` attachments.
+    ///
+    /// Returns `None` if there is no such function, see [`Module::function`].
+    pub fn extract(&self, name: &str) -> Option<String> {
+        let function = self.function(name)?;
+        let callees = function.callees();
+
+        let mut text = format!("; {}\n{}\n", function.name, function.text);
+        for declaration in &self.declarations {
+            if callees.contains(&declaration.name.as_str()) {
+                text.push_str(&format!("\n; {}\n{}\n", declaration.name, declaration.text));
+            }
+        }
+        Some(text)
+    }
+
+    /// Returns the statistics of every function combined.
+    pub fn statistics(&self) -> Statistics {
+        let mut statistics = Statistics::default();
+        for function in &self.functions {
+            statistics.merge(&function.statistics());
+        }
+        statistics
+    }
+}
+
+/// Removes debug information from textual LLVM IR.
+///
+/// Drops `!dbg` attachments, `#dbg_*` records, calls to `llvm.dbg.*` intrinsics
+/// and every metadata node which is no longer referenced afterwards, like the
+/// `!DILocation`s and `!DISubprogram`s. The result is typically less than half
+/// the size of the input.
+pub fn strip_debug_info(ir: &str) -> String {
+    let lines: Vec<String> = ir
+        .lines()
+        .filter(|line| {
+            let trimmed = line.trim_start();
+            let is_dbg_call =
+                line.starts_with(char::is_whitespace) && trimmed.contains("call void @llvm.dbg.");
+            !(is_dbg_call
+                || trimmed.starts_with("#dbg_")
+                || trimmed.starts_with("!llvm.dbg.")
+                || trimmed.starts_with("declare void @llvm.dbg."))
+        })
+        .map(|line| {
+            let (text, metadata) = split_attachments(line);
+            let mut line = text.to_owned();
+            for attachment in metadata
+                .iter()
+                .filter(|attachment| attachment.kind != "dbg")
+            {
+                line.push_str(&format!(", !{} {}", attachment.kind, attachment.node));
+            }
+            // Functions attach their `DISubprogram` before the opening brace.
+            remove_dbg_reference(&line)
+        })
+        .collect();
+
+    // Keep the metadata nodes which are reachable from the remaining code.
+    let definitions: HashMap<&str, &str> = lines
+        .iter()
+        .filter_map(|line| line.split_once(" = "))
+        .filter(|(name, _)| is_numbered_node(name))
+        .collect();
+    let mut reachable = BTreeSet::new();
+    let mut pending: Vec<&str> = lines
+        .iter()
+        .filter(|line| {
+            !line
+                .split_once(" = ")
+                .is_some_and(|(name, _)| is_numbered_node(name))
+        })
+        .flat_map(|line| node_references(line))
+        .collect();
+    while let Some(node) = pending.pop() {
+        if reachable.insert(node) {
+            if let Some(value) = definitions.get(node) {
+                pending.extend(node_references(value));
+            }
+        }
+    }
+
+    let mut output = String::new();
+    let mut previous_blank = false;
+    for line in &lines {
+        let is_unreachable = line
+            .split_once(" = ")
+            .is_some_and(|(name, _)| is_numbered_node(name) && !reachable.contains(name));
+        let is_blank = line.trim().is_empty();
+        if is_unreachable || (is_blank && previous_blank) {
+            continue;
+        }
+        output.push_str(line);
+        output.push('\n');
+        previous_blank = is_blank;
+    }
+    output
+}
+
+/// Splits the body of a function into basic blocks.
+fn parse_blocks(body: &[&str]) -> Vec<BasicBlock> {
+    let mut blocks: Vec<BasicBlock> = Vec::new();
+    for line in body {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with(';') || trimmed.starts_with("#dbg_") {
+            continue;
+        }
+
+        if !line.starts_with(char::is_whitespace) {
+            let (label, preds) = line.split_once("; preds = ").unwrap_or((line, ""));
+            if let Some(label) = label.trim().strip_suffix(':') {
+                blocks.push(BasicBlock {
+                    label: label.trim_matches('"').to_owned(),
+                    predecessors: preds
+                        .split(", ")
+                        .map(|pred| pred.trim().trim_start_matches('%').trim_matches('"'))
+                        .filter(|pred| !pred.is_empty())
+                        .map(str::to_owned)
+                        .collect(),
+                    instructions: Vec::new(),
+                });
+                continue;
+            }
+        }
+
+        if blocks.is_empty() {
+            blocks.push(BasicBlock {
+                label: String::new(),
+                predecessors: Vec::new(),
+                instructions: Vec::new(),
+            });
+        }
+        if let Some(block) = blocks.last_mut() {
+            block.instructions.push(Instruction::parse(trimmed));
+        }
+    }
+    blocks
+}
+
+/// Extracts the symbol after the first `@`, e.g. `foo` from `void @foo(i32 %x)`
+/// or `a b` from `@"a b"(...)`.
+pub(crate) fn symbol(text: &str) -> Option<String> {
+    let rest = &text[text.find('@')? + 1..];
+    let (symbol, _) = read_name(rest)?;
+    Some(symbol.to_owned())
+}
+
+/// Reads a plain or quoted LLVM name at the start of `text`, returning the name
+/// and the remaining text.
+fn read_name(text: &str) -> Option<(&str, &str)> {
+    let (name, rest) = match text.strip_prefix('"') {
+        Some(quoted) => {
+            let end = quoted.find('"')?;
+            (&quoted[..end], &quoted[end + 1..])
+        }
+        None => {
+            let end = text
+                .find(|c: char| !(c.is_alphanumeric() || "_.$-".contains(c)))
+                .unwrap_or(text.len());
+            text.split_at(end)
+        }
+    };
+    (!name.is_empty()).then_some((name, rest))
+}
+
+/// Returns the symbol of the callee of a `call` or `invoke`, or `None` for
+/// indirect calls through a `%` value.
+fn direct_callee(text: &str) -> Option<String> {
+    let mut rest = text;
+    while let Some(start) = rest.find(['@', '%']) {
+        let sigil = rest.as_bytes()[start];
+        let (name, after) = read_name(&rest[start + 1..]).unwrap_or(("", &rest[start + 1..]));
+        if after.starts_with('(') && !name.is_empty() {
+            return (sigil == b'@').then(|| name.to_owned());
+        }
+        rest = after;
+    }
+    None
+}
+
+/// Splits trailing metadata attachments like `, !dbg !24, !prof !63` off a line.
+fn split_attachments(line: &str) -> (&str, Vec<MetadataRef>) {
+    let mut end = line.len();
+    let mut metadata = Vec::new();
+    while let Some(start) = line[..end].rfind(", !") {
+        let attachment = &line[start + 3..end];
+        let Some((kind, node)) = attachment.split_once(' ') else {
+            break;
+        };
+        let is_kind = kind.starts_with(|c: char| c.is_ascii_alphabetic())
+            && kind
+                .chars()
+                .all(|c| c.is_alphanumeric() || "_.".contains(c));
+        if !is_kind || !is_node(node.trim_end()) {
+            break;
+        }
+        metadata.push(MetadataRef {
+            kind: kind.to_owned(),
+            node: node.trim_end().to_owned(),
+        });
+        end = start;
+    }
+    metadata.reverse();
+    (&line[..end], metadata)
+}
+
+/// Removes a `!dbg !N` reference which isn't an attachment after a comma, like
+/// the one in `define ... #0 !dbg !7 {`.
+fn remove_dbg_reference(line: &str) -> String {
+    match line.find(" !dbg !") {
+        Some(start) => {
+            let rest = &line[start + " !dbg ".len()..];
+            let end = rest[1..]
+                .find(|c: char| !c.is_ascii_digit())
+                .map_or(rest.len(), |end| end + 1);
+            format!("{}{}", &line[..start], &rest[end..])
+        }
+        None => line.to_owned(),
+    }
+}
+
+/// Returns the numbered metadata nodes like `!12` referenced in `text`.
+fn node_references(text: &str) -> Vec<&str> {
+    let mut nodes = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('!') {
+        let digits = rest[start + 1..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - start - 1);
+        if digits > 0 {
+            nodes.push(&rest[start..start + 1 + digits]);
+        }
+        rest = &rest[start + 1 + digits..];
+    }
+    nodes
+}
+
+/// Returns `true` for `!{}` or a numbered node like `!12`.
+fn is_node(text: &str) -> bool {
+    text == "!{}" || is_numbered_node(text)
+}
+
+fn is_numbered_node(text: &str) -> bool {
+    text.strip_prefix('!')
+        .is_some_and(|digits| !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn is_attribute_group(word: &str) -> bool {
+    word.strip_prefix('#')
+        .is_some_and(|digits| !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Splits an attribute list at the spaces outside of quotes and parentheses, so
+/// `memory(argmem: read)` and `"target-cpu"="x86-64"` stay intact.
+fn split_attributes(attributes: &str) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+    let mut in_string = false;
+    for c in attributes.chars() {
+        match c {
+            '"' => in_string = !in_string,
+            '(' if !in_string => depth += 1,
+            ')' if !in_string => depth = depth.saturating_sub(1),
+            c if c.is_whitespace() && !in_string && depth == 0 => {
+                if !current.is_empty() {
+                    result.push(std::mem::take(&mut current));
+                }
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    if !current.is_empty() {
+        result.push(current);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_module() {
+        let module = Module::parse(include_str!("../test-outputs/llvm-ir-release.ll"));
+        assert_eq!(
+            module.target_triple.as_deref(),
+            Some("x86_64-unknown-linux-gnu")
+        );
+        assert_eq!(module.globals.len(), 3);
+        assert_eq!(module.functions.len(), 4);
+        assert_eq!(module.declarations.len(), 4);
+        assert_eq!(
+            module.metadata.last().unwrap().value,
+            "distinct !{!8, !7, !6}"
+        );
+
+        let sum = module.function("sum").unwrap();
+        assert_eq!(sum.symbol, "_ZN10playground3sum17h120ca45857a523a1E");
+        assert!(sum.attributes.contains(&"memory(argmem: read)".to_owned()));
+        let labels: Vec<_> = sum.blocks.iter().map(|b| b.label.as_str()).collect();
+        assert_eq!(
+            labels[..4],
+            ["start", "bb8.i.preheader", "vector.ph", "vector.body"]
+        );
+        assert_eq!(sum.blocks[3].predecessors, ["vector.body", "vector.ph"]);
+        assert!(sum.blocks[7]
+            .label
+            .ends_with("fold17h2b0920ce6c2f14faE.exit"));
+
+        let latch = sum.blocks[3].instructions.last().unwrap();
+        assert_eq!(latch.opcode, "br");
+        assert_eq!(
+            latch.metadata,
+            [MetadataRef {
+                kind: "llvm.loop".to_owned(),
+                node: "!5".to_owned()
+            }]
+        );
+
+        let get = module.function("get").unwrap();
+        let call = get.instructions().find(|i| i.opcode == "call").unwrap();
+        assert_eq!(
+            call.callee.as_deref(),
+            Some("core::panicking::panic_bounds_check")
+        );
+    }
+
+    #[test]
+    fn computes_statistics() {
+        let module = Module::parse(include_str!("../test-outputs/llvm-ir-debug.ll"));
+        let checked_sum = module.function("checked_sum").unwrap().statistics();
+        assert_eq!(checked_sum.basic_blocks, 3);
+        assert_eq!(checked_sum.instructions, 8);
+        assert_eq!(checked_sum.calls, 3);
+        assert_eq!(checked_sum.callees["playground::sum"], 1);
+        assert_eq!(
+            checked_sum.to_string(),
+            "3 blocks, 8 instructions (3 call, 1 add, 1 br, 1 icmp, 1 ret, 1 unreachable), \
+             0 allocas, 3 calls"
+        );
+
+        let statistics = module.statistics();
+        assert_eq!(
+            statistics.basic_blocks,
+            module
+                .functions
+                .iter()
+                .map(|f| f.blocks.len())
+                .sum::<usize>()
+        );
+        assert!(statistics.allocas > 0);
+    }
+
+    #[test]
+    fn extracts_function() {
+        let module = Module::parse(include_str!("../test-outputs/llvm-ir-release.ll"));
+        let get = module.extract("playground::get").unwrap();
+        assert!(get.starts_with("; playground::get\ndefine noundef i32 @_ZN10playground3get"));
+        assert!(get.contains("\n}\n\n; core::panicking::panic_bounds_check\ndeclare void"));
+        assert!(!get.contains("unwrap_failed"));
+        assert!(module.extract("missing").is_none());
+    }
+
+    #[test]
+    fn strips_debug_info() {
+        let ir = include_str!("../test-outputs/llvm-ir-debuginfo.ll");
+        let stripped = strip_debug_info(ir);
+        assert!(!stripped.contains("dbg"));
+        assert!(!stripped.contains("!DI"));
+        assert!(stripped.len() < ir.len() / 2);
+        assert!(stripped.contains("br i1 %_5.not, label %bb2, label %bb1, !prof !"));
+        assert!(stripped.contains("unnamed_addr #0 {\n"));
+
+        // Non-debug metadata like loop hints survives with its dependencies.
+        let module = Module::parse(&stripped);
+        assert_eq!(module.functions.len(), 4);
+        assert!(module
+            .metadata
+            .iter()
+            .any(|node| node.value.contains("llvm.loop.isvectorized")));
+        assert_eq!(
+            Module::parse(ir).function("sum").unwrap().statistics(),
+            module.function("sum").unwrap().statistics()
+        );
+    }
+}
@@ -0,0 +1,696 @@
+//! Parsing of the MIR output of [`CompileTarget::Mir`](crate::endpoints::CompileTarget::Mir).
+//!
+//! [`Mir::parse`] splits the output into functions with their locals and basic
+//! blocks. Each [`Function`] can be exported as a control-flow graph in Graphviz
+//! DOT format with [`Function::to_dot`], or summarized as plain text with
+//! [`Function::summary`].
+
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+/// A local variable, argument or temporary like `_3`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Local {
+    /// The local, e.g. `_3`. `_0` is the return place.
+    pub name: String,
+
+    /// The type, e.g. `std::string::String`.
+    pub ty: String,
+
+    /// Whether the local is declared with `let mut`. Always `false` for arguments.
+    pub mutable: bool,
+
+    /// Whether the local is a function argument.
+    pub argument: bool,
+
+    /// The names of the user variables stored in the local, from the `debug`
+    /// lines, e.g. `["names"]`. Empty for temporaries.
+    pub variables: Vec<String>,
+}
+
+/// The kind of a terminator.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum TerminatorKind {
+    /// `goto -> bb3`
+    Goto,
+
+    /// `switchInt(move _2) -> [0: bb2, otherwise: bb1]`
+    SwitchInt,
+
+    /// `return`
+    Return,
+
+    /// `unreachable`
+    Unreachable,
+
+    /// A function call like `_0 = String::new() -> [return: bb9, unwind continue]`.
+    Call,
+
+    /// `drop(_1) -> [return: bb13, unwind continue]`
+    Drop,
+
+    /// A runtime check like an overflow or bounds check which panics if it fails.
+    Assert,
+
+    /// `resume`, continuing to unwind after a cleanup block.
+    UnwindResume,
+
+    /// `abort` or `terminate`, aborting the process while unwinding.
+    UnwindTerminate,
+
+    /// Any other terminator, e.g. `yield` or `falseEdge`.
+    Other,
+}
+
+/// An edge of the control-flow graph.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Edge {
+    /// The label of the target, e.g. `return`, `unwind`, `0` or `otherwise`.
+    /// Empty for a `goto`.
+    pub label: String,
+
+    /// The target block, e.g. `bb3`.
+    pub target: String,
+}
+
+impl Edge {
+    /// Returns `true` for edges taken while unwinding after a panic.
+    pub fn is_unwind(&self) -> bool {
+        self.label == "unwind"
+    }
+}
+
+/// The last instruction of a basic block, which decides where to continue.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Terminator {
+    /// The kind of terminator.
+    pub kind: TerminatorKind,
+
+    /// The terminator without its targets, e.g. `_0 = String::new()`.
+    pub text: String,
+
+    /// The successors of the block.
+    pub targets: Vec<Edge>,
+}
+
+impl Terminator {
+    /// Parses a terminator like `switchInt(move _2) -> [0: bb2, otherwise: bb1]`.
+    fn parse(line: &str) -> Self {
+        let line = line.trim().trim_end_matches(';');
+        let (text, targets) = match line.rsplit_once(" -> ") {
+            Some((text, targets)) if targets.starts_with(['[', 'b']) => (text, targets),
+            _ => (line, ""),
+        };
+
+        let targets = match targets.strip_prefix('[') {
+            Some(list) => list
+                .trim_end_matches(']')
+                .split(", ")
+                .filter_map(|target| {
+                    let (label, target) = target.split_once(": ")?;
+                    target.starts_with("bb").then(|| Edge {
+                        label: label.to_owned(),
+                        target: target.to_owned(),
+                    })
+                })
+                .collect(),
+            None if !targets.is_empty() => vec![Edge {
+                label: String::new(),
+                target: targets.to_owned(),
+            }],
+            None => Vec::new(),
+        };
+
+        let operation = match text.split_once(" = ") {
+            Some((place, operation)) if place.starts_with('_') => operation,
+            _ => text,
+        };
+        let keyword = operation
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .next()
+            .unwrap_or_default();
+        let kind = match keyword {
+            "goto" => TerminatorKind::Goto,
+            "switchInt" => TerminatorKind::SwitchInt,
+            "return" => TerminatorKind::Return,
+            "unreachable" => TerminatorKind::Unreachable,
+            "drop" => TerminatorKind::Drop,
+            "assert" => TerminatorKind::Assert,
+            "resume" => TerminatorKind::UnwindResume,
+            "abort" | "terminate" => TerminatorKind::UnwindTerminate,
+            "yield" | "falseEdge" | "falseUnwind" | "coroutine_drop" | "asm" => {
+                TerminatorKind::Other
+            }
+            _ if operation.contains('(') => TerminatorKind::Call,
+            _ => TerminatorKind::Other,
+        };
+
+        Self {
+            kind,
+            text: text.to_owned(),
+            targets,
+        }
+    }
+}
+
+/// A basic block.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BasicBlock {
+    /// The block name, e.g. `bb3`.
+    pub name: String,
+
+    /// Whether the block only runs while unwinding.
+    pub cleanup: bool,
+
+    /// The statements without the trailing `;`, e.g. `_4 = move _2`.
+    pub statements: Vec<String>,
+
+    /// The terminator.
+    pub terminator: Terminator,
+}
+
+/// A MIR body: a function, closure, constant or promoted constant.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Function {
+    /// The name, e.g. `main`, `add_one::{closure#0}` or `main::promoted[0]`.
+    pub name: String,
+
+    /// The header without the opening brace, e.g. `fn describe(_1: Option<i32>) -> String`.
+    pub signature: String,
+
+    /// The locals, sorted by number, starting with the return place `_0`.
+    pub locals: Vec<Local>,
+
+    /// The basic blocks, starting with `bb0`.
+    pub blocks: Vec<BasicBlock>,
+}
+
+impl Function {
+    /// Returns the block with the given name.
+    pub fn block(&self, name: &str) -> Option<&BasicBlock> {
+        self.blocks.iter().find(|block| block.name == name)
+    }
+
+    /// Returns the local with the given name, e.g. `_3`, or the local storing the
+    /// user variable with that name, e.g. `names`.
+    pub fn local(&self, name: &str) -> Option<&Local> {
+        self.locals
+            .iter()
+            .find(|local| local.name == name)
+            .or_else(|| {
+                self.locals
+                    .iter()
+                    .find(|local| local.variables.iter().any(|variable| variable == name))
+            })
+    }
+
+    /// Returns every edge of the control-flow graph as `(source block, edge)`.
+    pub fn edges(&self) -> impl Iterator<Item = (&str, &Edge)> {
+        self.blocks.iter().flat_map(|block| {
+            block
+                .terminator
+                .targets
+                .iter()
+                .map(|edge| (block.name.as_str(), edge))
+        })
+    }
+
+    /// Returns the names of the blocks jumping to the given block.
+    pub fn predecessors(&self, name: &str) -> Vec<&str> {
+        let mut predecessors: Vec<&str> = Vec::new();
+        for (source, _) in self.edges().filter(|(_, edge)| edge.target == name) {
+            if !predecessors.contains(&source) {
+                predecessors.push(source);
+            }
+        }
+        predecessors
+    }
+
+    /// Renders the control-flow graph in Graphviz DOT format.
+    ///
+    /// Each block is a node listing its statements and terminator. Cleanup blocks
+    /// and unwind edges are dashed, and edges are labelled with their target
+    /// label, e.g. `otherwise`. Render it with `dot -Tsvg`.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        let _ = writeln!(dot, "digraph {} {{", dot_string(&self.name));
+        let _ = writeln!(dot, "    label={};", dot_string(&self.signature));
+        let _ = writeln!(dot, "    node [shape=box, fontname=\"monospace\"];");
+        let _ = writeln!(dot, "    edge [fontname=\"monospace\"];");
+
+        for block in &self.blocks {
+            // `\l` ends a left-aligned line.
+            let lines: Vec<String> = std::iter::once(&block.name)
+                .chain(&block.statements)
+                .chain(std::iter::once(&block.terminator.text))
+                .map(|line| dot_escape(line))
+                .collect();
+            let label = format!("\"{}\\l\"", lines.join("\\l"));
+            let style = if block.cleanup { ", style=dashed" } else { "" };
+            let _ = writeln!(dot, "    {} [label={label}{style}];", block.name);
+        }
+        for (source, edge) in self.edges() {
+            let mut attributes = Vec::new();
+            if !edge.label.is_empty() {
+                attributes.push(format!("label={}", dot_string(&edge.label)));
+            }
+            if edge.is_unwind() {
+                attributes.push("style=dashed".to_owned());
+            }
+            let attributes = if attributes.is_empty() {
+                String::new()
+            } else {
+                format!(" [{}]", attributes.join(", "))
+            };
+            let _ = writeln!(dot, "    {source} -> {}{attributes};", edge.target);
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Renders a plain-text summary with the named locals and one line per block.
+    ///
+    /// ```text
+    /// fn add_one(_1: u8) -> u8
+    /// 2 blocks, 5 locals (x: _1, increment: _2)
+    /// bb0: 2 statements, call <{closure@src/main.rs:19:21: 19:28} as Fn<(u8,)>>::call -> return: bb1
+    /// bb1: return
+    /// ```
+    pub fn summary(&self) -> String {
+        let named: Vec<String> = self
+            .locals
+            .iter()
+            .flat_map(|local| {
+                local
+                    .variables
+                    .iter()
+                    .map(move |variable| format!("{variable}: {}", local.name))
+            })
+            .collect();
+
+        let mut summary = format!("{}\n", self.signature);
+        let _ = write!(
+            summary,
+            "{} blocks, {} locals",
+            self.blocks.len(),
+            self.locals.len()
+        );
+        if !named.is_empty() {
+            let _ = write!(summary, " ({})", named.join(", "));
+        }
+        summary.push('\n');
+
+        for block in &self.blocks {
+            let _ = write!(summary, "{}", block.name);
+            if block.cleanup {
+                summary.push_str(" (cleanup)");
+            }
+            summary.push_str(": ");
+            match block.statements.len() {
+                0 => {}
+                1 => summary.push_str("1 statement, "),
+                count => {
+                    let _ = write!(summary, "{count} statements, ");
+                }
+            }
+            summary.push_str(&terminator_summary(&block.terminator));
+            let targets: Vec<String> = block
+                .terminator
+                .targets
+                .iter()
+                .map(|edge| match edge.label.as_str() {
+                    "" => edge.target.clone(),
+                    label => format!("{label}: {}", edge.target),
+                })
+                .collect();
+            if !targets.is_empty() {
+                let _ = write!(summary, " -> {}", targets.join(", "));
+            }
+            summary.push('\n');
+        }
+        summary
+    }
+}
+
+/// Parsed MIR output.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Mir {
+    /// The bodies in order of appearance.
+    pub functions: Vec<Function>,
+}
+
+impl Mir {
+    /// Parses MIR output.
+    ///
+    /// Allocations printed after the bodies, like string constants, are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use playground_api::mir::{Mir, TerminatorKind};
+    ///
+    /// let mir = "fn main() -> () {\n    let mut _0: ();\n\n    bb0: {\n        return;\n    }\n}\n";
+    /// let main = &Mir::parse(mir).functions[0];
+    ///
+    /// assert_eq!(main.blocks[0].terminator.kind, TerminatorKind::Return);
+    /// assert!(main.to_dot().starts_with("digraph \"main\" {"));
+    /// ```
+    pub fn parse(text: &str) -> Self {
+        let mut functions = Vec::new();
+        let mut lines = text.lines();
+
+        while let Some(line) = lines.next() {
+            let Some(header) = line.strip_suffix(" {") else {
+                continue;
+            };
+            let Some(name) = body_name(header) else {
+                continue;
+            };
+            let body: Vec<&str> = lines.by_ref().take_while(|line| *line != "}").collect();
+            let (locals, blocks) = parse_body(header, &body);
+            functions.push(Function {
+                name,
+                signature: header.trim_end_matches(" =").to_owned(),
+                locals,
+                blocks,
+            });
+        }
+
+        Self { functions }
+    }
+
+    /// Finds a body by name, e.g. `main` or `add_one::{closure#0}`.
+    pub fn function(&self, name: &str) -> Option<&Function> {
+        self.functions.iter().find(|function| function.name == name)
+    }
+
+    /// Renders the control-flow graphs of all bodies as one DOT graph with a
+    /// cluster per body.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph mir {\n    compound=true;\n");
+        for (idx, function) in self.functions.iter().enumerate() {
+            let graph = function.to_dot();
+            let _ = writeln!(dot, "    subgraph cluster_{idx} {{");
+            for line in graph.lines().skip(1) {
+                if line == "}" {
+                    continue;
+                }
+                // Prefix the block names so they are unique across bodies.
+                let line = prefix_blocks(line, idx);
+                let _ = writeln!(dot, "    {line}");
+            }
+            dot.push_str("    }\n");
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Returns the name of a body from its header, e.g. `describe` from
+/// `fn describe(_1: Option<i32>) -> String`, or `None` for other lines.
+fn body_name(header: &str) -> Option<String> {
+    if let Some(rest) = header.strip_prefix("fn ") {
+        let end = rest.find('(')?;
+        return Some(rest[..end].to_owned());
+    }
+    if let Some(rest) = header
+        .strip_prefix("const ")
+        .or_else(|| header.strip_prefix("static mut "))
+        .or_else(|| header.strip_prefix("static "))
+    {
+        return Some(rest.split_once(':')?.0.to_owned());
+    }
+    // Promoted constants: `promoted[0] in main: &i32 = {`.
+    let (promoted, rest) = header.split_once(" in ")?;
+    if !promoted.starts_with("promoted[") {
+        return None;
+    }
+    Some(format!("{}::{promoted}", rest.split_once(':')?.0))
+}
+
+/// Parses the locals and blocks of a body.
+fn parse_body(header: &str, body: &[&str]) -> (Vec<Local>, Vec<BasicBlock>) {
+    let mut locals: Vec<Local> = parameters(header)
+        .into_iter()
+        .filter_map(|parameter| {
+            let (name, ty) = parameter.split_once(": ")?;
+            Some(Local {
+                name: name.to_owned(),
+                ty: ty.to_owned(),
+                mutable: false,
+                argument: true,
+                variables: Vec::new(),
+            })
+        })
+        .collect();
+    let mut variables: Vec<(String, String)> = Vec::new();
+    let mut blocks = Vec::new();
+
+    let mut lines = body.iter();
+    while let Some(line) = lines.next() {
+        let line = line.trim();
+        if let Some(declaration) = line.strip_prefix("let ") {
+            let (mutable, declaration) = match declaration.strip_prefix("mut ") {
+                Some(declaration) => (true, declaration),
+                None => (false, declaration),
+            };
+            if let Some((name, ty)) = declaration.trim_end_matches(';').split_once(": ") {
+                locals.push(Local {
+                    name: name.to_owned(),
+                    ty: ty.to_owned(),
+                    mutable,
+                    argument: false,
+                    variables: Vec::new(),
+                });
+            }
+        } else if let Some(debug) = line.strip_prefix("debug ") {
+            if let Some((variable, local)) = debug.trim_end_matches(';').split_once(" => ") {
+                variables.push((variable.to_owned(), local.to_owned()));
+            }
+        } else if let Some(header) = line.strip_suffix(": {") {
+            let (name, cleanup) = match header.strip_suffix(" (cleanup)") {
+                Some(name) => (name, true),
+                None => (header, false),
+            };
+            let mut statements: Vec<String> = lines
+                .by_ref()
+                .map(|line| line.trim())
+                .take_while(|line| *line != "}")
+                .filter(|line| !line.is_empty())
+                .map(|line| line.trim_end_matches(';').to_owned())
+                .collect();
+            let terminator = statements.pop().unwrap_or_default();
+            blocks.push(BasicBlock {
+                name: name.to_owned(),
+                cleanup,
+                statements,
+                terminator: Terminator::parse(&terminator),
+            });
+        }
+    }
+
+    for (variable, local) in variables {
+        if let Some(local) = locals.iter_mut().find(|l| l.name == local) {
+            if !local.variables.contains(&variable) {
+                local.variables.push(variable);
+            }
+        }
+    }
+    locals.sort_by_key(|local| local.name[1..].parse::<usize>().unwrap_or(usize::MAX));
+    (locals, blocks)
+}
+
+/// Splits the parameter list of a `fn` header at the top-level commas.
+fn parameters(header: &str) -> Vec<&str> {
+    let Some(start) = header.find('(') else {
+        return Vec::new();
+    };
+    let mut parameters = Vec::new();
+    let mut depth = 0usize;
+    let mut begin = start + 1;
+    for (idx, c) in header.char_indices().skip(start + 1) {
+        match c {
+            '(' | '[' | '{' | '<' => depth += 1,
+            ')' if depth == 0 => {
+                parameters.push(&header[begin..idx]);
+                break;
+            }
+            ')' | ']' | '}' | '>' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parameters.push(&header[begin..idx]);
+                begin = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    parameters
+        .into_iter()
+        .map(str::trim)
+        .filter(|parameter| !parameter.is_empty())
+        .collect()
+}
+
+/// Describes a terminator in a few words, e.g. `call String::new` or `switchInt`.
+fn terminator_summary(terminator: &Terminator) -> String {
+    match terminator.kind {
+        TerminatorKind::Call => {
+            let operation = match terminator.text.split_once(" = ") {
+                Some((place, operation)) if place.starts_with('_') => operation,
+                _ => &terminator.text,
+            };
+            let callee = &operation[..argument_start(operation)];
+            format!("call {callee}")
+        }
+        TerminatorKind::Goto => "goto".to_owned(),
+        TerminatorKind::Assert => "assert".to_owned(),
+        _ => terminator
+            .text
+            .split(|c: char| c == '(' || c.is_whitespace())
+            .next()
+            .unwrap_or_default()
+            .to_owned(),
+    }
+}
+
+/// Returns the index of the parenthesis opening the argument list at the end of
+/// a call like `foo(move (_1.0: i32))`.
+fn argument_start(call: &str) -> usize {
+    let mut depth = 0usize;
+    for (idx, c) in call.char_indices().rev() {
+        match c {
+            ')' => depth += 1,
+            '(' if depth <= 1 => return idx,
+            '(' => depth -= 1,
+            _ => {}
+        }
+    }
+    call.len()
+}
+
+/// Quotes a string for DOT.
+fn dot_string(text: &str) -> String {
+    format!("\"{}\"", dot_escape(text))
+}
+
+/// Escapes backslashes and quotes for a DOT string.
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Prefixes the block names in a line of a body's DOT graph, e.g. `bb0` to `f1_bb0`.
+fn prefix_blocks(line: &str, idx: usize) -> String {
+    let trimmed = line.trim_start();
+    let indent = &line[..line.len() - trimmed.len()];
+    if !trimmed.starts_with("bb") {
+        return line.to_owned();
+    }
+    match trimmed.split_once(" -> ") {
+        Some((source, rest)) => format!("{indent}f{idx}_{source} -> f{idx}_{rest}"),
+        None => format!("{indent}f{idx}_{trimmed}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mir() -> Mir {
+        Mir::parse(include_str!("../test-outputs/mir.mir"))
+    }
+
+    #[test]
+    fn parses_bodies() {
+        let mir = mir();
+        let names: Vec<_> = mir.functions.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["describe", "main", "add_one", "add_one::{closure#0}"]
+        );
+
+        let describe = mir.function("describe").unwrap();
+        assert_eq!(describe.signature, "fn describe(_1: Option<i32>) -> String");
+        assert_eq!(describe.blocks.len(), 10);
+        assert_eq!(describe.locals[0].name, "_0");
+        assert!(describe.locals[1].argument);
+        assert_eq!(describe.locals[1].variables, ["value"]);
+        assert_eq!(describe.local("n").unwrap().name, "_3");
+        assert_eq!(describe.local("_13").unwrap().ty, "&[u8; 12]");
+
+        let closure = mir.function("add_one::{closure#0}").unwrap();
+        assert_eq!(closure.locals[1].ty, "&{closure@src/main.rs:19:21: 19:28}");
+        assert_eq!(closure.locals[2].variables, ["y"]);
+    }
+
+    #[test]
+    fn parses_terminators() {
+        let mir = mir();
+        let describe = mir.function("describe").unwrap();
+        let bb0 = describe.block("bb0").unwrap();
+        assert_eq!(bb0.statements, ["_2 = discriminant(_1)"]);
+        assert_eq!(bb0.terminator.kind, TerminatorKind::SwitchInt);
+        let targets: Vec<_> = bb0
+            .terminator
+            .targets
+            .iter()
+            .map(|edge| (edge.label.as_str(), edge.target.as_str()))
+            .collect();
+        assert_eq!(targets, [("0", "bb2"), ("1", "bb3"), ("otherwise", "bb1")]);
+        assert_eq!(describe.predecessors("bb9"), ["bb2", "bb5", "bb8"]);
+
+        let main = mir.function("main").unwrap();
+        let bb16 = main.block("bb16").unwrap();
+        assert!(bb16.cleanup);
+        assert_eq!(bb16.terminator.kind, TerminatorKind::Drop);
+        assert_eq!(
+            main.block("bb17").unwrap().terminator.kind,
+            TerminatorKind::UnwindResume
+        );
+        assert_eq!(
+            main.block("bb18").unwrap().terminator.kind,
+            TerminatorKind::Goto
+        );
+        assert!(main
+            .block("bb3")
+            .unwrap()
+            .terminator
+            .targets
+            .iter()
+            .any(Edge::is_unwind));
+
+        let closure = mir.function("add_one::{closure#0}").unwrap();
+        let assert = &closure.blocks[0].terminator;
+        assert_eq!(assert.kind, TerminatorKind::Assert);
+        assert_eq!(assert.targets[0].label, "success");
+    }
+
+    #[test]
+    fn renders_dot() {
+        let mir = mir();
+        let dot = mir.function("main").unwrap().to_dot();
+        assert!(dot.starts_with("digraph \"main\" {\n    label=\"fn main() -> ()\";\n"));
+        assert!(dot.contains("    bb2 [label=\"bb2\\l_4 = move _2\\lgoto\\l\"];\n"));
+        assert!(dot.contains("    bb16 [label=\"bb16\\ldrop(_1)\\l\", style=dashed];\n"));
+        assert!(dot.contains("    bb3 -> bb16 [label=\"unwind\", style=dashed];\n"));
+        assert!(dot.contains("    bb18 -> bb3;\n"));
+        assert!(dot.ends_with("}\n"));
+
+        let all = mir.to_dot();
+        assert!(all.contains("    subgraph cluster_3 {\n"));
+        assert!(all.contains("        f1_bb18 -> f1_bb3;\n"));
+    }
+
+    #[test]
+    fn renders_summary() {
+        let mir = mir();
+        assert_eq!(
+            mir.function("add_one").unwrap().summary(),
+            "fn add_one(_1: u8) -> u8\n\
+             2 blocks, 5 locals (x: _1)\n\
+             bb0: 2 statements, call <{closure@src/main.rs:19:21: 19:28} as Fn<(u8,)>>::call -> return: bb1\n\
+             bb1: return\n"
+        );
+        let summary = mir.function("main").unwrap().summary();
+        assert!(summary.contains("bb16 (cleanup): drop -> return: bb17\n"));
+        assert!(summary.contains("bb4: 1 statement, switchInt -> 0: bb7, 1: bb6, otherwise: bb5\n"));
+    }
+}
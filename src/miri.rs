@@ -0,0 +1,520 @@
+//! Parsing of the errors Miri reports, like undefined behavior or memory leaks.
+//!
+//! Miri prints a rustc-style diagnostic which, besides the message and location,
+//! contains the history of the borrow tags involved and a backtrace. [`parse`]
+//! turns it into a [`MiriReport`].
+
+use crate::{
+    diagnostics::{self, Span},
+    endpoints::AliasingModel,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The kind of error Miri stopped with, taken from the start of the message.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// `Undefined Behavior: ...`
+    UndefinedBehavior,
+
+    /// `unsupported operation: ...`, e.g. a foreign function Miri can't run.
+    Unsupported,
+
+    /// `memory leaked: ...`
+    MemoryLeak,
+
+    /// `deadlock: ...`
+    Deadlock,
+
+    /// `resource exhaustion: ...`, e.g. a stack overflow.
+    ResourceExhaustion,
+
+    /// `abnormal termination: ...`, e.g. a call to `std::process::abort`.
+    AbnormalTermination,
+}
+
+impl ErrorKind {
+    const PREFIXES: [(&'static str, ErrorKind); 6] = [
+        ("Undefined Behavior: ", ErrorKind::UndefinedBehavior),
+        ("unsupported operation: ", ErrorKind::Unsupported),
+        ("memory leaked: ", ErrorKind::MemoryLeak),
+        ("deadlock: ", ErrorKind::Deadlock),
+        ("resource exhaustion: ", ErrorKind::ResourceExhaustion),
+        ("abnormal termination: ", ErrorKind::AbnormalTermination),
+    ];
+
+    /// Splits a message like `Undefined Behavior: ...` into the kind and the rest.
+    fn split(message: &str) -> Option<(Self, &str)> {
+        Self::PREFIXES.iter().find_map(|(prefix, kind)| {
+            message
+                .strip_prefix(prefix)
+                .map(|rest| (*kind, rest.trim()))
+        })
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::UndefinedBehavior => write!(f, "undefined behavior"),
+            ErrorKind::Unsupported => write!(f, "unsupported operation"),
+            ErrorKind::MemoryLeak => write!(f, "memory leak"),
+            ErrorKind::Deadlock => write!(f, "deadlock"),
+            ErrorKind::ResourceExhaustion => write!(f, "resource exhaustion"),
+            ErrorKind::AbnormalTermination => write!(f, "abnormal termination"),
+        }
+    }
+}
+
+/// What kind of undefined behavior was detected, estimated from the message.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum UbCategory {
+    /// A violation of the aliasing rules checked by Stacked or Tree Borrows.
+    Aliasing,
+
+    /// An access outside of the bounds of an allocation.
+    OutOfBounds,
+
+    /// An access to memory which has already been freed.
+    UseAfterFree,
+
+    /// A read of uninitialized memory.
+    Uninitialized,
+
+    /// A value which is invalid for its type, e.g. a `bool` that is `2`.
+    InvalidValue,
+
+    /// Two threads accessed the same memory without synchronization.
+    DataRace,
+
+    /// An access through a pointer which isn't sufficiently aligned.
+    Misaligned,
+
+    /// Any other kind of undefined behavior.
+    Other,
+}
+
+impl UbCategory {
+    fn classify(message: &str, aliasing_model: Option<AliasingModel>) -> Self {
+        let message = message.to_lowercase();
+        let has = |needle: &str| message.contains(needle);
+
+        if has("data race") {
+            UbCategory::DataRace
+        } else if aliasing_model.is_some() || has("borrow stack") || has("protector") {
+            UbCategory::Aliasing
+        } else if has("has been freed") || has("dangling") {
+            UbCategory::UseAfterFree
+        } else if has("out-of-bounds") || has("out of bounds") {
+            UbCategory::OutOfBounds
+        } else if has("uninitialized") {
+            UbCategory::Uninitialized
+        } else if has("alignment") || has("misaligned") {
+            UbCategory::Misaligned
+        } else if has("invalid value") {
+            UbCategory::InvalidValue
+        } else {
+            UbCategory::Other
+        }
+    }
+}
+
+impl fmt::Display for UbCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UbCategory::Aliasing => write!(f, "aliasing violation"),
+            UbCategory::OutOfBounds => write!(f, "out-of-bounds access"),
+            UbCategory::UseAfterFree => write!(f, "use after free"),
+            UbCategory::Uninitialized => write!(f, "uninitialized memory"),
+            UbCategory::InvalidValue => write!(f, "invalid value"),
+            UbCategory::DataRace => write!(f, "data race"),
+            UbCategory::Misaligned => write!(f, "misaligned access"),
+            UbCategory::Other => write!(f, "other"),
+        }
+    }
+}
+
+/// What happened to a borrow tag in a [`TagEvent`].
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum TagEventKind {
+    /// The tag was created by a reference or a retag.
+    Created,
+
+    /// The tag was invalidated by a conflicting access (Stacked Borrows).
+    Invalidated,
+
+    /// The tag changed its permission, e.g. to `Disabled` (Tree Borrows).
+    Transitioned,
+
+    /// The tag is protected because it was passed to a function.
+    Protected,
+
+    /// Any other event.
+    Other,
+}
+
+/// A step in the history of a borrow tag, e.g. "<2586> was created by a SharedReadOnly retag".
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TagEvent {
+    /// The tag number, e.g. `2586` for `<2586>`.
+    pub tag: Option<u64>,
+
+    /// What happened to the tag.
+    pub kind: TagEventKind,
+
+    /// The full help message describing the event.
+    pub description: String,
+
+    /// The code which caused the event.
+    pub span: Option<Span>,
+}
+
+/// A frame of the backtrace Miri prints below the error.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StackFrame {
+    /// The function, e.g. `main` or `std::rt::lang_start::<()>`, or `closure`.
+    pub function: String,
+
+    /// The code the frame was executing.
+    pub span: Option<Span>,
+}
+
+/// A parsed Miri error.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MiriReport {
+    /// The kind of error.
+    pub kind: ErrorKind,
+
+    /// The category of undefined behavior, [`UbCategory::Other`] for other kinds.
+    pub category: UbCategory,
+
+    /// The message without the kind prefix.
+    pub message: String,
+
+    /// The location of the error in the submitted code, falling back to the
+    /// innermost backtrace frame in the submitted code.
+    pub location: Option<Span>,
+
+    /// The creation, invalidation and other events of the borrow tags involved.
+    pub history: Vec<TagEvent>,
+
+    /// The aliasing model whose rules were violated, if any.
+    pub aliasing_model: Option<AliasingModel>,
+
+    /// The backtrace, innermost frame first.
+    pub backtrace: Vec<StackFrame>,
+
+    /// The help messages Miri attached, including those of the history events.
+    pub help: Vec<String>,
+}
+
+impl fmt::Display for MiriReport {
+    /// Renders a short explanation with the location and the tag history.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+        if self.kind == ErrorKind::UndefinedBehavior {
+            write!(f, " ({}", self.category)?;
+            match self.aliasing_model {
+                Some(AliasingModel::Stacked) => write!(f, ", Stacked Borrows")?,
+                Some(AliasingModel::Tree) => write!(f, ", Tree Borrows")?,
+                None => {}
+            }
+            write!(f, ")")?;
+        }
+        if let Some(span) = &self.location {
+            write!(f, " at {}:{}:{}", span.file, span.line, span.column)?;
+        }
+        write!(f, ": {}", self.message)?;
+        for event in &self.history {
+            write!(f, "\n  {}", event.description)?;
+            if let Some(span) = &event.span {
+                write!(f, " ({}:{}:{})", span.file, span.line, span.column)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses the first Miri error in the given stderr.
+///
+/// Returns `None` if Miri didn't report an error, e.g. because the program ran
+/// fine or didn't compile.
+///
+/// # Example
+///
+/// ```
+/// use playground_api::miri::{parse, ErrorKind, UbCategory};
+///
+/// let stderr = "error: Undefined Behavior: memory access failed: alloc1 has been freed, so this pointer is dangling\n --> src/main.rs:5:5\n";
+/// let report = parse(stderr).unwrap();
+///
+/// assert_eq!(report.kind, ErrorKind::UndefinedBehavior);
+/// assert_eq!(report.category, UbCategory::UseAfterFree);
+/// assert_eq!(report.location.unwrap().line, 5);
+/// ```
+pub fn parse(stderr: &str) -> Option<MiriReport> {
+    let lines: Vec<&str> = stderr.lines().collect();
+    let start = lines.iter().position(|line| {
+        line.strip_prefix("error: ")
+            .and_then(ErrorKind::split)
+            .is_some()
+    })?;
+    let end = lines[start + 1..]
+        .iter()
+        .position(|line| line.starts_with("error"))
+        .map_or(lines.len(), |offset| start + 1 + offset);
+    let block = &lines[start..end];
+
+    let diagnostic = diagnostics::parse(&block.join("\n")).into_iter().next()?;
+    let (kind, message) = ErrorKind::split(&diagnostic.message)?;
+
+    let aliasing_model = diagnostic.help.iter().find_map(|help| {
+        if help.contains("Stacked Borrows") {
+            Some(AliasingModel::Stacked)
+        } else if help.contains("Tree Borrows") {
+            Some(AliasingModel::Tree)
+        } else {
+            None
+        }
+    });
+    let category = match kind {
+        ErrorKind::UndefinedBehavior => UbCategory::classify(message, aliasing_model),
+        _ => UbCategory::Other,
+    };
+
+    let history = parse_history(block);
+    let backtrace = parse_backtrace(block);
+    let location = diagnostic
+        .span
+        .clone()
+        .filter(Span::is_user_code)
+        .or_else(|| {
+            backtrace
+                .iter()
+                .filter_map(|frame| frame.span.clone())
+                .find(Span::is_user_code)
+        });
+
+    Some(MiriReport {
+        kind,
+        category,
+        message: message.to_owned(),
+        location,
+        history,
+        aliasing_model,
+        backtrace,
+        help: diagnostic.help,
+    })
+}
+
+/// Collects the `help:` sub-diagnostics mentioning a tag, with the span shown below them.
+fn parse_history(block: &[&str]) -> Vec<TagEvent> {
+    let mut history = Vec::new();
+
+    for (idx, line) in block.iter().enumerate() {
+        let Some(description) = line.strip_prefix("help: ") else {
+            continue;
+        };
+        let Some(tag) = find_tag(description) else {
+            continue;
+        };
+
+        history.push(TagEvent {
+            tag: Some(tag),
+            kind: classify_event(description),
+            description: description.to_owned(),
+            span: snippet_span(&block[idx + 1..]),
+        });
+    }
+
+    history
+}
+
+/// Parses the `inside ...` notes making up the backtrace.
+///
+/// Recent Miri versions print the location on the same line
+/// (`= note: inside `main` at src/main.rs:6:29: 6:31`), older ones print a
+/// `note: inside `main`` header followed by a `-->` line.
+fn parse_backtrace(block: &[&str]) -> Vec<StackFrame> {
+    let mut backtrace = Vec::new();
+
+    for (idx, line) in block.iter().enumerate() {
+        let trimmed = line.trim_start();
+        let Some(frame) = trimmed
+            .strip_prefix("= note: inside ")
+            .or_else(|| trimmed.strip_prefix("note: inside "))
+        else {
+            continue;
+        };
+
+        let (function, location) = match frame.split_once(" at ") {
+            Some((function, location)) => (function, parse_span(location)),
+            None => (frame, snippet_span(&block[idx + 1..])),
+        };
+        backtrace.push(StackFrame {
+            function: function.trim_matches('`').to_owned(),
+            span: location,
+        });
+    }
+
+    backtrace
+}
+
+/// Reads the `-->` line and the markers of the snippet at the start of `lines`.
+fn snippet_span(lines: &[&str]) -> Option<Span> {
+    let location = lines.first()?.trim_start().strip_prefix("--> ")?;
+    let mut span = parse_span(location)?;
+
+    let markers = lines[1..]
+        .iter()
+        .take_while(|line| {
+            line.trim_start()
+                .starts_with(|c: char| c == '|' || c.is_ascii_digit())
+        })
+        .filter_map(|line| line.split_once('|').map(|(_, content)| content))
+        .find(|content| content.trim_start().starts_with('^'));
+    if let Some(markers) = markers {
+        let content = markers.strip_prefix(' ').unwrap_or(markers);
+        let start = content.len() - content.trim_start().len();
+        let count = content[start..].chars().take_while(|c| *c == '^').count();
+        if start + 1 == span.column {
+            span.end_column = span.column + count;
+        }
+    }
+
+    Some(span)
+}
+
+/// Parses `src/main.rs:6:29` or `src/main.rs:6:29: 6:31` into a span.
+fn parse_span(text: &str) -> Option<Span> {
+    let (start, end) = match text.trim().split_once(": ") {
+        Some((start, end)) => (start, Some(end)),
+        None => (text.trim(), None),
+    };
+
+    let mut parts = start.rsplitn(3, ':');
+    let column = parts.next()?.parse().ok()?;
+    let line = parts.next()?.parse().ok()?;
+    let file = parts.next()?.to_owned();
+
+    let (end_line, end_column) = end
+        .and_then(|end| {
+            let (line, column) = end.split_once(':')?;
+            Some((line.parse().ok()?, column.parse().ok()?))
+        })
+        .unwrap_or((line, column));
+
+    Some(Span {
+        file,
+        line,
+        column,
+        end_line,
+        end_column,
+    })
+}
+
+/// Returns the number of the first tag like `<2586>` in the text.
+fn find_tag(text: &str) -> Option<u64> {
+    text.match_indices('<').find_map(|(idx, _)| {
+        let rest = &text[idx + 1..];
+        let (number, _) = rest.split_once('>')?;
+        number.parse().ok()
+    })
+}
+
+fn classify_event(description: &str) -> TagEventKind {
+    if description.contains("was created") {
+        TagEventKind::Created
+    } else if description.contains("invalidated") {
+        TagEventKind::Invalidated
+    } else if description.contains("transitioned") {
+        TagEventKind::Transitioned
+    } else if description.contains("protect") || description.contains("is this argument") {
+        TagEventKind::Protected
+    } else {
+        TagEventKind::Other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stacked_borrows() {
+        let report = parse(include_str!("../test-outputs/miri-stacked.stderr")).unwrap();
+        assert_eq!(report.kind, ErrorKind::UndefinedBehavior);
+        assert_eq!(report.category, UbCategory::Aliasing);
+        assert_eq!(report.aliasing_model, Some(AliasingModel::Stacked));
+        assert!(report
+            .message
+            .starts_with("attempting a read access using <2586>"));
+
+        let location = report.location.as_ref().unwrap();
+        assert_eq!((location.line, location.column), (6, 29));
+        assert_eq!(location.end_column, 31);
+
+        assert_eq!(report.history.len(), 2);
+        assert_eq!(report.history[0].tag, Some(2586));
+        assert_eq!(report.history[0].kind, TagEventKind::Created);
+        let created = report.history[0].span.as_ref().unwrap();
+        assert_eq!(
+            (created.line, created.column, created.end_column),
+            (4, 13, 15)
+        );
+        assert_eq!(report.history[1].kind, TagEventKind::Invalidated);
+        assert_eq!(report.history[1].span.as_ref().unwrap().line, 5);
+
+        assert_eq!(report.backtrace.len(), 1);
+        assert_eq!(report.backtrace[0].function, "main");
+        assert_eq!(report.backtrace[0].span.as_ref().unwrap().end_column, 31);
+
+        assert_eq!(
+            report.to_string(),
+            "undefined behavior (aliasing violation, Stacked Borrows) at src/main.rs:6:29: \
+             attempting a read access using <2586> at alloc1147[0x0], but that tag does not \
+             exist in the borrow stack for this location\n  \
+             <2586> was created by a SharedReadOnly retag at offsets [0x0..0x4] (src/main.rs:4:13)\n  \
+             <2586> was later invalidated at offsets [0x0..0x4] by a write access (src/main.rs:5:5)"
+        );
+    }
+
+    #[test]
+    fn tree_borrows() {
+        let report = parse(include_str!("../test-outputs/miri-tree.stderr")).unwrap();
+        assert_eq!(report.category, UbCategory::Aliasing);
+        assert_eq!(report.aliasing_model, Some(AliasingModel::Tree));
+        assert_eq!(report.location.as_ref().unwrap().line, 7);
+
+        let tags: Vec<_> = report.history.iter().map(|event| event.tag).collect();
+        assert_eq!(tags, [Some(1322), Some(1320)]);
+        assert!(report
+            .history
+            .iter()
+            .all(|event| event.kind == TagEventKind::Created));
+        assert!(report
+            .help
+            .iter()
+            .any(|help| help.contains("has state Frozen")));
+    }
+
+    #[test]
+    fn other_errors() {
+        let leak = "error: memory leaked: alloc1 (Rust heap, size: 4, align: 4), allocated here:\n\
+                    \x20 --> /rustc/library/alloc/src/alloc.rs:100:9\n\
+                    \x20 = note: inside `std::alloc::alloc` at /rustc/library/alloc/src/alloc.rs:100:9: 100:20\n\
+                    note: inside `main`\n\
+                    \x20--> src/main.rs:2:5\n\
+                    \x20 |\n\
+                    2 |     Box::leak(Box::new(1));\n\
+                    \x20 |               ^^^^^^^^^^^\n";
+        let report = parse(leak).unwrap();
+        assert_eq!(report.kind, ErrorKind::MemoryLeak);
+        assert_eq!(report.category, UbCategory::Other);
+        assert_eq!(report.backtrace.len(), 2);
+        assert_eq!(report.backtrace[1].function, "main");
+        assert_eq!(report.location.as_ref().unwrap().line, 2);
+
+        assert!(parse(include_str!("../test-outputs/borrow.stderr")).is_none());
+    }
+}
@@ -0,0 +1,290 @@
+//! Typed exit status and outcome classification for playground responses.
+//!
+//! Every response carries a free-form `exit_detail` string. [`ExitStatus`] parses
+//! it, and [`Outcome`] combines it with the response's output to tell a
+//! successful run apart from a compile error, a panic, a signal or a timeout.
+
+use crate::{cargo_output, ice};
+use serde::{Deserialize, Serialize};
+use std::{convert::Infallible, fmt, str::FromStr};
+
+/// How the process behind a response exited.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ExitStatus {
+    /// The process exited with the given status code.
+    Code(i32),
+
+    /// The process was terminated by a signal.
+    Signal {
+        /// The signal number, e.g. `9` for `SIGKILL`.
+        number: i32,
+        /// The signal name if it was reported, e.g. `SIGKILL`.
+        name: Option<String>,
+    },
+
+    /// The exit detail couldn't be parsed. Contains the original text.
+    Unknown(String),
+}
+
+impl ExitStatus {
+    /// Parses an exit detail like `Exited with status 101`, `exit status: 1` or
+    /// `signal: 9 (SIGKILL)`.
+    ///
+    /// An empty exit detail counts as status `0` if the run was successful.
+    pub fn parse(exit_detail: &str, success: bool) -> Self {
+        let detail = exit_detail.trim();
+        if detail.is_empty() && success {
+            return ExitStatus::Code(0);
+        }
+
+        let lower = detail.to_lowercase();
+        let number_after = |keyword: &str| {
+            let rest = &lower[lower.find(keyword)? + keyword.len()..];
+            let rest = rest.trim_start_matches([':', ' ']);
+            let end = rest
+                .find(|c: char| !c.is_ascii_digit() && c != '-')
+                .unwrap_or(rest.len());
+            rest[..end].parse::<i32>().ok()
+        };
+
+        if let Some(number) = number_after("signal") {
+            let name = detail
+                .split(|c: char| !c.is_ascii_alphanumeric())
+                .find(|word| word.starts_with("SIG"))
+                .map(str::to_owned);
+            return ExitStatus::Signal { number, name };
+        }
+        if let Some(code) = number_after("status").or_else(|| number_after("code")) {
+            return ExitStatus::Code(code);
+        }
+
+        ExitStatus::Unknown(detail.to_owned())
+    }
+
+    /// Returns `true` if the process exited with status `0`.
+    pub fn is_success(&self) -> bool {
+        *self == ExitStatus::Code(0)
+    }
+}
+
+impl FromStr for ExitStatus {
+    type Err = Infallible;
+
+    /// Parses an exit detail, treating an empty string as an unknown status.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(s, false))
+    }
+}
+
+impl fmt::Display for ExitStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExitStatus::Code(code) => write!(f, "exit status {code}"),
+            ExitStatus::Signal {
+                number,
+                name: Some(name),
+            } => write!(f, "signal {number} ({name})"),
+            ExitStatus::Signal { number, name: None } => write!(f, "signal {number}"),
+            ExitStatus::Unknown(detail) => write!(f, "unknown exit status: {detail}"),
+        }
+    }
+}
+
+/// What happened when the playground ran a request.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    /// Everything succeeded.
+    Success,
+
+    /// The code failed to compile.
+    CompileError,
+
+    /// The compiler crashed with an internal compiler error.
+    InternalCompilerError,
+
+    /// The program panicked at runtime.
+    RuntimePanic,
+
+    /// The program exited with a non-zero status code.
+    NonZeroExit(i32),
+
+    /// The program was terminated by a signal, e.g. when running out of memory.
+    Signal(i32),
+
+    /// The playground stopped the run because it took too long.
+    Timeout,
+
+    /// The run succeeded but the playground cut off part of the output.
+    OutputTruncated,
+}
+
+impl Outcome {
+    /// Returns `true` for [`Outcome::Success`] and [`Outcome::OutputTruncated`].
+    pub fn is_success(&self) -> bool {
+        matches!(self, Outcome::Success | Outcome::OutputTruncated)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Success => write!(f, "success"),
+            Outcome::CompileError => write!(f, "compilation failed"),
+            Outcome::InternalCompilerError => write!(f, "the compiler crashed"),
+            Outcome::RuntimePanic => write!(f, "the program panicked"),
+            Outcome::NonZeroExit(code) => write!(f, "the program exited with status {code}"),
+            Outcome::Signal(number) => write!(f, "the program was killed by signal {number}"),
+            Outcome::Timeout => write!(f, "the playground timed out"),
+            Outcome::OutputTruncated => write!(f, "the output was truncated"),
+        }
+    }
+}
+
+/// Classifies a response whose process runs the user's program, like execute or Miri.
+pub(crate) fn classify_run(
+    success: bool,
+    exit_detail: &str,
+    stdout: &str,
+    stderr: &str,
+) -> Outcome {
+    if is_timeout(exit_detail, stderr) {
+        return Outcome::Timeout;
+    }
+    if success {
+        return if is_truncated(stdout) || is_truncated(stderr) {
+            Outcome::OutputTruncated
+        } else {
+            Outcome::Success
+        };
+    }
+
+    let parts = cargo_output::split(stderr);
+    if ice::is_ice(&parts.compiler.join("\n")) {
+        return Outcome::InternalCompilerError;
+    }
+    let ran = parts
+        .build_log
+        .iter()
+        .any(|line| cargo_output::status_verb(line) == Some("Running"));
+    if !ran && parts.compiler.iter().any(|line| line.starts_with("error")) {
+        return Outcome::CompileError;
+    }
+    if parts.program.contains("panicked at") {
+        return Outcome::RuntimePanic;
+    }
+
+    match ExitStatus::parse(exit_detail, success) {
+        ExitStatus::Signal { number, .. } => Outcome::Signal(number),
+        ExitStatus::Code(code) => Outcome::NonZeroExit(code),
+        ExitStatus::Unknown(_) if !ran => Outcome::CompileError,
+        ExitStatus::Unknown(_) => Outcome::NonZeroExit(1),
+    }
+}
+
+/// Classifies a response which only builds the code, like compile or Clippy.
+pub(crate) fn classify_build(success: bool, exit_detail: &str, stderr: &str) -> Outcome {
+    if is_timeout(exit_detail, stderr) {
+        Outcome::Timeout
+    } else if success {
+        Outcome::Success
+    } else if ice::is_ice(stderr) {
+        Outcome::InternalCompilerError
+    } else {
+        Outcome::CompileError
+    }
+}
+
+fn is_timeout(exit_detail: &str, stderr: &str) -> bool {
+    let detail = exit_detail.to_lowercase();
+    detail.contains("timed out")
+        || detail.contains("timeout")
+        || stderr.trim_end().ends_with("exceeded the time limit")
+}
+
+fn is_truncated(output: &str) -> bool {
+    output
+        .trim_end()
+        .lines()
+        .next_back()
+        .is_some_and(|line| line.to_lowercase().contains("output truncated"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_exit_status() {
+        assert_eq!(ExitStatus::parse("", true), ExitStatus::Code(0));
+        assert_eq!(
+            ExitStatus::parse("Exited with status 101", false),
+            ExitStatus::Code(101)
+        );
+        assert_eq!(
+            ExitStatus::parse("exit status: 1", false),
+            ExitStatus::Code(1)
+        );
+        assert_eq!(
+            ExitStatus::parse("signal: 9 (SIGKILL)", false),
+            ExitStatus::Signal {
+                number: 9,
+                name: Some("SIGKILL".to_owned())
+            }
+        );
+        assert_eq!(
+            ExitStatus::parse("Exited with signal 11", false),
+            ExitStatus::Signal {
+                number: 11,
+                name: None
+            }
+        );
+        assert_eq!(
+            ExitStatus::parse("something else", false),
+            ExitStatus::Unknown("something else".to_owned())
+        );
+    }
+
+    #[test]
+    fn classifies_runs() {
+        let panic = include_str!("../test-outputs/execute.stderr");
+        assert_eq!(
+            classify_run(false, "Exited with status 101", "", panic),
+            Outcome::RuntimePanic
+        );
+
+        let compile_error = include_str!("../test-outputs/borrow.stderr");
+        assert_eq!(
+            classify_run(false, "Exited with status 101", "", compile_error),
+            Outcome::CompileError
+        );
+
+        let ice = include_str!("../test-outputs/ice.stderr");
+        assert_eq!(
+            classify_run(false, "Exited with status 101", "", ice),
+            Outcome::InternalCompilerError
+        );
+        assert_eq!(
+            classify_build(false, "Exited with status 101", ice),
+            Outcome::InternalCompilerError
+        );
+
+        let killed = "     Running `target/debug/playground`\n";
+        assert_eq!(
+            classify_run(false, "Exited with signal 9", "", killed),
+            Outcome::Signal(9)
+        );
+        assert_eq!(
+            classify_run(false, "Exited with status 3", "", killed),
+            Outcome::NonZeroExit(3)
+        );
+        assert_eq!(
+            classify_run(
+                true,
+                "Exited with status 0",
+                "a\n[output truncated]\n",
+                killed
+            ),
+            Outcome::OutputTruncated
+        );
+    }
+}
@@ -0,0 +1,296 @@
+//! Panic message and backtrace parsing for runtime failures.
+//!
+//! When a program panics, the standard library prints the thread name, the
+//! panic location and message and, with backtraces enabled, the stack. [`parse`]
+//! turns that text into [`PanicInfo`] values.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A position in a source file, using 1-based lines and columns.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct Location {
+    /// The file, e.g. `src/main.rs`.
+    pub file: String,
+
+    /// The line number.
+    pub line: usize,
+
+    /// The column number, if it was printed.
+    pub column: Option<usize>,
+}
+
+impl Location {
+    /// Returns `true` if the location points into the submitted code.
+    pub fn is_user_code(&self) -> bool {
+        self.file.starts_with("src/") || self.file.starts_with("./src/")
+    }
+
+    /// Parses a location like `src/main.rs:6:6` or `./src/main.rs:6`.
+    fn parse(text: &str) -> Option<Self> {
+        let mut parts = text.trim().rsplitn(3, ':');
+        let last = parts.next()?.parse().ok()?;
+        match parts.next().map(str::parse) {
+            Some(Ok(line)) => Some(Self {
+                file: parts.next()?.to_owned(),
+                line,
+                column: Some(last),
+            }),
+            _ => {
+                let (file, _) = text.trim().rsplit_once(':')?;
+                Some(Self {
+                    file: file.to_owned(),
+                    line: last,
+                    column: None,
+                })
+            }
+        }
+    }
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.column {
+            Some(column) => write!(f, "{}:{}:{column}", self.file, self.line),
+            None => write!(f, "{}:{}", self.file, self.line),
+        }
+    }
+}
+
+/// A single frame of a backtrace.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Frame {
+    /// The demangled function name, e.g. `playground::main`.
+    pub function: String,
+
+    /// The source location of the frame, if debug info was available.
+    pub location: Option<Location>,
+}
+
+impl Frame {
+    /// Returns `true` if the frame belongs to the submitted code rather than to
+    /// `std`, `core`, `alloc` or the runtime.
+    pub fn is_user_code(&self) -> bool {
+        match &self.location {
+            Some(location) => location.is_user_code(),
+            None => self.function.starts_with("playground::"),
+        }
+    }
+}
+
+/// A parsed panic.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PanicInfo {
+    /// The name of the panicking thread, e.g. `main`.
+    pub thread: String,
+
+    /// The panic message, which may span multiple lines.
+    pub message: String,
+
+    /// Where the panic happened.
+    pub location: Option<Location>,
+
+    /// The backtrace, empty if backtraces were disabled.
+    pub frames: Vec<Frame>,
+}
+
+impl PanicInfo {
+    /// Returns the frames belonging to the submitted code.
+    pub fn user_frames(&self) -> impl Iterator<Item = &Frame> {
+        self.frames.iter().filter(|frame| frame.is_user_code())
+    }
+}
+
+impl fmt::Display for PanicInfo {
+    /// Renders a compact report which only lists the frames of the submitted code.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "thread '{}' panicked", self.thread)?;
+        if let Some(location) = &self.location {
+            write!(f, " at {location}")?;
+        }
+        write!(f, ":\n{}", self.message)?;
+        for frame in self.user_frames() {
+            write!(f, "\n  in {}", frame.function)?;
+            if let Some(location) = &frame.location {
+                write!(f, " ({location})")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses every panic printed in the given output.
+///
+/// Supports both the current format (`thread 'main' panicked at src/main.rs:2:5:`
+/// followed by the message) and the one used before Rust 1.73
+/// (`thread 'main' panicked at 'message', src/main.rs:2:5`).
+///
+/// # Example
+///
+/// ```
+/// let stderr = "thread 'main' panicked at src/main.rs:2:5:\nexplicit panic\n";
+/// let panics = playground_api::panics::parse(stderr);
+///
+/// assert_eq!(panics[0].thread, "main");
+/// assert_eq!(panics[0].message, "explicit panic");
+/// assert_eq!(panics[0].location.as_ref().unwrap().line, 2);
+/// ```
+pub fn parse(output: &str) -> Vec<PanicInfo> {
+    let lines: Vec<&str> = output.lines().collect();
+    let mut panics = Vec::new();
+    let mut idx = 0;
+
+    while idx < lines.len() {
+        let Some((thread, rest)) = parse_panic_line(lines[idx]) else {
+            idx += 1;
+            continue;
+        };
+        idx += 1;
+
+        let (message, location) = match rest.strip_prefix('\'') {
+            // Pre 1.73: `'message', src/main.rs:2:5`
+            Some(rest) => match rest.rsplit_once("', ") {
+                Some((message, location)) => (message.to_owned(), Location::parse(location)),
+                None => (rest.trim_end_matches('\'').to_owned(), None),
+            },
+            None => {
+                let location = Location::parse(rest.trim_end_matches(':'));
+                let mut message = Vec::new();
+                while idx < lines.len() && !ends_message(lines[idx]) {
+                    message.push(lines[idx]);
+                    idx += 1;
+                }
+                (message.join("\n"), location)
+            }
+        };
+
+        let mut frames = Vec::new();
+        if lines
+            .get(idx)
+            .is_some_and(|line| line.trim() == "stack backtrace:")
+        {
+            idx += 1;
+            while let Some(function) = lines.get(idx).and_then(|line| parse_frame_line(line)) {
+                idx += 1;
+                let location = lines
+                    .get(idx)
+                    .and_then(|line| line.trim_start().strip_prefix("at "))
+                    .and_then(Location::parse);
+                if location.is_some() {
+                    idx += 1;
+                }
+                // Short backtraces hide frames with `[... omitted 1 frame ...]`.
+                while lines
+                    .get(idx)
+                    .is_some_and(|line| line.trim_start().starts_with("[... omitted "))
+                {
+                    idx += 1;
+                }
+                frames.push(Frame {
+                    function: function.to_owned(),
+                    location,
+                });
+            }
+        }
+
+        panics.push(PanicInfo {
+            thread,
+            message,
+            location,
+            frames,
+        });
+    }
+
+    panics
+}
+
+/// Parses `thread 'main' (7772) panicked at ...` into the thread name and the rest.
+fn parse_panic_line(line: &str) -> Option<(String, &str)> {
+    let rest = line.strip_prefix("thread '")?;
+    let (thread, rest) = rest.split_once('\'')?;
+    let (_, rest) = rest.split_once("panicked at ")?;
+    Some((thread.to_owned(), rest))
+}
+
+/// Returns `true` for lines which follow the panic message.
+fn ends_message(line: &str) -> bool {
+    line.starts_with("note: ")
+        || line.trim() == "stack backtrace:"
+        || line.starts_with("thread '")
+        || line.starts_with("error: process didn't exit successfully")
+}
+
+/// Parses a frame header like `   6: playground::main` or, as printed by rustc,
+/// `   6:     0x7f3b2c1d9e4b - rustc_hir_typeck::typeck`.
+fn parse_frame_line(line: &str) -> Option<&str> {
+    let (number, function) = line.trim_start().split_once(": ")?;
+    number.parse::<usize>().ok()?;
+    let function = function.trim();
+    match function.split_once(" - ") {
+        Some((address, function)) if address.starts_with("0x") => Some(function),
+        _ => Some(function),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn without_backtrace() {
+        let panics = parse(include_str!("../test-outputs/execute.stderr"));
+        assert_eq!(panics.len(), 1);
+
+        let panic = &panics[0];
+        assert_eq!(panic.thread, "main");
+        assert_eq!(
+            panic.message,
+            "index out of bounds: the len is 0 but the index is 3"
+        );
+        let location = panic.location.as_ref().unwrap();
+        assert_eq!((location.line, location.column), (6, Some(6)));
+        assert!(panic.frames.is_empty());
+    }
+
+    #[test]
+    fn with_backtrace() {
+        let panics = parse(include_str!("../test-outputs/backtrace.stderr"));
+        let panic = &panics[0];
+        assert_eq!(panic.thread, "main");
+        assert_eq!(panic.frames.len(), 8);
+        assert_eq!(
+            panic.frames[2].function,
+            "core::panicking::panic_bounds_check"
+        );
+
+        let user: Vec<_> = panic.user_frames().collect();
+        assert_eq!(user.len(), 1);
+        assert_eq!(user[0].function, "playground::main");
+        assert_eq!(user[0].location.as_ref().unwrap().line, 6);
+
+        assert_eq!(
+            panic.to_string(),
+            "thread 'main' panicked at src/main.rs:6:6:\n\
+             index out of bounds: the len is 0 but the index is 3\n  \
+             in playground::main (./src/main.rs:6:6)"
+        );
+    }
+
+    #[test]
+    fn old_format_and_multiline_messages() {
+        let old = "thread 'main' panicked at 'boom', src/main.rs:2:5\n";
+        let panic = &parse(old)[0];
+        assert_eq!(panic.message, "boom");
+        assert_eq!(panic.location.as_ref().unwrap().line, 2);
+
+        let assert = "thread 'worker' panicked at src/main.rs:3:5:\n\
+                      assertion `left == right` failed\n  left: 1\n right: 2\n\
+                      note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace\n";
+        let panic = &parse(assert)[0];
+        assert_eq!(panic.thread, "worker");
+        assert_eq!(
+            panic.message,
+            "assertion `left == right` failed\n  left: 1\n right: 2"
+        );
+    }
+}
@@ -0,0 +1,303 @@
+//! Checking whether code is formatted with rustfmt.
+//!
+//! [`Client::check_format`](crate::Client::check_format) formats the code and
+//! compares it with the input. The resulting [`FormatCheck`] tells whether the
+//! code was already formatted and shows what rustfmt changed as a unified diff
+//! and as [`Hunk`]s with line ranges.
+//!
+//! When rustfmt fails, e.g. on a syntax error, the check holds a [`FormatFailure`]
+//! instead, with the errors parsed by [`parse_errors`] into [`Diagnostic`]s pointing
+//! at the offending line.
+
+use crate::{
+    diagnostics::{self, Diagnostic},
+    endpoints::FormatResponse,
+    outcome::Outcome,
+};
+use serde::{Deserialize, Serialize};
+use similar::TextDiff;
+use std::fmt;
+
+/// The number of unchanged lines shown around each change.
+const CONTEXT_LINES: usize = 3;
+
+/// A changed region of the code, like a `@@ -1,3 +1,4 @@` hunk of a unified diff.
+///
+/// Line numbers are 1-based and include the unchanged context lines.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Hunk {
+    /// The first line in the original code.
+    pub old_start: usize,
+
+    /// The number of lines in the original code.
+    pub old_lines: usize,
+
+    /// The first line in the formatted code.
+    pub new_start: usize,
+
+    /// The number of lines in the formatted code.
+    pub new_lines: usize,
+}
+
+impl fmt::Display for Hunk {
+    /// Renders the hunk header, e.g. `@@ -1,3 +1,4 @@`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "@@ -{},{} +{},{} @@",
+            self.old_start, self.old_lines, self.new_start, self.new_lines
+        )
+    }
+}
+
+/// Why rustfmt couldn't format the code.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FormatFailure {
+    /// How the formatting went, e.g. [`Outcome::CompileError`] for a syntax error.
+    pub outcome: Outcome,
+
+    /// The errors rustfmt reported, see [`parse_errors`].
+    pub diagnostics: Vec<Diagnostic>,
+
+    /// The standard error of rustfmt.
+    pub stderr: String,
+}
+
+/// The result of checking whether code is formatted.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FormatCheck {
+    /// Whether rustfmt left the code unchanged. `false` if rustfmt failed.
+    pub is_formatted: bool,
+
+    /// The formatted code, or the original code if rustfmt failed.
+    pub code: String,
+
+    /// A unified diff from the original to the formatted code, empty if the code
+    /// was already formatted.
+    pub diff: String,
+
+    /// The changed regions of the code, in order.
+    pub hunks: Vec<Hunk>,
+
+    /// Why rustfmt failed, `None` if it formatted the code.
+    pub failure: Option<FormatFailure>,
+}
+
+impl FormatCheck {
+    /// Compares code with its formatted version.
+    ///
+    /// # Arguments
+    ///
+    /// * `original` - The code as written.
+    /// * `formatted` - The code as formatted by rustfmt.
+    pub fn new(original: &str, formatted: &str) -> Self {
+        if original == formatted {
+            return Self {
+                is_formatted: true,
+                code: formatted.to_owned(),
+                diff: String::new(),
+                hunks: Vec::new(),
+                failure: None,
+            };
+        }
+
+        let diff = TextDiff::from_lines(original, formatted);
+        let mut unified = diff.unified_diff();
+        unified
+            .context_radius(CONTEXT_LINES)
+            .header("original", "formatted");
+
+        let hunks = unified
+            .iter_hunks()
+            .filter_map(|hunk| {
+                let (first, last) = (hunk.ops().first()?, hunk.ops().last()?);
+                let old = first.old_range().start..last.old_range().end;
+                let new = first.new_range().start..last.new_range().end;
+                Some(Hunk {
+                    old_start: old.start + 1,
+                    old_lines: old.len(),
+                    new_start: new.start + 1,
+                    new_lines: new.len(),
+                })
+            })
+            .collect();
+
+        Self {
+            is_formatted: false,
+            code: formatted.to_owned(),
+            diff: unified.to_string(),
+            hunks,
+            failure: None,
+        }
+    }
+
+    /// Checks the response of formatting `original`.
+    ///
+    /// If rustfmt failed, the check keeps the original code and holds the
+    /// [`FormatFailure`] with the parsed errors.
+    pub fn from_response(original: &str, response: FormatResponse) -> Self {
+        let outcome = response.outcome();
+        if outcome.is_success() {
+            return Self::new(original, &response.code);
+        }
+
+        Self {
+            is_formatted: false,
+            code: original.to_owned(),
+            diff: String::new(),
+            hunks: Vec::new(),
+            failure: Some(FormatFailure {
+                outcome,
+                diagnostics: response.diagnostics(),
+                stderr: response.stderr,
+            }),
+        }
+    }
+}
+
+/// Parses the error output of rustfmt into diagnostics.
+///
+/// Handles both parse errors, which look like rustc's, and rustfmt's own
+/// `error[internal]` reports, e.g. for lines exceeding `max_width`. Paths are made
+/// relative to the crate like `src/main.rs`, and the trailing "rustfmt has failed
+/// to format" summary is dropped.
+///
+/// # Example
+///
+/// ```
+/// let stderr = "error: expected expression, found `;`\n --> /playground/src/main.rs:2:13\n";
+/// let errors = playground_api::rustfmt::parse_errors(stderr);
+///
+/// let span = errors[0].span.as_ref().unwrap();
+/// assert_eq!((span.file.as_str(), span.line, span.column), ("src/main.rs", 2, 13));
+/// ```
+pub fn parse_errors(stderr: &str) -> Vec<Diagnostic> {
+    let normalized: Vec<String> = stderr.lines().map(normalize_location).collect();
+    diagnostics::parse(&normalized.join("\n"))
+        .into_iter()
+        .filter(|diagnostic| {
+            !diagnostic
+                .message
+                .starts_with("rustfmt has failed to format")
+        })
+        .collect()
+}
+
+/// Rewrites a location line like ` --> /playground/src/main.rs:2:2:101` into the
+/// `src/main.rs:2:101` form rustc uses. Other lines are returned unchanged.
+fn normalize_location(line: &str) -> String {
+    let Some((indent, location)) = line.split_once("--> ") else {
+        return line.to_owned();
+    };
+    if !indent.trim().is_empty() {
+        return line.to_owned();
+    }
+
+    let mut parts: Vec<&str> = location.split(':').collect();
+    let numbers = parts
+        .iter()
+        .rev()
+        .take_while(|part| part.parse::<usize>().is_ok())
+        .count();
+    // rustfmt's own reports add a column before the one it points at.
+    if numbers == 3 {
+        parts.remove(parts.len() - 2);
+    }
+    let Some(file_end) = parts.len().checked_sub(numbers.min(2)) else {
+        return line.to_owned();
+    };
+
+    let file = parts[..file_end].join(":");
+    let file = match file.find("/src/") {
+        Some(idx) => &file[idx + 1..],
+        None => &file,
+    };
+    let position = parts[file_end..].join(":");
+    format!("{indent}--> {file}:{position}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_formatted_code() {
+        let code = "fn main() {\n    println!(\"hi\");\n}\n";
+        let check = FormatCheck::new(code, code);
+        assert!(check.is_formatted);
+        assert!(check.diff.is_empty());
+        assert!(check.hunks.is_empty());
+    }
+
+    #[test]
+    fn reports_hunks() {
+        let original = "fn main(){\n    let x=1;\n    let y = 2;\n    let z = 3;\n    let a = 4;\n    let b = 5;\n    let c = 6;\n    let d = 7;\n    let e = 8;\n    let f = 9;\n    println!(\"{}\",x+y+z+a+b+c+d);\n}\n";
+        let formatted = "fn main() {\n    let x = 1;\n    let y = 2;\n    let z = 3;\n    let a = 4;\n    let b = 5;\n    let c = 6;\n    let d = 7;\n    let e = 8;\n    let f = 9;\n    println!(\"{}\", x + y + z + a + b + c + d);\n}\n";
+        let check = FormatCheck::new(original, formatted);
+
+        assert!(!check.is_formatted);
+        assert_eq!(check.code, formatted);
+        let headers: Vec<_> = check.hunks.iter().map(ToString::to_string).collect();
+        assert_eq!(headers, ["@@ -1,5 +1,5 @@", "@@ -8,5 +8,5 @@"]);
+        assert!(check
+            .diff
+            .starts_with("--- original\n+++ formatted\n@@ -1,5 +1,5 @@\n-fn main(){\n-    let x=1;\n+fn main() {\n+    let x = 1;\n"));
+        assert!(check.diff.contains("@@ -8,5 +8,5 @@\n"));
+    }
+
+    #[test]
+    fn keeps_failures() {
+        let response: FormatResponse = serde_json::from_value(serde_json::json!({
+            "success": false,
+            "exitDetail": "Exited with status 1",
+            "code": "",
+            "stdout": "",
+            "stderr": include_str!("../test-outputs/rustfmt-parse.stderr"),
+        }))
+        .unwrap();
+        let original = "fn main() {\n    let x = ;\n}\n";
+        let check = FormatCheck::from_response(original, response);
+
+        assert!(!check.is_formatted);
+        assert_eq!(check.code, original);
+        assert!(check.diff.is_empty());
+        let failure = check.failure.unwrap();
+        assert_eq!(failure.outcome, Outcome::CompileError);
+        assert_eq!(failure.diagnostics.len(), 1);
+        assert_eq!(failure.diagnostics[0].span.as_ref().unwrap().line, 2);
+        assert!(failure.stderr.contains("expected expression"));
+    }
+
+    #[test]
+    fn parses_parse_errors() {
+        let errors = parse_errors(include_str!("../test-outputs/rustfmt-parse.stderr"));
+        assert_eq!(errors.len(), 1);
+        let error = &errors[0];
+        assert!(error.is_error());
+        assert_eq!(error.message, "expected expression, found `;`");
+        let span = error.span.as_ref().unwrap();
+        assert_eq!(
+            (span.file.as_str(), span.line, span.column),
+            ("src/main.rs", 2, 13)
+        );
+        assert_eq!(
+            error.labels[0].message.as_deref(),
+            Some("expected expression")
+        );
+    }
+
+    #[test]
+    fn parses_internal_errors() {
+        let errors = parse_errors(include_str!("../test-outputs/rustfmt-internal.stderr"));
+        assert_eq!(errors.len(), 1);
+        let error = &errors[0];
+        assert_eq!(error.code.as_deref(), Some("internal"));
+        assert!(error
+            .message
+            .starts_with("line formatted, but exceeded maximum width"));
+        let span = error.span.as_ref().unwrap();
+        assert_eq!((span.line, span.column), (2, 101));
+        assert!(span.is_user_code());
+        assert_eq!(error.notes.len(), 1);
+    }
+}
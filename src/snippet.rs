@@ -0,0 +1,341 @@
+//! A tool-agnostic piece of code together with its settings.
+//!
+//! A [`Snippet`] holds everything needed to send the same code to every
+//! playground tool. It converts into each request type and can be created
+//! from any of them again.
+
+use crate::endpoints::*;
+use serde::{Deserialize, Serialize};
+
+/// A piece of Rust code plus the settings shared by the playground tools.
+///
+/// Settings a tool doesn't support are dropped when converting into its request,
+/// e.g. `mode` for a [`ClippyRequest`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Snippet {
+    /// The Rust source code.
+    pub code: String,
+
+    /// The Rust release channel to use (stable, beta, nightly).
+    pub channel: Channel,
+
+    /// The Rust edition to use (2015, 2018, 2021, 2024).
+    pub edition: Edition,
+
+    /// The compilation mode: debug or release.
+    pub mode: Mode,
+
+    /// The crate type: binary or library.
+    #[serde(rename = "crateType")]
+    pub crate_type: CrateType,
+
+    /// Whether to run the tests instead of `main`.
+    pub tests: bool,
+
+    /// Whether to enable backtrace output on runtime errors.
+    pub backtrace: bool,
+
+    /// The target used when converting into a [`CompileRequest`] with
+    /// [`CompileRequest::from`]. `None` means [`CompileTarget::Assembly`].
+    #[serde(default)]
+    pub target: Option<CompileTarget>,
+
+    /// The assembly flavor for [`CompileTarget::Assembly`]. `None` uses the default.
+    #[serde(default, rename = "assemblyFlavor")]
+    pub assembly_flavor: Option<AssemblyFlavor>,
+
+    /// Whether to demangle assembly symbols. `None` uses the default.
+    #[serde(default, rename = "demangleAssembly")]
+    pub demangle_assembly: Option<DemangleAssembly>,
+
+    /// Whether to filter the assembly output. `None` uses the default.
+    #[serde(default, rename = "processAssembly")]
+    pub process_assembly: Option<ProcessAssembly>,
+
+    /// The aliasing model Miri uses.
+    #[serde(default, rename = "aliasingModel")]
+    pub aliasing_model: Option<AliasingModel>,
+}
+
+impl Snippet {
+    /// Creates a new [`Snippet`] with the given code and the default settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The Rust source code.
+    ///
+    /// # Returns
+    ///
+    /// A [`Snippet`] using the same defaults as [`ExecuteRequest::default`].
+    pub fn new(code: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Converts the snippet into a [`CompileRequest`] for the given target.
+    ///
+    /// For [`CompileTarget::Assembly`] the assembly options of the snippet are used,
+    /// falling back to their defaults. They are left empty for every other target.
+    pub fn compile_request(&self, target: CompileTarget) -> CompileRequest {
+        let mut request = CompileRequest::builder()
+            .target(target)
+            .channel(self.channel)
+            .mode(self.mode)
+            .edition(self.edition)
+            .crate_type(self.crate_type)
+            .tests(self.tests)
+            .backtrace(self.backtrace)
+            .code(self.code.clone())
+            .build();
+        if target == CompileTarget::Assembly {
+            request.assembly_flavor = self.assembly_flavor.or(request.assembly_flavor);
+            request.demangle_assembly = self.demangle_assembly.or(request.demangle_assembly);
+            request.process_assembly = self.process_assembly.or(request.process_assembly);
+        }
+        request
+    }
+
+    /// Converts the snippet into a [`CompileRequest`] for assembly in the given flavor.
+    pub fn assembly_request(&self, flavor: AssemblyFlavor) -> CompileRequest {
+        let mut request = self.compile_request(CompileTarget::Assembly);
+        request.assembly_flavor = Some(flavor);
+        request
+    }
+}
+
+impl Default for Snippet {
+    /// Provides a default [`Snippet`] matching [`ExecuteRequest::default`].
+    fn default() -> Self {
+        ExecuteRequest::default().into()
+    }
+}
+
+impl From<&Snippet> for CompileRequest {
+    /// Uses the target of the snippet, see [`Snippet::compile_request`].
+    fn from(snippet: &Snippet) -> Self {
+        snippet.compile_request(snippet.target.unwrap_or(CompileTarget::Assembly))
+    }
+}
+
+impl From<&Snippet> for ExecuteRequest {
+    fn from(snippet: &Snippet) -> Self {
+        Self {
+            channel: snippet.channel,
+            mode: snippet.mode,
+            edition: snippet.edition,
+            crate_type: snippet.crate_type,
+            tests: snippet.tests,
+            backtrace: snippet.backtrace,
+            code: snippet.code.clone(),
+        }
+    }
+}
+
+impl From<&Snippet> for ClippyRequest {
+    fn from(snippet: &Snippet) -> Self {
+        Self {
+            channel: snippet.channel,
+            crate_type: snippet.crate_type,
+            edition: snippet.edition,
+            code: snippet.code.clone(),
+        }
+    }
+}
+
+impl From<&Snippet> for FormatRequest {
+    fn from(snippet: &Snippet) -> Self {
+        Self {
+            channel: snippet.channel,
+            crate_type: snippet.crate_type,
+            edition: snippet.edition,
+            code: snippet.code.clone(),
+        }
+    }
+}
+
+impl From<&Snippet> for MiriRequest {
+    fn from(snippet: &Snippet) -> Self {
+        Self {
+            code: snippet.code.clone(),
+            edition: snippet.edition,
+            tests: snippet.tests,
+            aliasing_model: snippet.aliasing_model,
+        }
+    }
+}
+
+impl From<&Snippet> for MacroExpansionRequest {
+    fn from(snippet: &Snippet) -> Self {
+        Self {
+            code: snippet.code.clone(),
+            edition: snippet.edition,
+        }
+    }
+}
+
+impl From<ExecuteRequest> for Snippet {
+    fn from(request: ExecuteRequest) -> Self {
+        Self {
+            code: request.code,
+            channel: request.channel,
+            edition: request.edition,
+            mode: request.mode,
+            crate_type: request.crate_type,
+            tests: request.tests,
+            backtrace: request.backtrace,
+            target: None,
+            assembly_flavor: None,
+            demangle_assembly: None,
+            process_assembly: None,
+            aliasing_model: MiriRequest::default().aliasing_model,
+        }
+    }
+}
+
+impl From<CompileRequest> for Snippet {
+    /// Keeps every setting, including the target and assembly options.
+    fn from(request: CompileRequest) -> Self {
+        Self {
+            code: request.code,
+            channel: request.channel,
+            edition: request.edition,
+            mode: request.mode,
+            crate_type: request.crate_type,
+            tests: request.tests,
+            backtrace: request.backtrace,
+            target: Some(request.target),
+            assembly_flavor: request.assembly_flavor,
+            demangle_assembly: request.demangle_assembly,
+            process_assembly: request.process_assembly,
+            ..Self::default()
+        }
+    }
+}
+
+impl From<ClippyRequest> for Snippet {
+    /// Settings Clippy doesn't know about keep their defaults.
+    fn from(request: ClippyRequest) -> Self {
+        Self {
+            code: request.code,
+            channel: request.channel,
+            edition: request.edition,
+            crate_type: request.crate_type,
+            ..Self::default()
+        }
+    }
+}
+
+impl From<FormatRequest> for Snippet {
+    /// Settings rustfmt doesn't know about keep their defaults.
+    fn from(request: FormatRequest) -> Self {
+        Self {
+            code: request.code,
+            channel: request.channel,
+            edition: request.edition,
+            crate_type: request.crate_type,
+            ..Self::default()
+        }
+    }
+}
+
+impl From<MiriRequest> for Snippet {
+    /// Miri always runs on nightly, so the channel is set accordingly.
+    fn from(request: MiriRequest) -> Self {
+        Self {
+            code: request.code,
+            channel: Channel::Nightly,
+            edition: request.edition,
+            tests: request.tests,
+            aliasing_model: request.aliasing_model,
+            ..Self::default()
+        }
+    }
+}
+
+impl From<MacroExpansionRequest> for Snippet {
+    /// Macro expansion always runs on nightly, so the channel is set accordingly.
+    fn from(request: MacroExpansionRequest) -> Self {
+        Self {
+            code: request.code,
+            channel: Channel::Nightly,
+            edition: request.edition,
+            ..Self::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips() {
+        let snippet = Snippet {
+            code: "fn main() {}".to_owned(),
+            channel: Channel::Beta,
+            edition: Edition::Edition2018,
+            mode: Mode::Release,
+            crate_type: CrateType::Binary,
+            tests: true,
+            backtrace: true,
+            ..Snippet::default()
+        };
+
+        assert_eq!(Snippet::from(ExecuteRequest::from(&snippet)), snippet);
+        assert_eq!(
+            Snippet::from(snippet.compile_request(CompileTarget::Mir)),
+            Snippet {
+                target: Some(CompileTarget::Mir),
+                ..snippet.clone()
+            }
+        );
+
+        let clippy = ClippyRequest::from(&snippet);
+        assert_eq!(ClippyRequest::from(&Snippet::from(clippy.clone())), clippy);
+    }
+
+    #[test]
+    fn round_trips_tool_settings() {
+        let assembly = CompileRequest::builder()
+            .target(CompileTarget::Assembly)
+            .assembly_flavor(AssemblyFlavor::Intel)
+            .code("fn main() {}")
+            .build();
+        assert_eq!(
+            CompileRequest::from(&Snippet::from(assembly.clone())),
+            assembly
+        );
+
+        let mir = Snippet::default().compile_request(CompileTarget::Mir);
+        assert_eq!(CompileRequest::from(&Snippet::from(mir.clone())), mir);
+
+        for aliasing_model in [None, Some(AliasingModel::Tree)] {
+            let miri = MiriRequest {
+                aliasing_model,
+                ..MiriRequest::default()
+            };
+            assert_eq!(MiriRequest::from(&Snippet::from(miri.clone())), miri);
+        }
+        assert_eq!(
+            MiriRequest::from(&Snippet::default()),
+            MiriRequest {
+                code: Snippet::default().code,
+                ..MiriRequest::default()
+            }
+        );
+    }
+
+    #[test]
+    fn assembly_options_follow_target() {
+        let snippet = Snippet::default();
+
+        let req = snippet.assembly_request(AssemblyFlavor::Intel);
+        assert_eq!(req.assembly_flavor, Some(AssemblyFlavor::Intel));
+        assert!(req.validate().is_ok());
+
+        let req = snippet.compile_request(CompileTarget::LlvmIr);
+        assert_eq!(req.assembly_flavor, None);
+    }
+}
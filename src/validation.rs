@@ -0,0 +1,268 @@
+//! Client-side validation of requests before they are sent to the playground.
+//!
+//! Every request type has a `validate()` method which checks for combinations
+//! the playground would reject (or silently misinterpret). A [`Client`](crate::Client)
+//! can be configured to run these checks automatically with
+//! [`Client::validate_requests`](crate::Client::validate_requests).
+//!
+//! Whether an edition is supported depends on the toolchain, which a request
+//! alone doesn't know. With validation enabled, the client fetches the versions
+//! of the playground's toolchains once and checks the edition of every request
+//! against them with [`Edition::check_support`]. The `validate()` methods skip
+//! this check.
+
+use crate::{
+    endpoints::{Channel, CompileTarget, Edition, Version, VersionsResponse},
+    error::Error,
+};
+use thiserror::Error;
+
+/// A single problem found while validating a request.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ValidationIssue {
+    /// The request does not contain any code.
+    #[error("the code is empty")]
+    EmptyCode,
+
+    /// Assembly options were set although the target is not [`CompileTarget::Assembly`].
+    #[error("assembly options are only used with the assembly target, not {0:?}")]
+    AssemblyOptionsWithoutAssembly(CompileTarget),
+
+    /// The target is [`CompileTarget::Assembly`] but not every assembly option was set.
+    #[error("the assembly target requires the flavor, demangle and process options")]
+    MissingAssemblyOptions,
+
+    /// The chosen target is only available on the nightly channel.
+    #[error("the {target:?} target requires the nightly channel, not {channel:?}")]
+    RequiresNightly {
+        /// The target which needs nightly.
+        target: CompileTarget,
+        /// The channel that was requested instead.
+        channel: Channel,
+    },
+
+    /// The edition is not supported by the toolchain it was checked against.
+    #[error(
+        "edition {edition:?} requires rustc {required} or newer, but the toolchain is {found}"
+    )]
+    UnsupportedEdition {
+        /// The requested edition.
+        edition: Edition,
+        /// The minimum rustc version for the edition.
+        required: &'static str,
+        /// The version of the toolchain.
+        found: String,
+    },
+
+    /// The request must compile to assembly, e.g. for comparing assembly output.
+    #[error("the request must use the assembly target, not {0:?}")]
+    AssemblyTargetRequired(CompileTarget),
+
+    /// The code is run as a binary but has no `main` function.
+    #[error("the code has no `main` function and tests are disabled")]
+    MissingMain,
+}
+
+/// Implemented by every request the clients can send, so validation can be
+/// run generically before a request leaves the client.
+pub(crate) trait Validate {
+    fn validate(&self) -> Result<(), Error>;
+
+    /// The channel and edition the request is compiled with, if it compiles code.
+    fn toolchain(&self) -> Option<(Channel, Edition)> {
+        None
+    }
+}
+
+/// Checks the edition of a request against the rustc version of its channel.
+pub(crate) fn check_edition(
+    request: &impl Validate,
+    versions: &VersionsResponse,
+) -> Result<(), Error> {
+    let Some((channel, edition)) = request.toolchain() else {
+        return Ok(());
+    };
+    edition
+        .check_support(&versions.channel(channel).rustc)
+        .map_err(|issue| Error::InvalidRequest(vec![issue]))
+}
+
+/// Turns a list of issues into the result returned by the `validate()` methods.
+pub(crate) fn into_result(issues: Vec<ValidationIssue>) -> Result<(), Error> {
+    if issues.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::InvalidRequest(issues))
+    }
+}
+
+/// Pushes [`ValidationIssue::EmptyCode`] if the code only contains whitespace.
+pub(crate) fn check_code(code: &str, issues: &mut Vec<ValidationIssue>) {
+    if code.trim().is_empty() {
+        issues.push(ValidationIssue::EmptyCode);
+    }
+}
+
+/// Returns `true` if the code declares a `fn main`.
+///
+/// This is a textual check, so a `main` inside a comment or string also counts.
+pub(crate) fn has_main_fn(code: &str) -> bool {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+
+    code.match_indices("fn").any(|(idx, _)| {
+        let before = code[..idx].chars().next_back();
+        let after = &code[idx + 2..];
+        !before.is_some_and(is_ident)
+            && after.starts_with(char::is_whitespace)
+            && after
+                .trim_start()
+                .strip_prefix("main")
+                .is_some_and(|rest| !rest.starts_with(is_ident))
+    })
+}
+
+impl Edition {
+    /// The first stable rustc version which supports this edition.
+    pub fn minimum_rustc(&self) -> &'static str {
+        match self {
+            Edition::Edition2015 => "1.0.0",
+            Edition::Edition2018 => "1.31.0",
+            Edition::Edition2021 => "1.56.0",
+            Edition::Edition2024 => "1.85.0",
+        }
+    }
+
+    /// Checks whether a toolchain with the given version supports this edition.
+    ///
+    /// Returns [`ValidationIssue::UnsupportedEdition`] if it does not. Versions which
+    /// can't be parsed are assumed to be recent enough.
+    pub fn check_support(&self, version: &Version) -> Result<(), ValidationIssue> {
+        match (
+            parse_version(&version.version),
+            parse_version(self.minimum_rustc()),
+        ) {
+            (Some(found), Some(required)) if found < required => {
+                Err(ValidationIssue::UnsupportedEdition {
+                    edition: *self,
+                    required: self.minimum_rustc(),
+                    found: version.version.clone(),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Parses the leading `major.minor.patch` of a rustc version string like `1.86.0-nightly`.
+fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+    let version = version.split(['-', ' ']).next()?;
+    let mut parts = version.split('.').map(|part| part.parse().ok());
+    Some((
+        parts.next()??,
+        parts.next()??,
+        parts.next().flatten().unwrap_or(0),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::*;
+
+    #[test]
+    fn main_detection() {
+        assert!(has_main_fn("fn main() {}"));
+        assert!(has_main_fn("pub fn   main ( ) {}"));
+        assert!(!has_main_fn("fn mainly() {}"));
+        assert!(!has_main_fn("fn foo() { main() }"));
+        assert!(!has_main_fn("pub fn add(a: u8) {}"));
+    }
+
+    #[test]
+    fn collects_every_issue() {
+        let req = CompileRequest {
+            target: CompileTarget::Hir,
+            code: "   ".to_owned(),
+            ..CompileRequest::default()
+        };
+
+        let Err(Error::InvalidRequest(issues)) = req.validate() else {
+            panic!("request should be invalid");
+        };
+        assert_eq!(
+            issues,
+            vec![
+                ValidationIssue::EmptyCode,
+                ValidationIssue::AssemblyOptionsWithoutAssembly(CompileTarget::Hir),
+                ValidationIssue::RequiresNightly {
+                    target: CompileTarget::Hir,
+                    channel: Channel::Stable
+                },
+                ValidationIssue::MissingMain,
+            ]
+        );
+    }
+
+    #[test]
+    fn miri_without_main() {
+        let req = MiriRequest::builder().code("pub fn foo() {}").build();
+        assert!(req.validate().is_err());
+
+        let req = MiriRequest::builder()
+            .code("#[test] fn foo() {}")
+            .tests(true)
+            .build();
+        assert!(req.validate().is_ok());
+    }
+
+    #[test]
+    fn edition_support() {
+        let version = |version: &str| Version {
+            version: version.to_owned(),
+            hash: String::new(),
+            date: String::new(),
+        };
+        assert!(Edition::Edition2024
+            .check_support(&version("1.84.1"))
+            .is_err());
+        assert!(Edition::Edition2021
+            .check_support(&version("1.84.1"))
+            .is_ok());
+
+        let channel = |rustc: &str| ChannelVersion {
+            rustc: version(rustc),
+            rustfmt: version(""),
+            clippy: version(""),
+            miri: None,
+        };
+        let versions = VersionsResponse {
+            stable: channel("1.84.1"),
+            beta: channel("1.85.0-beta.1"),
+            nightly: channel("1.86.0-nightly"),
+        };
+        let stable = ExecuteRequest::default();
+        assert!(matches!(
+            check_edition(&stable, &versions),
+            Err(Error::InvalidRequest(issues))
+                if matches!(issues[..], [ValidationIssue::UnsupportedEdition { .. }])
+        ));
+        let beta = ExecuteRequest {
+            channel: Channel::Beta,
+            ..ExecuteRequest::default()
+        };
+        assert!(check_edition(&beta, &versions).is_ok());
+        // Miri always runs on nightly.
+        assert!(check_edition(&MiriRequest::default(), &versions).is_ok());
+        assert!(check_edition(&GistCreateRequest::new(String::new()), &versions).is_ok());
+    }
+
+    #[test]
+    fn defaults_are_valid() {
+        assert!(ExecuteRequest::default().validate().is_ok());
+        assert!(CompileRequest::default().validate().is_ok());
+        assert!(ClippyRequest::default().validate().is_ok());
+        assert!(FormatRequest::default().validate().is_ok());
+        assert!(MiriRequest::default().validate().is_ok());
+        assert!(MacroExpansionRequest::default().validate().is_ok());
+    }
+}
@@ -0,0 +1,469 @@
+//! Running the output of [`CompileTarget::Wasm`](crate::endpoints::CompileTarget::Wasm).
+//!
+//! The playground returns WebAssembly in the text format. [`WasmModule`] assembles
+//! it and runs its exported functions in the `wasmi` interpreter, so no browser or
+//! native runtime is needed. Execution is limited by fuel, so an endless loop
+//! returns [`WasmError::OutOfFuel`] instead of hanging.
+//!
+//! Only available with the `wasm` feature.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+use wasmi::{
+    core::{ValType, F32, F64},
+    Config, Engine, ExternType, Instance, Linker, Module, Store, Val,
+};
+
+/// The fuel given to each call by default, enough for a few million instructions.
+pub const DEFAULT_FUEL: u64 = 10_000_000;
+
+/// The type of a WebAssembly value.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ValueType {
+    /// A 32-bit integer.
+    I32,
+
+    /// A 64-bit integer.
+    I64,
+
+    /// A 32-bit float.
+    F32,
+
+    /// A 64-bit float.
+    F64,
+
+    /// A function reference.
+    FuncRef,
+
+    /// An external reference.
+    ExternRef,
+}
+
+impl From<ValType> for ValueType {
+    fn from(ty: ValType) -> Self {
+        match ty {
+            ValType::I32 => Self::I32,
+            ValType::I64 => Self::I64,
+            ValType::F32 => Self::F32,
+            ValType::F64 => Self::F64,
+            ValType::FuncRef => Self::FuncRef,
+            ValType::ExternRef => Self::ExternRef,
+        }
+    }
+}
+
+impl fmt::Display for ValueType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::I32 => "i32",
+            Self::I64 => "i64",
+            Self::F32 => "f32",
+            Self::F64 => "f64",
+            Self::FuncRef => "funcref",
+            Self::ExternRef => "externref",
+        })
+    }
+}
+
+/// A WebAssembly value passed to or returned from a function.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum Value {
+    /// A 32-bit integer.
+    I32(i32),
+
+    /// A 64-bit integer.
+    I64(i64),
+
+    /// A 32-bit float.
+    F32(f32),
+
+    /// A 64-bit float.
+    F64(f64),
+}
+
+impl Value {
+    /// Returns the type of the value.
+    pub fn ty(&self) -> ValueType {
+        match self {
+            Self::I32(_) => ValueType::I32,
+            Self::I64(_) => ValueType::I64,
+            Self::F32(_) => ValueType::F32,
+            Self::F64(_) => ValueType::F64,
+        }
+    }
+
+    fn from_val(val: &Val) -> Result<Self, WasmError> {
+        match val {
+            Val::I32(value) => Ok(Self::I32(*value)),
+            Val::I64(value) => Ok(Self::I64(*value)),
+            Val::F32(value) => Ok(Self::F32(value.to_float())),
+            Val::F64(value) => Ok(Self::F64(value.to_float())),
+            val => Err(WasmError::UnsupportedType(val.ty().into())),
+        }
+    }
+}
+
+impl From<Value> for Val {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::I32(value) => Val::I32(value),
+            Value::I64(value) => Val::I64(value),
+            Value::F32(value) => Val::F32(F32::from_float(value)),
+            Value::F64(value) => Val::F64(F64::from_float(value)),
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::I32(value) => write!(f, "{value}"),
+            Self::I64(value) => write!(f, "{value}"),
+            Self::F32(value) => write!(f, "{value}"),
+            Self::F64(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// What kind of item an export is.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ExportKind {
+    /// A function with its parameter and result types.
+    Function {
+        /// The parameter types.
+        params: Vec<ValueType>,
+
+        /// The result types.
+        results: Vec<ValueType>,
+    },
+
+    /// A global variable.
+    Global,
+
+    /// A linear memory.
+    Memory,
+
+    /// A table.
+    Table,
+}
+
+/// An item exported by a [`WasmModule`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Export {
+    /// The export name, e.g. `add`.
+    pub name: String,
+
+    /// What kind of item it is.
+    pub kind: ExportKind,
+}
+
+impl Export {
+    /// Returns `true` if the export is a function.
+    pub fn is_function(&self) -> bool {
+        matches!(self.kind, ExportKind::Function { .. })
+    }
+}
+
+impl fmt::Display for Export {
+    /// Renders the export like `add: fn(i32, i32) -> i32` or `memory: memory`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ExportKind::Function { params, results } => {
+                write!(f, "{}: fn({})", self.name, join_types(params))?;
+                match results.as_slice() {
+                    [] => Ok(()),
+                    [result] => write!(f, " -> {result}"),
+                    results => write!(f, " -> ({})", join_types(results)),
+                }
+            }
+            ExportKind::Global => write!(f, "{}: global", self.name),
+            ExportKind::Memory => write!(f, "{}: memory", self.name),
+            ExportKind::Table => write!(f, "{}: table", self.name),
+        }
+    }
+}
+
+/// An error while assembling or running a [`WasmModule`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum WasmError {
+    /// The WebAssembly text could not be assembled.
+    #[error("invalid WebAssembly text: {0}")]
+    Assemble(String),
+
+    /// The module is invalid or could not be instantiated, e.g. because it has imports.
+    #[error("could not instantiate the module: {0}")]
+    Instantiate(String),
+
+    /// The module has no export with this name.
+    #[error("the module has no export `{0}`")]
+    UnknownExport(String),
+
+    /// The export is not a function.
+    #[error("the export `{0}` is not a function")]
+    NotAFunction(String),
+
+    /// The arguments don't match the parameters of the function.
+    #[error("`{function}` takes ({}), but got ({})", join_types(.expected), join_types(.found))]
+    ArgumentMismatch {
+        /// The function name.
+        function: String,
+
+        /// The parameter types.
+        expected: Vec<ValueType>,
+
+        /// The types of the given arguments.
+        found: Vec<ValueType>,
+    },
+
+    /// The function returns a reference, which can't be represented as a [`Value`].
+    #[error("results of type {0} are not supported")]
+    UnsupportedType(ValueType),
+
+    /// The call used up its fuel, e.g. because of an endless loop.
+    #[error("the call ran out of fuel")]
+    OutOfFuel,
+
+    /// The call trapped, e.g. on `unreachable` after a panic.
+    #[error("the call trapped: {0}")]
+    Trap(String),
+}
+
+/// Joins value types with commas.
+fn join_types(types: &[ValueType]) -> String {
+    types
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// An instantiated WebAssembly module.
+///
+/// # Example
+///
+/// ```
+/// use playground_api::wasm::{Value, WasmModule};
+///
+/// let wat = r#"(module
+///     (func (export "add") (param i32 i32) (result i32)
+///         local.get 0
+///         local.get 1
+///         i32.add))"#;
+/// let mut module = WasmModule::new(wat).unwrap();
+/// assert_eq!(module.call("add", &[Value::I32(1), Value::I32(2)]).unwrap(), [Value::I32(3)]);
+/// ```
+pub struct WasmModule {
+    store: Store<()>,
+    instance: Instance,
+    fuel: u64,
+}
+
+impl WasmModule {
+    /// Assembles WebAssembly text and instantiates it.
+    ///
+    /// Modules with imports can't be instantiated, since there is nothing to link
+    /// them against. The start function, if any, runs with [`DEFAULT_FUEL`].
+    ///
+    /// # Arguments
+    ///
+    /// * `wat` - The module in the WebAssembly text format, e.g. [`CompileResponse::code`](crate::endpoints::CompileResponse::code).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WasmError::Assemble`] if the text is invalid and [`WasmError::Instantiate`]
+    /// if the module is invalid, has imports or its start function traps.
+    pub fn new(wat: &str) -> Result<Self, WasmError> {
+        let wasm = wat::parse_str(wat).map_err(|err| WasmError::Assemble(err.to_string()))?;
+
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+        let module =
+            Module::new(&engine, &wasm).map_err(|err| WasmError::Instantiate(err.to_string()))?;
+
+        let mut store = Store::new(&engine, ());
+        store
+            .set_fuel(DEFAULT_FUEL)
+            .map_err(|err| WasmError::Instantiate(err.to_string()))?;
+        let instance = Linker::new(&engine)
+            .instantiate(&mut store, &module)
+            .and_then(|pre| pre.start(&mut store))
+            .map_err(|err| WasmError::Instantiate(err.to_string()))?;
+
+        Ok(Self {
+            store,
+            instance,
+            fuel: DEFAULT_FUEL,
+        })
+    }
+
+    /// Sets the fuel given to each call. One unit of fuel is roughly one instruction.
+    pub fn set_fuel(&mut self, fuel: u64) {
+        self.fuel = fuel;
+    }
+
+    /// Returns the exports of the module sorted by name.
+    pub fn exports(&self) -> Vec<Export> {
+        let mut exports: Vec<Export> = self
+            .instance
+            .exports(&self.store)
+            .map(|export| {
+                let kind = match export.ty(&self.store) {
+                    ExternType::Func(ty) => ExportKind::Function {
+                        params: ty.params().iter().map(|ty| (*ty).into()).collect(),
+                        results: ty.results().iter().map(|ty| (*ty).into()).collect(),
+                    },
+                    ExternType::Global(_) => ExportKind::Global,
+                    ExternType::Memory(_) => ExportKind::Memory,
+                    ExternType::Table(_) => ExportKind::Table,
+                };
+                Export {
+                    name: export.name().to_owned(),
+                    kind,
+                }
+            })
+            .collect();
+        exports.sort_by(|a, b| a.name.cmp(&b.name));
+        exports
+    }
+
+    /// Returns the exported functions of the module.
+    pub fn functions(&self) -> Vec<Export> {
+        self.exports()
+            .into_iter()
+            .filter(Export::is_function)
+            .collect()
+    }
+
+    /// Calls an exported function.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The export name of the function.
+    /// * `args` - The arguments, which must match the parameter types exactly.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Vec<Value>, WasmError>` - On success, returns the results of the function.
+    ///   On failure, returns a [`WasmError`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there is no such function, if the arguments don't match, if
+    /// the call traps or if it runs out of fuel.
+    pub fn call(&mut self, name: &str, args: &[Value]) -> Result<Vec<Value>, WasmError> {
+        let func = match self.instance.get_export(&self.store, name) {
+            Some(export) => export
+                .into_func()
+                .ok_or_else(|| WasmError::NotAFunction(name.to_owned()))?,
+            None => return Err(WasmError::UnknownExport(name.to_owned())),
+        };
+
+        let ty = func.ty(&self.store);
+        let expected: Vec<ValueType> = ty.params().iter().map(|ty| (*ty).into()).collect();
+        let found: Vec<ValueType> = args.iter().map(Value::ty).collect();
+        if expected != found {
+            return Err(WasmError::ArgumentMismatch {
+                function: name.to_owned(),
+                expected,
+                found,
+            });
+        }
+
+        let inputs: Vec<Val> = args.iter().map(|arg| Val::from(*arg)).collect();
+        let mut outputs: Vec<Val> = ty.results().iter().map(|ty| Val::default(*ty)).collect();
+        self.store
+            .set_fuel(self.fuel)
+            .map_err(|err| WasmError::Trap(err.to_string()))?;
+        func.call(&mut self.store, &inputs, &mut outputs)
+            .map_err(|err| match err.as_trap_code() {
+                Some(wasmi::core::TrapCode::OutOfFuel) => WasmError::OutOfFuel,
+                _ => WasmError::Trap(err.to_string()),
+            })?;
+
+        outputs.iter().map(Value::from_val).collect()
+    }
+}
+
+impl fmt::Debug for WasmModule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WasmModule")
+            .field("exports", &self.exports())
+            .field("fuel", &self.fuel)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WAT: &str = include_str!("../test-outputs/wasm.wat");
+
+    #[test]
+    fn lists_exports() {
+        let module = WasmModule::new(WAT).unwrap();
+        let exports: Vec<_> = module.exports().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            exports,
+            [
+                "__data_end: global",
+                "__heap_base: global",
+                "add: fn(i32, i32) -> i32",
+                "boom: fn()",
+                "factorial: fn(i32) -> i64",
+                "half: fn(f64) -> f64",
+                "memory: memory",
+                "spin: fn()"
+            ]
+        );
+        assert_eq!(module.functions().len(), 5);
+    }
+
+    #[test]
+    fn calls_functions() {
+        let mut module = WasmModule::new(WAT).unwrap();
+        assert_eq!(
+            module.call("add", &[Value::I32(1), Value::I32(2)]).unwrap(),
+            [Value::I32(3)]
+        );
+        assert_eq!(
+            module.call("factorial", &[Value::I32(20)]).unwrap(),
+            [Value::I64(2_432_902_008_176_640_000)]
+        );
+        assert_eq!(
+            module.call("half", &[Value::F64(3.0)]).unwrap(),
+            [Value::F64(1.5)]
+        );
+        assert_eq!(module.call("spin", &[]), Err(WasmError::OutOfFuel));
+    }
+
+    #[test]
+    fn reports_errors() {
+        let mut module = WasmModule::new(WAT).unwrap();
+        assert!(matches!(module.call("boom", &[]), Err(WasmError::Trap(_))));
+        assert_eq!(
+            module.call("memory", &[]),
+            Err(WasmError::NotAFunction("memory".to_owned()))
+        );
+        assert_eq!(
+            module.call("sub", &[]),
+            Err(WasmError::UnknownExport("sub".to_owned()))
+        );
+
+        let err = module.call("add", &[Value::I64(1)]).unwrap_err();
+        assert_eq!(err.to_string(), "`add` takes (i32, i32), but got (i64)");
+
+        assert!(matches!(
+            WasmModule::new("(module"),
+            Err(WasmError::Assemble(_))
+        ));
+        let imports = r#"(module (import "env" "log" (func (param i32))))"#;
+        assert!(matches!(
+            WasmModule::new(imports),
+            Err(WasmError::Instantiate(_))
+        ));
+    }
+}
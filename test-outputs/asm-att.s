@@ -0,0 +1,92 @@
+playground::checked_sum:
+	pushq	%rbp
+	pushq	%r14
+	pushq	%rbx
+	movq	%rsi, %rbx
+	movq	%rdi, %r14
+	callq	*playground::sum@GOTPCREL(%rip)
+	movl	%eax, %ebp
+	movq	%r14, %rdi
+	movq	%rbx, %rsi
+	xorl	%edx, %edx
+	callq	*playground::get@GOTPCREL(%rip)
+	addl	%ebp, %eax
+	popq	%rbx
+	popq	%r14
+	popq	%rbp
+	retq
+
+playground::first_or_zero:
+	testq	%rsi, %rsi
+	je	.LBB1_2
+	movl	(%rdi), %eax
+	retq
+.LBB1_2:
+	pushq	%rax
+	leaq	.Lanon.d5924fec307dad9f73870d3f9775fddb.1(%rip), %rdi
+	callq	*core::option::unwrap_failed@GOTPCREL(%rip)
+
+playground::get:
+	cmpq	%rsi, %rdx
+	jae	.LBB2_2
+	movl	(%rdi,%rdx,4), %eax
+	retq
+.LBB2_2:
+	pushq	%rax
+	leaq	.Lanon.d5924fec307dad9f73870d3f9775fddb.2(%rip), %rax
+	movq	%rdx, %rdi
+	movq	%rax, %rdx
+	callq	*core::panicking::panic_bounds_check@GOTPCREL(%rip)
+
+playground::sum:
+	testq	%rsi, %rsi
+	je	.LBB3_1
+	cmpq	$8, %rsi
+	jae	.LBB3_5
+	xorl	%ecx, %ecx
+	xorl	%eax, %eax
+	jmp	.LBB3_4
+.LBB3_1:
+	xorl	%eax, %eax
+	retq
+.LBB3_5:
+	movabsq	$2305843009213693944, %rcx
+	andq	%rsi, %rcx
+	leaq	(,%rsi,4), %rax
+	andq	$-32, %rax
+	pxor	%xmm0, %xmm0
+	xorl	%edx, %edx
+	pxor	%xmm1, %xmm1
+.LBB3_6:
+	movdqu	(%rdi,%rdx), %xmm2
+	paddd	%xmm2, %xmm1
+	movdqu	16(%rdi,%rdx), %xmm2
+	paddd	%xmm2, %xmm0
+	addq	$32, %rdx
+	cmpq	%rdx, %rax
+	jne	.LBB3_6
+	paddd	%xmm1, %xmm0
+	pshufd	$238, %xmm0, %xmm1
+	paddd	%xmm0, %xmm1
+	pshufd	$85, %xmm1, %xmm0
+	paddd	%xmm1, %xmm0
+	movd	%xmm0, %eax
+	jmp	.LBB3_8
+.LBB3_4:
+	addl	(%rdi,%rcx,4), %eax
+	incq	%rcx
+.LBB3_8:
+	cmpq	%rcx, %rsi
+	jne	.LBB3_4
+	retq
+
+.Lanon.d5924fec307dad9f73870d3f9775fddb.0:
+	.asciz	"src/lib.rs"
+
+.Lanon.d5924fec307dad9f73870d3f9775fddb.1:
+	.quad	.Lanon.d5924fec307dad9f73870d3f9775fddb.0
+	.asciz	"\n\000\000\000\000\000\000\000\f\000\000\000\035\000\000"
+
+.Lanon.d5924fec307dad9f73870d3f9775fddb.2:
+	.quad	.Lanon.d5924fec307dad9f73870d3f9775fddb.0
+	.asciz	"\n\000\000\000\000\000\000\000\b\000\000\000\005\000\000"
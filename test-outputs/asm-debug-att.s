@@ -0,0 +1,334 @@
+playground::checked_sum:
+	subq	$24, %rsp
+	movq	%rdi, (%rsp)
+	movq	%rsi, 8(%rsp)
+	callq	*playground::sum@GOTPCREL(%rip)
+	movq	(%rsp), %rdi
+	movq	8(%rsp), %rsi
+	movl	%eax, 16(%rsp)
+	xorl	%eax, %eax
+	movl	%eax, %edx
+	callq	*playground::get@GOTPCREL(%rip)
+	movl	16(%rsp), %ecx
+	movl	%eax, %edx
+	movl	%ecx, %eax
+	addl	%edx, %eax
+	movl	%eax, 20(%rsp)
+	cmpl	%ecx, %eax
+	jb	.LBB0_2
+	movl	20(%rsp), %eax
+	addq	$24, %rsp
+	retq
+.LBB0_2:
+	leaq	.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.1(%rip), %rdi
+	callq	*core::panicking::panic_const::panic_const_add_overflow@GOTPCREL(%rip)
+
+playground::first_or_zero:
+	pushq	%rax
+	callq	*core::slice::<impl [T]>::first@GOTPCREL(%rip)
+	movq	%rax, %rdi
+	callq	*core::option::Option<&T>::copied@GOTPCREL(%rip)
+	movl	%eax, (%rsp)
+	movl	%edx, 4(%rsp)
+	movl	(%rsp), %eax
+	testq	$1, %rax
+	jne	.LBB1_2
+	leaq	.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.2(%rip), %rdi
+	callq	*core::option::unwrap_failed@GOTPCREL(%rip)
+.LBB1_2:
+	movl	4(%rsp), %eax
+	popq	%rcx
+	retq
+
+playground::get:
+	subq	$24, %rsp
+	movq	%rdi, (%rsp)
+	movq	%rsi, 8(%rsp)
+	movq	%rdx, 16(%rsp)
+	cmpq	%rsi, %rdx
+	jae	.LBB2_2
+	movq	(%rsp), %rax
+	movq	16(%rsp), %rcx
+	movl	(%rax,%rcx,4), %eax
+	addq	$24, %rsp
+	retq
+.LBB2_2:
+	movq	8(%rsp), %rsi
+	movq	16(%rsp), %rdi
+	leaq	.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.3(%rip), %rdx
+	callq	*core::panicking::panic_bounds_check@GOTPCREL(%rip)
+
+playground::sum:
+	pushq	%rax
+	callq	*core::slice::<impl [T]>::iter@GOTPCREL(%rip)
+	movq	%rax, %rdi
+	movq	%rdx, %rsi
+	callq	*core::iter::traits::iterator::Iterator::sum@GOTPCREL(%rip)
+	popq	%rcx
+	retq
+
+core::num::<impl usize>::unchecked_add::precondition_check:
+	pushq	%rax
+	movq	%rdi, %rax
+	movq	%rdx, (%rsp)
+	movq	%rax, %rdi
+	addq	%rsi, %rdi
+	cmpq	%rax, %rdi
+	jb	.LBB4_2
+	popq	%rax
+	retq
+.LBB4_2:
+	movq	(%rsp), %rcx
+	leaq	.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.4(%rip), %rdi
+	movl	$373, %esi
+	xorl	%edx, %edx
+	callq	*core::panicking::panic_nounwind_fmt@GOTPCREL(%rip)
+
+core::ptr::const_ptr::<impl *const T>::offset_from_unsigned::precondition_check:
+	pushq	%rax
+	movq	%rdx, (%rsp)
+	cmpq	%rsi, %rdi
+	jae	.LBB5_2
+	movq	(%rsp), %rcx
+	leaq	.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.5(%rip), %rdi
+	movl	$403, %esi
+	xorl	%edx, %edx
+	callq	*core::panicking::panic_nounwind_fmt@GOTPCREL(%rip)
+.LBB5_2:
+	popq	%rax
+	retq
+
+core::iter::traits::iterator::Iterator::sum:
+	pushq	%rax
+	callq	*<u32 as core::iter::traits::accum::Sum<&u32>>::sum@GOTPCREL(%rip)
+	popq	%rcx
+	retq
+
+core::slice::<impl [T]>::iter:
+	pushq	%rax
+	movq	core::slice::iter::Iter<T>::new@GOTPCREL(%rip), %rax
+	callq	*%rax
+	popq	%rcx
+	retq
+
+core::slice::<impl [T]>::first:
+	movq	%rdi, -16(%rsp)
+	cmpq	$1, %rsi
+	jae	.LBB8_2
+	movq	$0, -8(%rsp)
+	jmp	.LBB8_3
+.LBB8_2:
+	movq	-16(%rsp), %rax
+	movq	%rax, -8(%rsp)
+.LBB8_3:
+	movq	-8(%rsp), %rax
+	retq
+
+core::slice::iter::Iter<T>::new:
+	movq	%rdi, -24(%rsp)
+	movq	%rsi, -16(%rsp)
+	movq	-24(%rsp), %rax
+	movq	-16(%rsp), %rcx
+	shlq	$2, %rcx
+	addq	%rcx, %rax
+	movq	%rax, -8(%rsp)
+	movq	-24(%rsp), %rax
+	movq	-8(%rsp), %rdx
+	retq
+
+core::option::Option<&T>::copied:
+	movq	%rdi, -16(%rsp)
+	movq	-16(%rsp), %rdx
+	movl	$1, %eax
+	xorl	%ecx, %ecx
+	cmpq	$0, %rdx
+	cmoveq	%rcx, %rax
+	testq	$1, %rax
+	je	.LBB10_2
+	movq	-16(%rsp), %rax
+	movl	(%rax), %eax
+	movl	%eax, -4(%rsp)
+	movl	$1, -8(%rsp)
+	jmp	.LBB10_3
+.LBB10_2:
+	movl	$0, -8(%rsp)
+.LBB10_3:
+	movl	-8(%rsp), %eax
+	movl	-4(%rsp), %edx
+	retq
+
+<u32 as core::iter::traits::accum::Sum<&u32>>::sum:
+	pushq	%rax
+	xorl	%edx, %edx
+	callq	*<core::slice::iter::Iter<T> as core::iter::traits::iterator::Iterator>::fold@GOTPCREL(%rip)
+	popq	%rcx
+	retq
+
+<u32 as core::iter::traits::accum::Sum<&u32>>::sum::{{closure}}:
+	pushq	%rax
+	movl	%esi, %eax
+	movl	%eax, %esi
+	addl	(%rdx), %esi
+	movl	%esi, 4(%rsp)
+	cmpl	%eax, %esi
+	jb	.LBB12_2
+	movl	4(%rsp), %eax
+	popq	%rcx
+	retq
+.LBB12_2:
+	leaq	.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.7(%rip), %rdi
+	callq	*core::panicking::panic_const::panic_const_add_overflow@GOTPCREL(%rip)
+
+<core::slice::iter::Iter<T> as core::iter::traits::iterator::Iterator>::fold:
+	subq	$88, %rsp
+	movq	%rdi, 8(%rsp)
+	movq	%rsi, 16(%rsp)
+	movl	%edx, 28(%rsp)
+	movq	8(%rsp), %rax
+	movq	16(%rsp), %rcx
+	cmpq	%rcx, %rax
+	sete	%al
+	andb	$1, %al
+	movb	%al, 43(%rsp)
+	testb	$1, 43(%rsp)
+	jne	.LBB13_4
+	movl	28(%rsp), %eax
+	movb	$0, 71(%rsp)
+	movb	$1, 70(%rsp)
+	movl	%eax, 44(%rsp)
+	movq	$0, 48(%rsp)
+	jmp	.LBB13_5
+.LBB13_4:
+	movl	28(%rsp), %eax
+	movb	$0, 71(%rsp)
+	movl	%eax, 36(%rsp)
+	jmp	.LBB13_18
+.LBB13_5:
+	jmp	.LBB13_6
+.LBB13_6:
+	movq	8(%rsp), %rsi
+	movq	16(%rsp), %rdi
+	leaq	.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.9(%rip), %rdx
+	callq	core::ptr::const_ptr::<impl *const T>::offset_from_unsigned::precondition_check
+	jmp	.LBB13_8
+.LBB13_8:
+	jmp	.LBB13_9
+.LBB13_9:
+	movq	8(%rsp), %rcx
+	movq	16(%rsp), %rax
+	subq	%rcx, %rax
+	shrq	$2, %rax
+	movq	%rax, 56(%rsp)
+	jmp	.LBB13_12
+.LBB13_10:
+	testb	$1, 70(%rsp)
+	jne	.LBB13_20
+	jmp	.LBB13_19
+.LBB13_11:
+	movq	%rax, %rcx
+	movl	%edx, %eax
+	movq	%rcx, 72(%rsp)
+	movl	%eax, 80(%rsp)
+	jmp	.LBB13_10
+.LBB13_12:
+	movq	8(%rsp), %rax
+	movb	$0, 70(%rsp)
+	movl	44(%rsp), %esi
+	movq	48(%rsp), %rcx
+	leaq	(%rax,%rcx,4), %rdx
+	movq	<u32 as core::iter::traits::accum::Sum<&u32>>::sum::{{closure}}@GOTPCREL(%rip), %rax
+	leaq	35(%rsp), %rdi
+	callq	*%rax
+	movl	%eax, 4(%rsp)
+	jmp	.LBB13_13
+.LBB13_13:
+	movl	4(%rsp), %eax
+	movl	%eax, 44(%rsp)
+	movq	48(%rsp), %rdi
+	movl	$1, %esi
+	leaq	.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.12(%rip), %rdx
+	callq	core::num::<impl usize>::unchecked_add::precondition_check
+	movq	48(%rsp), %rax
+	addq	$1, %rax
+	movq	%rax, 48(%rsp)
+	movq	48(%rsp), %rax
+	cmpq	56(%rsp), %rax
+	je	.LBB13_17
+	jmp	.LBB13_12
+.LBB13_17:
+	movl	44(%rsp), %eax
+	movl	%eax, 36(%rsp)
+.LBB13_18:
+	movl	36(%rsp), %eax
+	addq	$88, %rsp
+	retq
+.LBB13_19:
+	jmp	.LBB13_21
+.LBB13_20:
+	jmp	.LBB13_19
+.LBB13_21:
+	testb	$1, 71(%rsp)
+	jne	.LBB13_23
+.LBB13_22:
+	movq	72(%rsp), %rdi
+	callq	_Unwind_Resume@PLT
+.LBB13_23:
+	jmp	.LBB13_22
+GCC_except_table13:
+.Lexception0:
+	.byte	255
+	.byte	255
+	.byte	1
+.Lcst_begin0:
+	.byte	0
+	.byte	0
+	.byte	0
+.Lcst_end0:
+
+.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.0:
+	.asciz	"src/lib.rs"
+
+.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.1:
+	.quad	.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.0
+	.asciz	"\n\000\000\000\000\000\000\000\020\000\000\000\005\000\000"
+
+.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.2:
+	.quad	.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.0
+	.asciz	"\n\000\000\000\000\000\000\000\f\000\000\000\035\000\000"
+
+.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.3:
+	.quad	.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.0
+	.asciz	"\n\000\000\000\000\000\000\000\b\000\000\000\005\000\000"
+
+.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.4:
+	.ascii	"unsafe precondition(s) violated: usize::unchecked_add cannot overflow\n\nThis indicates a bug in the program. This Undefined Behavior check is optional, and cannot be relied on for safety."
+
+.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.5:
+	.ascii	"unsafe precondition(s) violated: ptr::offset_from_unsigned requires `self >= origin`\n\nThis indicates a bug in the program. This Undefined Behavior check is optional, and cannot be relied on for safety."
+
+.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.6:
+	.asciz	"/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860/library/core/src/iter/traits/accum.rs"
+
+.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.7:
+	.quad	.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.6
+	.asciz	"U\000\000\000\000\000\000\000\314\000\000\000\001\000\000"
+
+.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.8:
+	.asciz	"/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860/library/core/src/ptr/non_null.rs"
+
+.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.9:
+	.quad	.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.8
+	.asciz	"P\000\000\000\000\000\000\000\271\003\000\000 \000\000"
+
+.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.10:
+	.ascii	"assertion failed: 0 < pointee_size && pointee_size <= isize::MAX as usize"
+
+.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.11:
+	.asciz	"/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860/library/core/src/slice/iter.rs"
+
+.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.12:
+	.quad	.Lanon.ce3c7c4b9e32d01929d844d57d9a4257.11
+	.asciz	"N\000\000\000\000\000\000\000\216\000\000\000\001\000\000"
+
+DW.ref.rust_eh_personality:
+	.quad	rust_eh_personality
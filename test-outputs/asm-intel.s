@@ -0,0 +1,152 @@
+	.intel_syntax noprefix
+	.file	"playground.d3871e328ee0cb96-cgu.0"
+	.section	.text._ZN10playground11checked_sum17h9f20f835d8d96096E,"ax",@progbits
+	.globl	_ZN10playground11checked_sum17h9f20f835d8d96096E
+	.p2align	4
+	.type	_ZN10playground11checked_sum17h9f20f835d8d96096E,@function
+_ZN10playground11checked_sum17h9f20f835d8d96096E:
+	.cfi_startproc
+	push	rbp
+	.cfi_def_cfa_offset 16
+	push	r14
+	.cfi_def_cfa_offset 24
+	push	rbx
+	.cfi_def_cfa_offset 32
+	.cfi_offset rbx, -32
+	.cfi_offset r14, -24
+	.cfi_offset rbp, -16
+	mov	rbx, rsi
+	mov	r14, rdi
+	call	qword ptr [rip + _ZN10playground3sum17h120ca45857a523a1E@GOTPCREL]
+	mov	ebp, eax
+	mov	rdi, r14
+	mov	rsi, rbx
+	xor	edx, edx
+	call	qword ptr [rip + _ZN10playground3get17h53dae1e8bd5cfe57E@GOTPCREL]
+	add	eax, ebp
+	pop	rbx
+	.cfi_def_cfa_offset 24
+	pop	r14
+	.cfi_def_cfa_offset 16
+	pop	rbp
+	.cfi_def_cfa_offset 8
+	ret
+.Lfunc_end0:
+	.size	_ZN10playground11checked_sum17h9f20f835d8d96096E, .Lfunc_end0-_ZN10playground11checked_sum17h9f20f835d8d96096E
+	.cfi_endproc
+
+	.section	.text._ZN10playground13first_or_zero17h693ee13ff9fbfb7cE,"ax",@progbits
+	.globl	_ZN10playground13first_or_zero17h693ee13ff9fbfb7cE
+	.p2align	4
+	.type	_ZN10playground13first_or_zero17h693ee13ff9fbfb7cE,@function
+_ZN10playground13first_or_zero17h693ee13ff9fbfb7cE:
+	.cfi_startproc
+	test	rsi, rsi
+	je	.LBB1_2
+	mov	eax, dword ptr [rdi]
+	ret
+.LBB1_2:
+	push	rax
+	.cfi_def_cfa_offset 16
+	lea	rdi, [rip + .Lanon.d5924fec307dad9f73870d3f9775fddb.1]
+	call	qword ptr [rip + _RNvNtCsgEmfK2I1SDS_4core6option13unwrap_failed@GOTPCREL]
+.Lfunc_end1:
+	.size	_ZN10playground13first_or_zero17h693ee13ff9fbfb7cE, .Lfunc_end1-_ZN10playground13first_or_zero17h693ee13ff9fbfb7cE
+	.cfi_endproc
+
+	.section	.text._ZN10playground3get17h53dae1e8bd5cfe57E,"ax",@progbits
+	.globl	_ZN10playground3get17h53dae1e8bd5cfe57E
+	.p2align	4
+	.type	_ZN10playground3get17h53dae1e8bd5cfe57E,@function
+_ZN10playground3get17h53dae1e8bd5cfe57E:
+	.cfi_startproc
+	cmp	rdx, rsi
+	jae	.LBB2_2
+	mov	eax, dword ptr [rdi + 4*rdx]
+	ret
+.LBB2_2:
+	push	rax
+	.cfi_def_cfa_offset 16
+	lea	rax, [rip + .Lanon.d5924fec307dad9f73870d3f9775fddb.2]
+	mov	rdi, rdx
+	mov	rdx, rax
+	call	qword ptr [rip + _RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check@GOTPCREL]
+.Lfunc_end2:
+	.size	_ZN10playground3get17h53dae1e8bd5cfe57E, .Lfunc_end2-_ZN10playground3get17h53dae1e8bd5cfe57E
+	.cfi_endproc
+
+	.section	.text._ZN10playground3sum17h120ca45857a523a1E,"ax",@progbits
+	.globl	_ZN10playground3sum17h120ca45857a523a1E
+	.p2align	4
+	.type	_ZN10playground3sum17h120ca45857a523a1E,@function
+_ZN10playground3sum17h120ca45857a523a1E:
+	.cfi_startproc
+	test	rsi, rsi
+	je	.LBB3_1
+	cmp	rsi, 8
+	jae	.LBB3_5
+	xor	ecx, ecx
+	xor	eax, eax
+	jmp	.LBB3_4
+.LBB3_1:
+	xor	eax, eax
+	ret
+.LBB3_5:
+	movabs	rcx, 2305843009213693944
+	and	rcx, rsi
+	lea	rax, [4*rsi]
+	and	rax, -32
+	pxor	xmm0, xmm0
+	xor	edx, edx
+	pxor	xmm1, xmm1
+	.p2align	4
+.LBB3_6:
+	movdqu	xmm2, xmmword ptr [rdi + rdx]
+	paddd	xmm1, xmm2
+	movdqu	xmm2, xmmword ptr [rdi + rdx + 16]
+	paddd	xmm0, xmm2
+	add	rdx, 32
+	cmp	rax, rdx
+	jne	.LBB3_6
+	paddd	xmm0, xmm1
+	pshufd	xmm1, xmm0, 238
+	paddd	xmm1, xmm0
+	pshufd	xmm0, xmm1, 85
+	paddd	xmm0, xmm1
+	movd	eax, xmm0
+	jmp	.LBB3_8
+.LBB3_4:
+	add	eax, dword ptr [rdi + 4*rcx]
+	inc	rcx
+.LBB3_8:
+	cmp	rsi, rcx
+	jne	.LBB3_4
+	ret
+.Lfunc_end3:
+	.size	_ZN10playground3sum17h120ca45857a523a1E, .Lfunc_end3-_ZN10playground3sum17h120ca45857a523a1E
+	.cfi_endproc
+
+	.type	.Lanon.d5924fec307dad9f73870d3f9775fddb.0,@object
+	.section	.rodata.str1.1,"aMS",@progbits,1
+.Lanon.d5924fec307dad9f73870d3f9775fddb.0:
+	.asciz	"src/lib.rs"
+	.size	.Lanon.d5924fec307dad9f73870d3f9775fddb.0, 11
+
+	.type	.Lanon.d5924fec307dad9f73870d3f9775fddb.1,@object
+	.section	.data.rel.ro..Lanon.d5924fec307dad9f73870d3f9775fddb.1,"aw",@progbits
+	.p2align	3, 0x0
+.Lanon.d5924fec307dad9f73870d3f9775fddb.1:
+	.quad	.Lanon.d5924fec307dad9f73870d3f9775fddb.0
+	.asciz	"\n\000\000\000\000\000\000\000\f\000\000\000\035\000\000"
+	.size	.Lanon.d5924fec307dad9f73870d3f9775fddb.1, 24
+
+	.type	.Lanon.d5924fec307dad9f73870d3f9775fddb.2,@object
+	.section	.data.rel.ro..Lanon.d5924fec307dad9f73870d3f9775fddb.2,"aw",@progbits
+	.p2align	3, 0x0
+.Lanon.d5924fec307dad9f73870d3f9775fddb.2:
+	.quad	.Lanon.d5924fec307dad9f73870d3f9775fddb.0
+	.asciz	"\n\000\000\000\000\000\000\000\b\000\000\000\005\000\000"
+	.size	.Lanon.d5924fec307dad9f73870d3f9775fddb.2, 24
+
+	.ident	"rustc version 1.95.0 (59807616e 2026-04-14)"
+	.section	".note.GNU-stack","",@progbits
@@ -0,0 +1,42 @@
+use std::num::ParseIntError;
+
+/// A point in 2D.
+#[derive(Debug)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    fn sum(&self) -> i32 {
+        self.x + self.y
+    }
+}
+
+fn parse_sum(input: &str) -> Result<i32, ParseIntError> {
+    let mut total = 0;
+    for part in input.split(',') {
+        total += part.trim().parse::<i32>()?;
+    }
+    Ok(total)
+}
+
+async fn double(x: i32) -> i32 {
+    x * 2
+}
+
+async fn run() -> i32 {
+    let add = |a: i32| a + 1;
+    add(double(20).await)
+}
+
+fn main() {
+    println!("{:?}", parse_sum("1, 2, 3"));
+    let _ = run();
+    let p = Point { x: 1, y: 2 };
+    println!("{p:?} {}", p.sum());
+}
+
+const fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
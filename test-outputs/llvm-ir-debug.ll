@@ -0,0 +1,463 @@
+; ModuleID = 'playground.d3871e328ee0cb96-cgu.0'
+source_filename = "playground.d3871e328ee0cb96-cgu.0"
+target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-i128:128-f80:128-n8:16:32:64-S128"
+target triple = "x86_64-unknown-linux-gnu"
+
+@alloc_466e6e10d4d5a95679ab35d1156aa54e = private unnamed_addr constant [11 x i8] c"src/lib.rs\00", align 1
+@alloc_f2bcdb9f4fa7f3e13cc31482a008da11 = private unnamed_addr constant <{ ptr, [16 x i8] }> <{ ptr @alloc_466e6e10d4d5a95679ab35d1156aa54e, [16 x i8] c"\0A\00\00\00\00\00\00\00\10\00\00\00\05\00\00\00" }>, align 8
+@alloc_7a949f35e6ddbe66e80e80d2b5e21e32 = private unnamed_addr constant <{ ptr, [16 x i8] }> <{ ptr @alloc_466e6e10d4d5a95679ab35d1156aa54e, [16 x i8] c"\0A\00\00\00\00\00\00\00\0C\00\00\00\1D\00\00\00" }>, align 8
+@alloc_0a8bf98f3b82fab2e8d83de81afa38a4 = private unnamed_addr constant <{ ptr, [16 x i8] }> <{ ptr @alloc_466e6e10d4d5a95679ab35d1156aa54e, [16 x i8] c"\0A\00\00\00\00\00\00\00\08\00\00\00\05\00\00\00" }>, align 8
+@alloc_3e1ebac14318b612ab4efabc52799932 = private unnamed_addr constant [186 x i8] c"unsafe precondition(s) violated: usize::unchecked_add cannot overflow\0A\0AThis indicates a bug in the program. This Undefined Behavior check is optional, and cannot be relied on for safety.", align 1
+@alloc_de4e626d456b04760e72bc785ed7e52a = private unnamed_addr constant [201 x i8] c"unsafe precondition(s) violated: ptr::offset_from_unsigned requires `self >= origin`\0A\0AThis indicates a bug in the program. This Undefined Behavior check is optional, and cannot be relied on for safety.", align 1
+@alloc_ab77ed90a3bb8c697b2704042ce329ff = private unnamed_addr constant [86 x i8] c"/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860/library/core/src/iter/traits/accum.rs\00", align 1
+@alloc_45c7fca6d83c7d5a4170abc80231b3c8 = private unnamed_addr constant <{ ptr, [16 x i8] }> <{ ptr @alloc_ab77ed90a3bb8c697b2704042ce329ff, [16 x i8] c"U\00\00\00\00\00\00\00\CC\00\00\00\01\00\00\00" }>, align 8
+@alloc_88bfb5085a97a9f03cee2b9c3c42dd08 = private unnamed_addr constant [81 x i8] c"/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860/library/core/src/ptr/non_null.rs\00", align 1
+@alloc_7cb1dd7620319c06f8fe4029f6b4a0d1 = private unnamed_addr constant <{ ptr, [16 x i8] }> <{ ptr @alloc_88bfb5085a97a9f03cee2b9c3c42dd08, [16 x i8] c"P\00\00\00\00\00\00\00\B9\03\00\00 \00\00\00" }>, align 8
+@alloc_ec595fc0e82ef92fc59bd74f68296eae = private unnamed_addr constant [73 x i8] c"assertion failed: 0 < pointee_size && pointee_size <= isize::MAX as usize", align 1
+@alloc_e9481a72334486f24b2c0d82b25567e1 = private unnamed_addr constant [79 x i8] c"/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860/library/core/src/slice/iter.rs\00", align 1
+@alloc_12fdce0839ba33fd8bd1bb0711a36af6 = private unnamed_addr constant <{ ptr, [16 x i8] }> <{ ptr @alloc_e9481a72334486f24b2c0d82b25567e1, [16 x i8] c"N\00\00\00\00\00\00\00\8E\00\00\00\01\00\00\00" }>, align 8
+
+; playground::checked_sum
+; Function Attrs: nonlazybind uwtable
+define i32 @_ZN10playground11checked_sum17h9f20f835d8d96096E(ptr align 4 %values.0, i64 %values.1) unnamed_addr #0 {
+start:
+; call playground::sum
+  %_2 = call i32 @_ZN10playground3sum17h120ca45857a523a1E(ptr align 4 %values.0, i64 %values.1) #8
+; call playground::get
+  %_3 = call i32 @_ZN10playground3get17h53dae1e8bd5cfe57E(ptr align 4 %values.0, i64 %values.1, i64 0) #8
+  %_4.0 = add i32 %_2, %_3
+  %_4.1 = icmp ult i32 %_4.0, %_2
+  br i1 %_4.1, label %panic, label %bb3
+
+bb3:                                              ; preds = %start
+  ret i32 %_4.0
+
+panic:                                            ; preds = %start
+; call core::panicking::panic_const::panic_const_add_overflow
+  call void @_RNvNtNtCsgEmfK2I1SDS_4core9panicking11panic_const24panic_const_add_overflow(ptr align 8 @alloc_f2bcdb9f4fa7f3e13cc31482a008da11) #9
+  unreachable
+}
+
+; playground::first_or_zero
+; Function Attrs: nonlazybind uwtable
+define i32 @_ZN10playground13first_or_zero17h693ee13ff9fbfb7cE(ptr align 4 %values.0, i64 %values.1) unnamed_addr #0 {
+start:
+  %self.i = alloca [8 x i8], align 4
+; call core::slice::<impl [T]>::first
+  %_3 = call align 4 ptr @"_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$5first17h4a68f28247b0298fE"(ptr align 4 %values.0, i64 %values.1) #10
+; call core::option::Option<&T>::copied
+  %0 = call { i32, i32 } @"_ZN4core6option19Option$LT$$RF$T$GT$6copied17he1e3e341e515c661E"(ptr align 4 %_3)
+  %_2.0 = extractvalue { i32, i32 } %0, 0
+  %_2.1 = extractvalue { i32, i32 } %0, 1
+  store i32 %_2.0, ptr %self.i, align 4
+  %1 = getelementptr inbounds i8, ptr %self.i, i64 4
+  store i32 %_2.1, ptr %1, align 4
+  %2 = load i32, ptr %self.i, align 4
+  %3 = getelementptr inbounds i8, ptr %self.i, i64 4
+  %4 = load i32, ptr %3, align 4
+  %_2.i = zext i32 %2 to i64
+  %5 = trunc nuw i64 %_2.i to i1
+  br i1 %5, label %"_ZN4core6option15Option$LT$T$GT$6unwrap17h477af2000e260832E.exit", label %bb2.i
+
+bb2.i:                                            ; preds = %start
+; call core::option::unwrap_failed
+  call void @_RNvNtCsgEmfK2I1SDS_4core6option13unwrap_failed(ptr align 8 @alloc_7a949f35e6ddbe66e80e80d2b5e21e32) #9
+  unreachable
+
+"_ZN4core6option15Option$LT$T$GT$6unwrap17h477af2000e260832E.exit": ; preds = %start
+  %6 = getelementptr inbounds i8, ptr %self.i, i64 4
+  %val.i = load i32, ptr %6, align 4
+  ret i32 %val.i
+}
+
+; playground::get
+; Function Attrs: noinline nonlazybind uwtable
+define i32 @_ZN10playground3get17h53dae1e8bd5cfe57E(ptr align 4 %values.0, i64 %values.1, i64 %index) unnamed_addr #1 {
+start:
+  %_4 = icmp ult i64 %index, %values.1
+  br i1 %_4, label %bb1, label %panic
+
+bb1:                                              ; preds = %start
+  %0 = getelementptr inbounds nuw i32, ptr %values.0, i64 %index
+  %_0 = load i32, ptr %0, align 4
+  ret i32 %_0
+
+panic:                                            ; preds = %start
+; call core::panicking::panic_bounds_check
+  call void @_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check(i64 %index, i64 %values.1, ptr align 8 @alloc_0a8bf98f3b82fab2e8d83de81afa38a4) #9
+  unreachable
+}
+
+; playground::sum
+; Function Attrs: noinline nonlazybind uwtable
+define i32 @_ZN10playground3sum17h120ca45857a523a1E(ptr align 4 %values.0, i64 %values.1) unnamed_addr #1 {
+start:
+; call core::slice::<impl [T]>::iter
+  %0 = call { ptr, ptr } @"_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$4iter17hde05630c16e01348E"(ptr align 4 %values.0, i64 %values.1) #10
+  %_2.0 = extractvalue { ptr, ptr } %0, 0
+  %_2.1 = extractvalue { ptr, ptr } %0, 1
+; call core::iter::traits::iterator::Iterator::sum
+  %_0 = call i32 @_ZN4core4iter6traits8iterator8Iterator3sum17hef52314f9fea7af2E(ptr %_2.0, ptr %_2.1)
+  ret i32 %_0
+}
+
+; core::num::<impl usize>::unchecked_add::precondition_check
+; Function Attrs: inlinehint nounwind nonlazybind uwtable
+define internal void @"_ZN4core3num23_$LT$impl$u20$usize$GT$13unchecked_add18precondition_check17hcc6d71b4a005859bE"(i64 %lhs, i64 %rhs, ptr align 8 %0) unnamed_addr #2 {
+start:
+  %_7.0 = add i64 %lhs, %rhs
+  %_7.1 = icmp ult i64 %_7.0, %lhs
+  br i1 %_7.1, label %bb1, label %bb2
+
+bb2:                                              ; preds = %start
+  ret void
+
+bb1:                                              ; preds = %start
+; call core::panicking::panic_nounwind_fmt
+  call void @_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_nounwind_fmt(ptr @alloc_3e1ebac14318b612ab4efabc52799932, ptr inttoptr (i64 373 to ptr), i1 zeroext false, ptr align 8 %0) #11
+  unreachable
+}
+
+; core::ptr::const_ptr::<impl *const T>::offset_from_unsigned::precondition_check
+; Function Attrs: inlinehint nounwind nonlazybind uwtable
+define internal void @"_ZN4core3ptr9const_ptr33_$LT$impl$u20$$BP$const$u20$T$GT$20offset_from_unsigned18precondition_check17h5dfd1691aaa4c9e0E"(ptr %this, ptr %origin, ptr align 8 %0) unnamed_addr #2 {
+start:
+  %_3 = icmp uge ptr %this, %origin
+  br i1 %_3, label %bb1, label %bb2
+
+bb2:                                              ; preds = %start
+; call core::panicking::panic_nounwind_fmt
+  call void @_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_nounwind_fmt(ptr @alloc_de4e626d456b04760e72bc785ed7e52a, ptr inttoptr (i64 403 to ptr), i1 zeroext false, ptr align 8 %0) #11
+  unreachable
+
+bb1:                                              ; preds = %start
+  ret void
+}
+
+; core::iter::traits::iterator::Iterator::sum
+; Function Attrs: nonlazybind uwtable
+define i32 @_ZN4core4iter6traits8iterator8Iterator3sum17hef52314f9fea7af2E(ptr %self.0, ptr %self.1) unnamed_addr #0 {
+start:
+; call <u32 as core::iter::traits::accum::Sum<&u32>>::sum
+  %_0 = call i32 @"_ZN69_$LT$u32$u20$as$u20$core..iter..traits..accum..Sum$LT$$RF$u32$GT$$GT$3sum17hcb4828661486477fE"(ptr %self.0, ptr %self.1)
+  ret i32 %_0
+}
+
+; core::slice::<impl [T]>::iter
+; Function Attrs: inlinehint nonlazybind uwtable
+define { ptr, ptr } @"_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$4iter17hde05630c16e01348E"(ptr align 4 %self.0, i64 %self.1) unnamed_addr #3 {
+start:
+; call core::slice::iter::Iter<T>::new
+  %0 = call { ptr, ptr } @"_ZN4core5slice4iter13Iter$LT$T$GT$3new17h0a8e8efb82508590E"(ptr align 4 %self.0, i64 %self.1) #10
+  %_0.0 = extractvalue { ptr, ptr } %0, 0
+  %_0.1 = extractvalue { ptr, ptr } %0, 1
+  %1 = insertvalue { ptr, ptr } poison, ptr %_0.0, 0
+  %2 = insertvalue { ptr, ptr } %1, ptr %_0.1, 1
+  ret { ptr, ptr } %2
+}
+
+; core::slice::<impl [T]>::first
+; Function Attrs: inlinehint nonlazybind uwtable
+define align 4 ptr @"_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$5first17h4a68f28247b0298fE"(ptr align 4 %self.0, i64 %self.1) unnamed_addr #3 {
+start:
+  %_0 = alloca [8 x i8], align 8
+  %_3 = icmp uge i64 %self.1, 1
+  br i1 %_3, label %bb1, label %bb2
+
+bb2:                                              ; preds = %start
+  store ptr null, ptr %_0, align 8
+  br label %bb3
+
+bb1:                                              ; preds = %start
+  %first = getelementptr inbounds nuw i32, ptr %self.0, i64 0
+  store ptr %first, ptr %_0, align 8
+  br label %bb3
+
+bb3:                                              ; preds = %bb1, %bb2
+  %0 = load ptr, ptr %_0, align 8
+  ret ptr %0
+}
+
+; core::slice::iter::Iter<T>::new
+; Function Attrs: inlinehint nonlazybind uwtable
+define { ptr, ptr } @"_ZN4core5slice4iter13Iter$LT$T$GT$3new17h0a8e8efb82508590E"(ptr align 4 %slice.0, i64 %slice.1) unnamed_addr #3 {
+start:
+  %end_or_len = alloca [8 x i8], align 8
+  br label %bb2
+
+bb2:                                              ; preds = %start
+  %_6 = getelementptr inbounds nuw i32, ptr %slice.0, i64 %slice.1
+  store ptr %_6, ptr %end_or_len, align 8
+  br label %bb3
+
+bb3:                                              ; preds = %bb2
+  %_0.1 = load ptr, ptr %end_or_len, align 8
+  %0 = insertvalue { ptr, ptr } poison, ptr %slice.0, 0
+  %1 = insertvalue { ptr, ptr } %0, ptr %_0.1, 1
+  ret { ptr, ptr } %1
+
+bb1:                                              ; No predecessors!
+  unreachable
+}
+
+; core::option::Option<&T>::copied
+; Function Attrs: nonlazybind uwtable
+define { i32, i32 } @"_ZN4core6option19Option$LT$$RF$T$GT$6copied17he1e3e341e515c661E"(ptr align 4 %0) unnamed_addr #0 {
+start:
+  %_0 = alloca [8 x i8], align 4
+  %self = alloca [8 x i8], align 8
+  store ptr %0, ptr %self, align 8
+  %1 = load ptr, ptr %self, align 8
+  %2 = ptrtoint ptr %1 to i64
+  %3 = icmp eq i64 %2, 0
+  %_2 = select i1 %3, i64 0, i64 1
+  %4 = trunc nuw i64 %_2 to i1
+  br i1 %4, label %bb3, label %bb2
+
+bb3:                                              ; preds = %start
+  %_4 = load ptr, ptr %self, align 8
+  %v = load i32, ptr %_4, align 4
+  %5 = getelementptr inbounds i8, ptr %_0, i64 4
+  store i32 %v, ptr %5, align 4
+  store i32 1, ptr %_0, align 4
+  br label %bb4
+
+bb2:                                              ; preds = %start
+  store i32 0, ptr %_0, align 4
+  br label %bb4
+
+bb4:                                              ; preds = %bb3, %bb2
+  %6 = load i32, ptr %_0, align 4
+  %7 = getelementptr inbounds i8, ptr %_0, i64 4
+  %8 = load i32, ptr %7, align 4
+  %9 = insertvalue { i32, i32 } poison, i32 %6, 0
+  %10 = insertvalue { i32, i32 } %9, i32 %8, 1
+  ret { i32, i32 } %10
+
+bb1:                                              ; No predecessors!
+  unreachable
+}
+
+; <u32 as core::iter::traits::accum::Sum<&u32>>::sum
+; Function Attrs: nonlazybind uwtable
+define i32 @"_ZN69_$LT$u32$u20$as$u20$core..iter..traits..accum..Sum$LT$$RF$u32$GT$$GT$3sum17hcb4828661486477fE"(ptr %iter.0, ptr %iter.1) unnamed_addr #0 {
+start:
+; call <core::slice::iter::Iter<T> as core::iter::traits::iterator::Iterator>::fold
+  %_0 = call i32 @"_ZN91_$LT$core..slice..iter..Iter$LT$T$GT$$u20$as$u20$core..iter..traits..iterator..Iterator$GT$4fold17h2b0920ce6c2f14faE"(ptr %iter.0, ptr %iter.1, i32 0) #10
+  ret i32 %_0
+}
+
+; <u32 as core::iter::traits::accum::Sum<&u32>>::sum::{{closure}}
+; Function Attrs: inlinehint nonlazybind uwtable
+define i32 @"_ZN69_$LT$u32$u20$as$u20$core..iter..traits..accum..Sum$LT$$RF$u32$GT$$GT$3sum28_$u7b$$u7b$closure$u7d$$u7d$17hb87f1733c1554ea7E"(ptr align 1 %_1, i32 %a, ptr align 4 %b) unnamed_addr #3 {
+start:
+  %other = load i32, ptr %b, align 4
+  %_5.0 = add i32 %a, %other
+  %_5.1 = icmp ult i32 %_5.0, %a
+  br i1 %_5.1, label %panic, label %bb1
+
+bb1:                                              ; preds = %start
+  ret i32 %_5.0
+
+panic:                                            ; preds = %start
+; call core::panicking::panic_const::panic_const_add_overflow
+  call void @_RNvNtNtCsgEmfK2I1SDS_4core9panicking11panic_const24panic_const_add_overflow(ptr align 8 @alloc_45c7fca6d83c7d5a4170abc80231b3c8) #9
+  unreachable
+}
+
+; <core::slice::iter::Iter<T> as core::iter::traits::iterator::Iterator>::fold
+; Function Attrs: inlinehint nonlazybind uwtable
+define i32 @"_ZN91_$LT$core..slice..iter..Iter$LT$T$GT$$u20$as$u20$core..iter..traits..iterator..Iterator$GT$4fold17h2b0920ce6c2f14faE"(ptr %self.0, ptr %self.1, i32 %init) unnamed_addr #3 personality ptr @rust_eh_personality {
+start:
+  %0 = alloca [16 x i8], align 8
+  %_32 = alloca [1 x i8], align 1
+  %_31 = alloca [1 x i8], align 1
+  %len = alloca [8 x i8], align 8
+  %i = alloca [8 x i8], align 8
+  %acc = alloca [4 x i8], align 4
+  %_4 = alloca [1 x i8], align 1
+  %_0 = alloca [4 x i8], align 4
+  %f = alloca [0 x i8], align 1
+  br label %bb2
+
+bb2:                                              ; preds = %start
+  %1 = icmp eq ptr %self.0, %self.1
+  %2 = zext i1 %1 to i8
+  store i8 %2, ptr %_4, align 1
+  br label %bb3
+
+bb3:                                              ; preds = %bb2
+  %3 = load i8, ptr %_4, align 1
+  %4 = trunc nuw i8 %3 to i1
+  br i1 %4, label %bb4, label %bb5
+
+bb5:                                              ; preds = %bb3
+  store i8 0, ptr %_32, align 1
+  store i8 1, ptr %_31, align 1
+  store i32 %init, ptr %acc, align 4
+  store i64 0, ptr %i, align 8
+  br label %bb7
+
+bb4:                                              ; preds = %bb3
+  store i8 0, ptr %_32, align 1
+  store i32 %init, ptr %_0, align 4
+  br label %bb12
+
+bb7:                                              ; preds = %bb5
+  br label %bb20
+
+bb20:                                             ; preds = %bb7
+; call core::ptr::const_ptr::<impl *const T>::offset_from_unsigned::precondition_check
+  call void @"_ZN4core3ptr9const_ptr33_$LT$impl$u20$$BP$const$u20$T$GT$20offset_from_unsigned18precondition_check17h5dfd1691aaa4c9e0E"(ptr %self.1, ptr %self.0, ptr align 8 @alloc_7cb1dd7620319c06f8fe4029f6b4a0d1) #12
+  br label %bb22
+
+bb22:                                             ; preds = %bb20
+  br label %bb23
+
+bb23:                                             ; preds = %bb22
+  br label %bb24
+
+bb24:                                             ; preds = %bb23
+  %5 = ptrtoint ptr %self.1 to i64
+  %6 = ptrtoint ptr %self.0 to i64
+  %7 = sub nuw i64 %5, %6
+  %8 = udiv exact i64 %7, 4
+  store i64 %8, ptr %len, align 8
+  br label %bb8
+
+bb25:                                             ; No predecessors!
+; invoke core::panicking::panic
+  invoke void @_RNvNtCsgEmfK2I1SDS_4core9panicking5panic(ptr align 1 @alloc_ec595fc0e82ef92fc59bd74f68296eae, i64 73, ptr align 8 @alloc_7cb1dd7620319c06f8fe4029f6b4a0d1) #13
+          to label %unreachable unwind label %cleanup
+
+bb16:                                             ; preds = %cleanup
+  %9 = load i8, ptr %_31, align 1
+  %10 = trunc nuw i8 %9 to i1
+  br i1 %10, label %bb15, label %bb13
+
+cleanup:                                          ; preds = %bb8, %bb25
+  %11 = landingpad { ptr, i32 }
+          cleanup
+  %12 = extractvalue { ptr, i32 } %11, 0
+  %13 = extractvalue { ptr, i32 } %11, 1
+  store ptr %12, ptr %0, align 8
+  %14 = getelementptr inbounds i8, ptr %0, i64 8
+  store i32 %13, ptr %14, align 8
+  br label %bb16
+
+unreachable:                                      ; preds = %bb25
+  unreachable
+
+bb8:                                              ; preds = %bb11, %bb24
+  store i8 0, ptr %_31, align 1
+  %_21 = load i32, ptr %acc, align 4
+  %count = load i64, ptr %i, align 8
+  %_45 = getelementptr inbounds nuw i32, ptr %self.0, i64 %count
+; invoke <u32 as core::iter::traits::accum::Sum<&u32>>::sum::{{closure}}
+  %_18 = invoke i32 @"_ZN69_$LT$u32$u20$as$u20$core..iter..traits..accum..Sum$LT$$RF$u32$GT$$GT$3sum28_$u7b$$u7b$closure$u7d$$u7d$17hb87f1733c1554ea7E"(ptr align 1 %f, i32 %_21, ptr align 4 %_45)
+          to label %bb9 unwind label %cleanup
+
+bb9:                                              ; preds = %bb8
+  store i32 %_18, ptr %acc, align 4
+  br label %bb26
+
+bb26:                                             ; preds = %bb9
+  %15 = load i64, ptr %i, align 8
+; call core::num::<impl usize>::unchecked_add::precondition_check
+  call void @"_ZN4core3num23_$LT$impl$u20$usize$GT$13unchecked_add18precondition_check17hcc6d71b4a005859bE"(i64 %15, i64 1, ptr align 8 @alloc_12fdce0839ba33fd8bd1bb0711a36af6) #12
+  br label %bb27
+
+bb27:                                             ; preds = %bb26
+  %16 = load i64, ptr %i, align 8
+  %_27 = add nuw i64 %16, 1
+  store i64 %_27, ptr %i, align 8
+  %_29 = load i64, ptr %i, align 8
+  %_30 = load i64, ptr %len, align 8
+  %_28 = icmp eq i64 %_29, %_30
+  br i1 %_28, label %bb10, label %bb11
+
+bb11:                                             ; preds = %bb27
+  br label %bb8
+
+bb10:                                             ; preds = %bb27
+  %17 = load i32, ptr %acc, align 4
+  store i32 %17, ptr %_0, align 4
+  br label %bb12
+
+bb12:                                             ; preds = %bb4, %bb10
+  %18 = load i32, ptr %_0, align 4
+  ret i32 %18
+
+bb13:                                             ; preds = %bb15, %bb16
+  br label %bb18
+
+bb15:                                             ; preds = %bb16
+  br label %bb13
+
+bb18:                                             ; preds = %bb13
+  %19 = load i8, ptr %_32, align 1
+  %20 = trunc nuw i8 %19 to i1
+  br i1 %20, label %bb17, label %bb14
+
+bb14:                                             ; preds = %bb17, %bb18
+  %21 = load ptr, ptr %0, align 8
+  %22 = getelementptr inbounds i8, ptr %0, i64 8
+  %23 = load i32, ptr %22, align 8
+  %24 = insertvalue { ptr, i32 } poison, ptr %21, 0
+  %25 = insertvalue { ptr, i32 } %24, i32 %23, 1
+  resume { ptr, i32 } %25
+
+bb17:                                             ; preds = %bb18
+  br label %bb14
+
+bb1:                                              ; No predecessors!
+  unreachable
+
+bb6:                                              ; No predecessors!
+  unreachable
+}
+
+; core::panicking::panic_const::panic_const_add_overflow
+; Function Attrs: cold noinline noreturn nonlazybind uwtable
+declare void @_RNvNtNtCsgEmfK2I1SDS_4core9panicking11panic_const24panic_const_add_overflow(ptr align 8) unnamed_addr #4
+
+; core::panicking::panic_bounds_check
+; Function Attrs: cold minsize noinline noreturn nonlazybind optsize uwtable
+declare void @_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check(i64, i64, ptr align 8) unnamed_addr #5
+
+; core::panicking::panic_nounwind_fmt
+; Function Attrs: cold noinline noreturn nounwind nonlazybind uwtable
+declare void @_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_nounwind_fmt(ptr, ptr, i1 zeroext, ptr align 8) unnamed_addr #6
+
+; core::option::unwrap_failed
+; Function Attrs: cold noinline noreturn nonlazybind uwtable
+declare void @_RNvNtCsgEmfK2I1SDS_4core6option13unwrap_failed(ptr align 8) unnamed_addr #4
+
+; Function Attrs: nounwind nonlazybind uwtable
+declare i32 @rust_eh_personality(i32, i32, i64, ptr, ptr) unnamed_addr #7
+
+; core::panicking::panic
+; Function Attrs: cold noinline noreturn nonlazybind uwtable
+declare void @_RNvNtCsgEmfK2I1SDS_4core9panicking5panic(ptr align 1, i64, ptr align 8) unnamed_addr #4
+
+attributes #0 = { nonlazybind uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #1 = { noinline nonlazybind uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #2 = { inlinehint nounwind nonlazybind uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #3 = { inlinehint nonlazybind uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #4 = { cold noinline noreturn nonlazybind uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #5 = { cold minsize noinline noreturn nonlazybind optsize uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #6 = { cold noinline noreturn nounwind nonlazybind uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #7 = { nounwind nonlazybind uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #8 = { noinline }
+attributes #9 = { noinline noreturn }
+attributes #10 = { inlinehint }
+attributes #11 = { noinline noreturn nounwind }
+attributes #12 = { inlinehint nounwind }
+attributes #13 = { noreturn }
+
+!llvm.module.flags = !{!0, !1}
+!llvm.ident = !{!2}
+
+!0 = !{i32 8, !"PIC Level", i32 2}
+!1 = !{i32 2, !"RtLibUseGOT", i32 1}
+!2 = !{!"rustc version 1.95.0 (59807616e 2026-04-14)"}
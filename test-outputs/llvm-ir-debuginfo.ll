@@ -0,0 +1,474 @@
+; ModuleID = 'playground.d3871e328ee0cb96-cgu.0'
+source_filename = "playground.d3871e328ee0cb96-cgu.0"
+target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-i128:128-f80:128-n8:16:32:64-S128"
+target triple = "x86_64-unknown-linux-gnu"
+
+@alloc_466e6e10d4d5a95679ab35d1156aa54e = private unnamed_addr constant [11 x i8] c"src/lib.rs\00", align 1
+@alloc_7a949f35e6ddbe66e80e80d2b5e21e32 = private unnamed_addr constant <{ ptr, [16 x i8] }> <{ ptr @alloc_466e6e10d4d5a95679ab35d1156aa54e, [16 x i8] c"\0A\00\00\00\00\00\00\00\0C\00\00\00\1D\00\00\00" }>, align 8
+@alloc_0a8bf98f3b82fab2e8d83de81afa38a4 = private unnamed_addr constant <{ ptr, [16 x i8] }> <{ ptr @alloc_466e6e10d4d5a95679ab35d1156aa54e, [16 x i8] c"\0A\00\00\00\00\00\00\00\08\00\00\00\05\00\00\00" }>, align 8
+
+; playground::checked_sum
+; Function Attrs: nonlazybind uwtable
+define noundef i32 @_ZN10playground11checked_sum17h9f20f835d8d96096E(ptr noalias noundef nonnull readonly align 4 captures(none) %values.0, i64 noundef range(i64 0, 2305843009213693952) %values.1) unnamed_addr #0 !dbg !7 {
+start:
+    #dbg_value(ptr %values.0, !22, !DIExpression(DW_OP_LLVM_fragment, 0, 64), !23)
+    #dbg_value(i64 %values.1, !22, !DIExpression(DW_OP_LLVM_fragment, 64, 64), !23)
+; call playground::sum
+  %_2 = tail call noundef i32 @_ZN10playground3sum17h120ca45857a523a1E(ptr noalias noundef nonnull readonly align 4 captures(address, read_provenance) %values.0, i64 noundef %values.1) #7, !dbg !24
+; call playground::get
+  %_3 = tail call noundef i32 @_ZN10playground3get17h53dae1e8bd5cfe57E(ptr noalias noundef nonnull readonly align 4 captures(address, read_provenance) %values.0, i64 noundef %values.1, i64 noundef 0) #7, !dbg !25
+  %_0 = add i32 %_3, %_2, !dbg !24
+  ret i32 %_0, !dbg !26
+}
+
+; playground::first_or_zero
+; Function Attrs: nonlazybind uwtable
+define noundef i32 @_ZN10playground13first_or_zero17h693ee13ff9fbfb7cE(ptr noalias noundef nonnull readonly align 4 captures(none) %values.0, i64 noundef range(i64 0, 2305843009213693952) %values.1) unnamed_addr #0 !dbg !27 {
+start:
+    #dbg_value(ptr %values.0, !29, !DIExpression(DW_OP_LLVM_fragment, 0, 64), !30)
+    #dbg_value(ptr %values.0, !31, !DIExpression(DW_OP_LLVM_fragment, 0, 64), !60)
+    #dbg_value(i64 %values.1, !29, !DIExpression(DW_OP_LLVM_fragment, 64, 64), !30)
+    #dbg_value(i64 %values.1, !31, !DIExpression(DW_OP_LLVM_fragment, 64, 64), !60)
+  %_5.not = icmp eq i64 %values.1, 0, !dbg !62
+  br i1 %_5.not, label %bb2, label %bb1, !dbg !62, !prof !63
+
+bb2:                                              ; preds = %start
+    #dbg_value(ptr null, !64, !DIExpression(), !84)
+    #dbg_value(i32 poison, !86, !DIExpression(DW_OP_LLVM_fragment, 0, 32), !124)
+    #dbg_value(i32 poison, !86, !DIExpression(DW_OP_LLVM_fragment, 32, 32), !124)
+; call core::option::unwrap_failed
+  tail call void @_RNvNtCsgEmfK2I1SDS_4core6option13unwrap_failed(ptr noalias noundef readonly align 8 captures(address, read_provenance) dereferenceable(24) @alloc_7a949f35e6ddbe66e80e80d2b5e21e32) #8, !dbg !126
+  unreachable, !dbg !126
+
+bb1:                                              ; preds = %start
+    #dbg_value(ptr %values.0, !56, !DIExpression(), !127)
+    #dbg_value(ptr %values.0, !64, !DIExpression(), !84)
+  %v = load i32, ptr %values.0, align 4, !dbg !128, !noundef !20
+    #dbg_value(i32 %v, !86, !DIExpression(DW_OP_LLVM_fragment, 32, 32), !124)
+    #dbg_value(i32 1, !86, !DIExpression(DW_OP_LLVM_fragment, 0, 32), !124)
+  ret i32 %v, !dbg !129
+}
+
+; playground::get
+; Function Attrs: noinline nonlazybind uwtable
+define noundef i32 @_ZN10playground3get17h53dae1e8bd5cfe57E(ptr noalias noundef nonnull readonly align 4 captures(none) %values.0, i64 noundef range(i64 0, 2305843009213693952) %values.1, i64 noundef %index) unnamed_addr #1 !dbg !130 {
+start:
+    #dbg_value(ptr %values.0, !134, !DIExpression(DW_OP_LLVM_fragment, 0, 64), !136)
+    #dbg_value(i64 %values.1, !134, !DIExpression(DW_OP_LLVM_fragment, 64, 64), !136)
+    #dbg_value(i64 %index, !135, !DIExpression(), !136)
+  %_4 = icmp ult i64 %index, %values.1, !dbg !137
+  br i1 %_4, label %bb1, label %panic, !dbg !137
+
+bb1:                                              ; preds = %start
+  %0 = getelementptr inbounds nuw i32, ptr %values.0, i64 %index, !dbg !137
+  %_0 = load i32, ptr %0, align 4, !dbg !137, !noundef !20
+  ret i32 %_0, !dbg !138
+
+panic:                                            ; preds = %start
+; call core::panicking::panic_bounds_check
+  tail call void @_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check(i64 noundef %index, i64 noundef %values.1, ptr noalias noundef readonly align 8 captures(address, read_provenance) dereferenceable(24) @alloc_0a8bf98f3b82fab2e8d83de81afa38a4) #8, !dbg !137
+  unreachable, !dbg !137
+}
+
+; playground::sum
+; Function Attrs: nofree noinline norecurse nosync nounwind nonlazybind memory(argmem: read) uwtable
+define noundef i32 @_ZN10playground3sum17h120ca45857a523a1E(ptr noalias noundef nonnull readonly align 4 captures(none) %values.0, i64 noundef range(i64 0, 2305843009213693952) %values.1) unnamed_addr #2 personality ptr @rust_eh_personality !dbg !139 {
+start:
+    #dbg_value(ptr %values.0, !141, !DIExpression(DW_OP_LLVM_fragment, 0, 64), !142)
+    #dbg_value(i64 %values.1, !141, !DIExpression(DW_OP_LLVM_fragment, 64, 64), !142)
+    #dbg_value(ptr %values.0, !143, !DIExpression(DW_OP_LLVM_fragment, 0, 64), !167)
+    #dbg_value(ptr %values.0, !169, !DIExpression(DW_OP_LLVM_fragment, 0, 64), !177)
+    #dbg_value(!DIArgList(ptr %values.0, i64 %values.1), !143, !DIExpression(DW_OP_LLVM_arg, 0, DW_OP_LLVM_arg, 1, DW_OP_constu, 2, DW_OP_shl, DW_OP_plus, DW_OP_stack_value, DW_OP_LLVM_fragment, 64, 64), !167)
+    #dbg_value(!DIArgList(ptr %values.0, i64 %values.1), !169, !DIExpression(DW_OP_LLVM_arg, 0, DW_OP_LLVM_arg, 1, DW_OP_constu, 2, DW_OP_shl, DW_OP_plus, DW_OP_stack_value, DW_OP_LLVM_fragment, 64, 64), !177)
+    #dbg_value(ptr %values.0, !179, !DIExpression(DW_OP_LLVM_fragment, 0, 64), !207)
+    #dbg_value(!DIArgList(ptr %values.0, i64 %values.1), !179, !DIExpression(DW_OP_LLVM_arg, 0, DW_OP_LLVM_arg, 1, DW_OP_constu, 2, DW_OP_shl, DW_OP_plus, DW_OP_stack_value, DW_OP_LLVM_fragment, 64, 64), !207)
+    #dbg_value(i32 0, !188, !DIExpression(), !207)
+    #dbg_declare(ptr poison, !189, !DIExpression(), !209)
+    #dbg_value(i64 1, !210, !DIExpression(), !219)
+    #dbg_value(!DIArgList(ptr %values.0, i64 %values.1), !192, !DIExpression(DW_OP_LLVM_arg, 0, DW_OP_LLVM_arg, 1, DW_OP_constu, 2, DW_OP_shl, DW_OP_plus, DW_OP_stack_value), !221)
+    #dbg_value(ptr poison, !222, !DIExpression(), !232)
+    #dbg_value(ptr poison, !231, !DIExpression(), !234)
+  %0 = icmp eq i64 %values.1, 0, !dbg !235
+  br i1 %0, label %"_ZN91_$LT$core..slice..iter..Iter$LT$T$GT$$u20$as$u20$core..iter..traits..iterator..Iterator$GT$4fold17h2b0920ce6c2f14faE.exit", label %bb8.i.preheader, !dbg !236
+
+bb8.i.preheader:                                  ; preds = %start
+  %min.iters.check = icmp samesign ult i64 %values.1, 8, !dbg !237
+  br i1 %min.iters.check, label %bb8.i.preheader5, label %vector.ph, !dbg !237
+
+vector.ph:                                        ; preds = %bb8.i.preheader
+  %n.vec = and i64 %values.1, 2305843009213693944
+  br label %vector.body, !dbg !237
+
+vector.body:                                      ; preds = %vector.body, %vector.ph
+  %index = phi i64 [ 0, %vector.ph ], [ %index.next, %vector.body ], !dbg !238
+  %vec.phi = phi <4 x i32> [ zeroinitializer, %vector.ph ], [ %3, %vector.body ]
+  %vec.phi3 = phi <4 x i32> [ zeroinitializer, %vector.ph ], [ %4, %vector.body ]
+  %1 = getelementptr inbounds nuw i32, ptr %values.0, i64 %index, !dbg !239
+  %2 = getelementptr inbounds nuw i8, ptr %1, i64 16, !dbg !248
+  %wide.load = load <4 x i32>, ptr %1, align 4, !dbg !248
+  %wide.load4 = load <4 x i32>, ptr %2, align 4, !dbg !248
+  %3 = add <4 x i32> %wide.load, %vec.phi, !dbg !249
+  %4 = add <4 x i32> %wide.load4, %vec.phi3, !dbg !249
+  %index.next = add nuw i64 %index, 8, !dbg !238
+  %5 = icmp eq i64 %index.next, %n.vec, !dbg !237
+  br i1 %5, label %middle.block, label %vector.body, !dbg !237, !llvm.loop !279
+
+middle.block:                                     ; preds = %vector.body
+  %bin.rdx = add <4 x i32> %4, %3, !dbg !237
+  %6 = tail call i32 @llvm.vector.reduce.add.v4i32(<4 x i32> %bin.rdx), !dbg !237
+  %cmp.n = icmp eq i64 %values.1, %n.vec, !dbg !237
+  br i1 %cmp.n, label %"_ZN91_$LT$core..slice..iter..Iter$LT$T$GT$$u20$as$u20$core..iter..traits..iterator..Iterator$GT$4fold17h2b0920ce6c2f14faE.exit", label %bb8.i.preheader5, !dbg !237
+
+bb8.i.preheader5:                                 ; preds = %bb8.i.preheader, %middle.block
+  %i.sroa.0.0.i.ph = phi i64 [ 0, %bb8.i.preheader ], [ %n.vec, %middle.block ]
+  %acc.sroa.0.0.i.ph = phi i32 [ 0, %bb8.i.preheader ], [ %6, %middle.block ]
+  br label %bb8.i, !dbg !237
+
+bb8.i:                                            ; preds = %bb8.i.preheader5, %bb8.i
+  %i.sroa.0.0.i = phi i64 [ %_27.i, %bb8.i ], [ %i.sroa.0.0.i.ph, %bb8.i.preheader5 ], !dbg !238
+  %acc.sroa.0.0.i = phi i32 [ %_5.0.i.i, %bb8.i ], [ %acc.sroa.0.0.i.ph, %bb8.i.preheader5 ], !dbg !207
+    #dbg_value(i32 %acc.sroa.0.0.i, !194, !DIExpression(), !238)
+    #dbg_value(i64 %i.sroa.0.0.i, !218, !DIExpression(), !219)
+    #dbg_value(i64 %i.sroa.0.0.i, !196, !DIExpression(), !282)
+    #dbg_value(ptr %values.0, !245, !DIExpression(), !283)
+    #dbg_value(i64 %i.sroa.0.0.i, !246, !DIExpression(), !283)
+  %_45.i = getelementptr inbounds nuw i32, ptr %values.0, i64 %i.sroa.0.0.i, !dbg !239
+  %_45.val.i = load i32, ptr %_45.i, align 4, !dbg !248, !noundef !20
+    #dbg_value(ptr poison, !277, !DIExpression(), !284)
+    #dbg_value(i32 %acc.sroa.0.0.i, !275, !DIExpression(), !284)
+    #dbg_value(i32 %acc.sroa.0.0.i, !267, !DIExpression(), !285)
+    #dbg_value(i32 %acc.sroa.0.0.i, !258, !DIExpression(), !286)
+    #dbg_value(ptr poison, !276, !DIExpression(), !284)
+    #dbg_value(ptr poison, !268, !DIExpression(), !285)
+    #dbg_value(i32 %_45.val.i, !259, !DIExpression(), !286)
+  %_5.0.i.i = add i32 %_45.val.i, %acc.sroa.0.0.i, !dbg !249
+    #dbg_value(i32 %_5.0.i.i, !194, !DIExpression(), !238)
+  %_27.i = add nuw nsw i64 %i.sroa.0.0.i, 1, !dbg !287
+    #dbg_value(i64 %_27.i, !196, !DIExpression(), !282)
+    #dbg_value(i64 %_27.i, !218, !DIExpression(), !219)
+  %_28.i = icmp eq i64 %_27.i, %values.1, !dbg !237
+  br i1 %_28.i, label %"_ZN91_$LT$core..slice..iter..Iter$LT$T$GT$$u20$as$u20$core..iter..traits..iterator..Iterator$GT$4fold17h2b0920ce6c2f14faE.exit", label %bb8.i, !dbg !237, !llvm.loop !288
+
+"_ZN91_$LT$core..slice..iter..Iter$LT$T$GT$$u20$as$u20$core..iter..traits..iterator..Iterator$GT$4fold17h2b0920ce6c2f14faE.exit": ; preds = %bb8.i, %middle.block, %start
+  %_0.sroa.0.0.i = phi i32 [ 0, %start ], [ %6, %middle.block ], [ %_5.0.i.i, %bb8.i ], !dbg !207
+  ret i32 %_0.sroa.0.0.i, !dbg !289
+}
+
+; core::option::unwrap_failed
+; Function Attrs: cold noinline noreturn nonlazybind uwtable
+declare void @_RNvNtCsgEmfK2I1SDS_4core6option13unwrap_failed(ptr noalias noundef readonly align 8 captures(address, read_provenance) dereferenceable(24)) unnamed_addr #3
+
+; core::panicking::panic_bounds_check
+; Function Attrs: cold minsize noinline noreturn nonlazybind optsize uwtable
+declare void @_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check(i64 noundef, i64 noundef, ptr noalias noundef readonly align 8 captures(address, read_provenance) dereferenceable(24)) unnamed_addr #4
+
+; Function Attrs: nounwind nonlazybind uwtable
+declare noundef range(i32 0, 10) i32 @rust_eh_personality(i32 noundef, i32 noundef, i64 noundef, ptr noundef, ptr noundef) unnamed_addr #5
+
+; Function Attrs: nocallback nofree nosync nounwind speculatable willreturn memory(none)
+declare i32 @llvm.vector.reduce.add.v4i32(<4 x i32>) #6
+
+attributes #0 = { nonlazybind uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #1 = { noinline nonlazybind uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #2 = { nofree noinline norecurse nosync nounwind nonlazybind memory(argmem: read) uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #3 = { cold noinline noreturn nonlazybind uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #4 = { cold minsize noinline noreturn nonlazybind optsize uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #5 = { nounwind nonlazybind uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #6 = { nocallback nofree nosync nounwind speculatable willreturn memory(none) }
+attributes #7 = { noinline }
+attributes #8 = { noinline noreturn }
+
+!llvm.module.flags = !{!0, !1, !2, !3}
+!llvm.ident = !{!4}
+!llvm.dbg.cu = !{!5}
+
+!0 = !{i32 8, !"PIC Level", i32 2}
+!1 = !{i32 2, !"RtLibUseGOT", i32 1}
+!2 = !{i32 7, !"Dwarf Version", i32 4}
+!3 = !{i32 2, !"Debug Info Version", i32 3}
+!4 = !{!"rustc version 1.95.0 (59807616e 2026-04-14)"}
+!5 = distinct !DICompileUnit(language: DW_LANG_Rust, file: !6, producer: "clang LLVM (rustc version 1.95.0 (59807616e 2026-04-14))", isOptimized: true, runtimeVersion: 0, emissionKind: FullDebug, splitDebugInlining: false, nameTableKind: None)
+!6 = !DIFile(filename: "src/lib.rs/@/playground.d3871e328ee0cb96-cgu.0", directory: "/tmp/asm")
+!7 = distinct !DISubprogram(name: "checked_sum", linkageName: "_ZN10playground11checked_sum17h9f20f835d8d96096E", scope: !9, file: !8, line: 15, type: !10, scopeLine: 15, flags: DIFlagPrototyped, spFlags: DISPFlagDefinition | DISPFlagOptimized, unit: !5, templateParams: !20, retainedNodes: !21)
+!8 = !DIFile(filename: "src/lib.rs", directory: "/tmp/asm", checksumkind: CSK_MD5, checksum: "b355f886a8c52c85cef614d8df8448a3")
+!9 = !DINamespace(name: "playground", scope: null)
+!10 = !DISubroutineType(types: !11)
+!11 = !{!12, !13}
+!12 = !DIBasicType(name: "u32", size: 32, encoding: DW_ATE_unsigned)
+!13 = !DICompositeType(tag: DW_TAG_structure_type, name: "&[u32]", file: !14, size: 128, align: 64, elements: !15, templateParams: !20, identifier: "467923a08fc75332ca53cdba7e9dfffc")
+!14 = !DIFile(filename: "<unknown>", directory: "")
+!15 = !{!16, !18}
+!16 = !DIDerivedType(tag: DW_TAG_member, name: "data_ptr", scope: !13, file: !14, baseType: !17, size: 64, align: 64)
+!17 = !DIDerivedType(tag: DW_TAG_pointer_type, baseType: !12, size: 64, align: 64, dwarfAddressSpace: 0)
+!18 = !DIDerivedType(tag: DW_TAG_member, name: "length", scope: !13, file: !14, baseType: !19, size: 64, align: 64, offset: 64)
+!19 = !DIBasicType(name: "usize", size: 64, encoding: DW_ATE_unsigned)
+!20 = !{}
+!21 = !{!22}
+!22 = !DILocalVariable(name: "values", arg: 1, scope: !7, file: !8, line: 15, type: !13)
+!23 = !DILocation(line: 0, scope: !7)
+!24 = !DILocation(line: 16, column: 5, scope: !7)
+!25 = !DILocation(line: 16, column: 19, scope: !7)
+!26 = !DILocation(line: 17, column: 2, scope: !7)
+!27 = distinct !DISubprogram(name: "first_or_zero", linkageName: "_ZN10playground13first_or_zero17h693ee13ff9fbfb7cE", scope: !9, file: !8, line: 11, type: !10, scopeLine: 11, flags: DIFlagPrototyped, spFlags: DISPFlagDefinition | DISPFlagOptimized, unit: !5, templateParams: !20, retainedNodes: !28)
+!28 = !{!29}
+!29 = !DILocalVariable(name: "values", arg: 1, scope: !27, file: !8, line: 11, type: !13)
+!30 = !DILocation(line: 0, scope: !27)
+!31 = !DILocalVariable(name: "self", arg: 1, scope: !32, file: !33, line: 155, type: !13)
+!32 = distinct !DISubprogram(name: "first<u32>", linkageName: "_ZN4core5slice29_$LT$impl$u20$$u5b$T$u5d$$GT$5first17h4a68f28247b0298fE", scope: !34, file: !33, line: 155, type: !37, scopeLine: 155, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !5, templateParams: !58, retainedNodes: !55)
+!33 = !DIFile(filename: "library/core/src/slice/mod.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "b606e5d97bff599edd0dcbc6067a14f1")
+!34 = !DINamespace(name: "{impl#0}", scope: !35)
+!35 = !DINamespace(name: "slice", scope: !36)
+!36 = !DINamespace(name: "core", scope: null)
+!37 = !DISubroutineType(types: !38)
+!38 = !{!39, !13}
+!39 = !DICompositeType(tag: DW_TAG_structure_type, name: "Option<&u32>", scope: !40, file: !14, size: 64, align: 64, flags: DIFlagPublic, elements: !41, templateParams: !20, identifier: "2b65bbeaa590675d42e3ea3d17db7bad")
+!40 = !DINamespace(name: "option", scope: !36)
+!41 = !{!42}
+!42 = !DICompositeType(tag: DW_TAG_variant_part, scope: !39, file: !14, size: 64, align: 64, elements: !43, templateParams: !20, identifier: "49fea7dc8860a070ee3b3f49178b222b", discriminator: !53)
+!43 = !{!44, !49}
+!44 = !DIDerivedType(tag: DW_TAG_member, name: "None", scope: !42, file: !14, baseType: !45, size: 64, align: 64, extraData: i64 0)
+!45 = !DICompositeType(tag: DW_TAG_structure_type, name: "None", scope: !39, file: !14, size: 64, align: 64, flags: DIFlagPublic, elements: !20, templateParams: !46, identifier: "8acd7c481925dee5a208465581796e97")
+!46 = !{!47}
+!47 = !DITemplateTypeParameter(name: "T", type: !48)
+!48 = !DIDerivedType(tag: DW_TAG_pointer_type, name: "&u32", baseType: !12, size: 64, align: 64, dwarfAddressSpace: 0)
+!49 = !DIDerivedType(tag: DW_TAG_member, name: "Some", scope: !42, file: !14, baseType: !50, size: 64, align: 64)
+!50 = !DICompositeType(tag: DW_TAG_structure_type, name: "Some", scope: !39, file: !14, size: 64, align: 64, flags: DIFlagPublic, elements: !51, templateParams: !46, identifier: "8fa0ac4674d3343cead75db7c4a4f9fb")
+!51 = !{!52}
+!52 = !DIDerivedType(tag: DW_TAG_member, name: "__0", scope: !50, file: !14, baseType: !48, size: 64, align: 64, flags: DIFlagPublic)
+!53 = !DIDerivedType(tag: DW_TAG_member, scope: !39, file: !14, baseType: !54, size: 64, align: 64, flags: DIFlagArtificial)
+!54 = !DIBasicType(name: "u64", size: 64, encoding: DW_ATE_unsigned)
+!55 = !{!31, !56}
+!56 = !DILocalVariable(name: "first", scope: !57, file: !33, line: 156, type: !48, align: 64)
+!57 = distinct !DILexicalBlock(scope: !32, file: !33, line: 156, column: 35)
+!58 = !{!59}
+!59 = !DITemplateTypeParameter(name: "T", type: !12)
+!60 = !DILocation(line: 0, scope: !32, inlinedAt: !61)
+!61 = !DILocation(line: 12, column: 12, scope: !27)
+!62 = !DILocation(line: 156, column: 16, scope: !57, inlinedAt: !61)
+!63 = !{!"branch_weights", !"expected", i32 1, i32 2000}
+!64 = !DILocalVariable(name: "self", arg: 1, scope: !65, file: !66, line: 2119, type: !39)
+!65 = distinct !DISubprogram(name: "copied<u32>", linkageName: "_ZN4core6option19Option$LT$$RF$T$GT$6copied17he1e3e341e515c661E", scope: !39, file: !66, line: 2119, type: !67, scopeLine: 2119, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !5, templateParams: !58, declaration: !80, retainedNodes: !81)
+!66 = !DIFile(filename: "library/core/src/option.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "3f12dd837a95e960b90752f70f5ddc0e")
+!67 = !DISubroutineType(types: !68)
+!68 = !{!69, !39}
+!69 = !DICompositeType(tag: DW_TAG_structure_type, name: "Option<u32>", scope: !40, file: !14, size: 64, align: 32, flags: DIFlagPublic, elements: !70, templateParams: !20, identifier: "6f9cf68b0721c153842fac1aca06aca0")
+!70 = !{!71}
+!71 = !DICompositeType(tag: DW_TAG_variant_part, scope: !69, file: !14, size: 64, align: 32, elements: !72, templateParams: !20, identifier: "52547bc7e29ba4c3a424c45f3b6ad9d5", discriminator: !79)
+!72 = !{!73, !75}
+!73 = !DIDerivedType(tag: DW_TAG_member, name: "None", scope: !71, file: !14, baseType: !74, size: 64, align: 32, extraData: i32 0)
+!74 = !DICompositeType(tag: DW_TAG_structure_type, name: "None", scope: !69, file: !14, size: 64, align: 32, flags: DIFlagPublic, elements: !20, templateParams: !58, identifier: "2d35c67b0b954dbe72d9ec7c545db516")
+!75 = !DIDerivedType(tag: DW_TAG_member, name: "Some", scope: !71, file: !14, baseType: !76, size: 64, align: 32, extraData: i32 1)
+!76 = !DICompositeType(tag: DW_TAG_structure_type, name: "Some", scope: !69, file: !14, size: 64, align: 32, flags: DIFlagPublic, elements: !77, templateParams: !58, identifier: "8398ebe1588ef464fb4c1616d021a5e8")
+!77 = !{!78}
+!78 = !DIDerivedType(tag: DW_TAG_member, name: "__0", scope: !76, file: !14, baseType: !12, size: 32, align: 32, offset: 32, flags: DIFlagPublic)
+!79 = !DIDerivedType(tag: DW_TAG_member, scope: !69, file: !14, baseType: !12, size: 32, align: 32, flags: DIFlagArtificial)
+!80 = !DISubprogram(name: "copied<u32>", linkageName: "_ZN4core6option19Option$LT$$RF$T$GT$6copied17he1e3e341e515c661E", scope: !39, file: !66, line: 2119, type: !67, scopeLine: 2119, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagOptimized, templateParams: !58)
+!81 = !{!64, !82}
+!82 = !DILocalVariable(name: "v", scope: !83, file: !66, line: 2126, type: !12, align: 32)
+!83 = distinct !DILexicalBlock(scope: !65, file: !66, line: 2126, column: 13)
+!84 = !DILocation(line: 0, scope: !65, inlinedAt: !85)
+!85 = !DILocation(line: 12, column: 20, scope: !27)
+!86 = !DILocalVariable(name: "self", arg: 1, scope: !87, file: !66, line: 1013, type: !69)
+!87 = distinct !DISubprogram(name: "unwrap<u32>", linkageName: "_ZN4core6option15Option$LT$T$GT$6unwrap17h477af2000e260832E", scope: !69, file: !66, line: 1013, type: !88, scopeLine: 1013, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !5, templateParams: !58, declaration: !120, retainedNodes: !121)
+!88 = !DISubroutineType(types: !89)
+!89 = !{!12, !69, !90}
+!90 = !DIDerivedType(tag: DW_TAG_pointer_type, name: "&core::panic::location::Location", baseType: !91, size: 64, align: 64, dwarfAddressSpace: 0)
+!91 = !DICompositeType(tag: DW_TAG_structure_type, name: "Location", scope: !92, file: !14, size: 192, align: 64, flags: DIFlagPublic, elements: !94, templateParams: !20, identifier: "c23b7de1297af8f4a89df3da49f37ee6")
+!92 = !DINamespace(name: "location", scope: !93)
+!93 = !DINamespace(name: "panic", scope: !36)
+!94 = !{!95, !109, !110, !111}
+!95 = !DIDerivedType(tag: DW_TAG_member, name: "filename", scope: !91, file: !14, baseType: !96, size: 128, align: 64, flags: DIFlagPrivate)
+!96 = !DICompositeType(tag: DW_TAG_structure_type, name: "NonNull<str>", scope: !97, file: !14, size: 128, align: 64, flags: DIFlagPublic, elements: !99, templateParams: !107, identifier: "cc7383b2ce73f6838bfb6ae828fb6ae6")
+!97 = !DINamespace(name: "non_null", scope: !98)
+!98 = !DINamespace(name: "ptr", scope: !36)
+!99 = !{!100}
+!100 = !DIDerivedType(tag: DW_TAG_member, name: "pointer", scope: !96, file: !14, baseType: !101, size: 128, align: 64, flags: DIFlagPrivate)
+!101 = !DICompositeType(tag: DW_TAG_structure_type, name: "*const str", file: !14, size: 128, align: 64, elements: !102, templateParams: !20, identifier: "238a44609877474087c05adf26cd41fa")
+!102 = !{!103, !106}
+!103 = !DIDerivedType(tag: DW_TAG_member, name: "data_ptr", scope: !101, file: !14, baseType: !104, size: 64, align: 64)
+!104 = !DIDerivedType(tag: DW_TAG_pointer_type, baseType: !105, size: 64, align: 64, dwarfAddressSpace: 0)
+!105 = !DIBasicType(name: "u8", size: 8, encoding: DW_ATE_unsigned)
+!106 = !DIDerivedType(tag: DW_TAG_member, name: "length", scope: !101, file: !14, baseType: !19, size: 64, align: 64, offset: 64)
+!107 = !{!108}
+!108 = !DITemplateTypeParameter(name: "T", type: !105)
+!109 = !DIDerivedType(tag: DW_TAG_member, name: "line", scope: !91, file: !14, baseType: !12, size: 32, align: 32, offset: 128, flags: DIFlagPrivate)
+!110 = !DIDerivedType(tag: DW_TAG_member, name: "col", scope: !91, file: !14, baseType: !12, size: 32, align: 32, offset: 160, flags: DIFlagPrivate)
+!111 = !DIDerivedType(tag: DW_TAG_member, name: "_filename", scope: !91, file: !14, baseType: !112, align: 8, offset: 192, flags: DIFlagPrivate)
+!112 = !DICompositeType(tag: DW_TAG_structure_type, name: "PhantomData<&str>", scope: !113, file: !14, align: 8, flags: DIFlagPublic, elements: !20, templateParams: !114, identifier: "e16ac355808b6aef9fb50b5f125fee05")
+!113 = !DINamespace(name: "marker", scope: !36)
+!114 = !{!115}
+!115 = !DITemplateTypeParameter(name: "T", type: !116)
+!116 = !DICompositeType(tag: DW_TAG_structure_type, name: "&str", file: !14, size: 128, align: 64, elements: !117, templateParams: !20, identifier: "9277eecd40495f85161460476aacc992")
+!117 = !{!118, !119}
+!118 = !DIDerivedType(tag: DW_TAG_member, name: "data_ptr", scope: !116, file: !14, baseType: !104, size: 64, align: 64)
+!119 = !DIDerivedType(tag: DW_TAG_member, name: "length", scope: !116, file: !14, baseType: !19, size: 64, align: 64, offset: 64)
+!120 = !DISubprogram(name: "unwrap<u32>", linkageName: "_ZN4core6option15Option$LT$T$GT$6unwrap17h477af2000e260832E", scope: !69, file: !66, line: 1013, type: !88, scopeLine: 1013, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagOptimized, templateParams: !58)
+!121 = !{!86, !122}
+!122 = !DILocalVariable(name: "val", scope: !123, file: !66, line: 1015, type: !12, align: 32)
+!123 = distinct !DILexicalBlock(scope: !87, file: !66, line: 1015, column: 13)
+!124 = !DILocation(line: 0, scope: !87, inlinedAt: !125)
+!125 = !DILocation(line: 12, column: 29, scope: !27)
+!126 = !DILocation(line: 1016, column: 21, scope: !87, inlinedAt: !125)
+!127 = !DILocation(line: 0, scope: !57, inlinedAt: !61)
+!128 = !DILocation(line: 2126, column: 19, scope: !65, inlinedAt: !85)
+!129 = !DILocation(line: 13, column: 2, scope: !27)
+!130 = distinct !DISubprogram(name: "get", linkageName: "_ZN10playground3get17h53dae1e8bd5cfe57E", scope: !9, file: !8, line: 7, type: !131, scopeLine: 7, flags: DIFlagPrototyped, spFlags: DISPFlagDefinition | DISPFlagOptimized, unit: !5, templateParams: !20, retainedNodes: !133)
+!131 = !DISubroutineType(types: !132)
+!132 = !{!12, !13, !19}
+!133 = !{!134, !135}
+!134 = !DILocalVariable(name: "values", arg: 1, scope: !130, file: !8, line: 7, type: !13)
+!135 = !DILocalVariable(name: "index", arg: 2, scope: !130, file: !8, line: 7, type: !19)
+!136 = !DILocation(line: 0, scope: !130)
+!137 = !DILocation(line: 8, column: 5, scope: !130)
+!138 = !DILocation(line: 9, column: 2, scope: !130)
+!139 = distinct !DISubprogram(name: "sum", linkageName: "_ZN10playground3sum17h120ca45857a523a1E", scope: !9, file: !8, line: 2, type: !10, scopeLine: 2, flags: DIFlagPrototyped, spFlags: DISPFlagDefinition | DISPFlagOptimized, unit: !5, templateParams: !20, retainedNodes: !140)
+!140 = !{!141}
+!141 = !DILocalVariable(name: "values", arg: 1, scope: !139, file: !8, line: 2, type: !13)
+!142 = !DILocation(line: 0, scope: !139)
+!143 = !DILocalVariable(name: "self", arg: 1, scope: !144, file: !145, line: 3653, type: !152)
+!144 = distinct !DISubprogram(name: "sum<core::slice::iter::Iter<u32>, u32>", linkageName: "_ZN4core4iter6traits8iterator8Iterator3sum17hef52314f9fea7af2E", scope: !146, file: !145, line: 3653, type: !150, scopeLine: 3653, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !5, templateParams: !164, retainedNodes: !163)
+!145 = !DIFile(filename: "library/core/src/iter/traits/iterator.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "03f316921fc0b2bb7786dd4fa41456f9")
+!146 = !DINamespace(name: "Iterator", scope: !147)
+!147 = !DINamespace(name: "iterator", scope: !148)
+!148 = !DINamespace(name: "traits", scope: !149)
+!149 = !DINamespace(name: "iter", scope: !36)
+!150 = !DISubroutineType(types: !151)
+!151 = !{!12, !152}
+!152 = !DICompositeType(tag: DW_TAG_structure_type, name: "Iter<u32>", scope: !153, file: !14, size: 128, align: 64, flags: DIFlagPublic, elements: !154, templateParams: !58, identifier: "50ba7b6ddd9af9748916ca36c92e17c8")
+!153 = !DINamespace(name: "iter", scope: !35)
+!154 = !{!155, !160, !161}
+!155 = !DIDerivedType(tag: DW_TAG_member, name: "ptr", scope: !152, file: !14, baseType: !156, size: 64, align: 64, flags: DIFlagPrivate)
+!156 = !DICompositeType(tag: DW_TAG_structure_type, name: "NonNull<u32>", scope: !97, file: !14, size: 64, align: 64, flags: DIFlagPublic, elements: !157, templateParams: !58, identifier: "fb1048154646a3688da93e9784e14c26")
+!157 = !{!158}
+!158 = !DIDerivedType(tag: DW_TAG_member, name: "pointer", scope: !156, file: !14, baseType: !159, size: 64, align: 64, flags: DIFlagPrivate)
+!159 = !DIDerivedType(tag: DW_TAG_pointer_type, name: "*const u32", baseType: !12, size: 64, align: 64, dwarfAddressSpace: 0)
+!160 = !DIDerivedType(tag: DW_TAG_member, name: "end_or_len", scope: !152, file: !14, baseType: !159, size: 64, align: 64, offset: 64, flags: DIFlagPrivate)
+!161 = !DIDerivedType(tag: DW_TAG_member, name: "_marker", scope: !152, file: !14, baseType: !162, align: 8, offset: 128, flags: DIFlagPrivate)
+!162 = !DICompositeType(tag: DW_TAG_structure_type, name: "PhantomData<&u32>", scope: !113, file: !14, align: 8, flags: DIFlagPublic, elements: !20, templateParams: !46, identifier: "294f9296864bb29fb2f3fce45cf10fbe")
+!163 = !{!143}
+!164 = !{!165, !166}
+!165 = !DITemplateTypeParameter(name: "Self", type: !152)
+!166 = !DITemplateTypeParameter(name: "S", type: !12)
+!167 = !DILocation(line: 0, scope: !144, inlinedAt: !168)
+!168 = !DILocation(line: 3, column: 19, scope: !139)
+!169 = !DILocalVariable(name: "iter", arg: 1, scope: !170, file: !171, line: 71, type: !152)
+!170 = distinct !DISubprogram(name: "sum<core::slice::iter::Iter<u32>>", linkageName: "_ZN69_$LT$u32$u20$as$u20$core..iter..traits..accum..Sum$LT$$RF$u32$GT$$GT$3sum17hcb4828661486477fE", scope: !172, file: !171, line: 71, type: !150, scopeLine: 71, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !5, templateParams: !175, retainedNodes: !174)
+!171 = !DIFile(filename: "library/core/src/iter/traits/accum.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "29c702feed2d279674f04740ce9fc327")
+!172 = !DINamespace(name: "{impl#38}", scope: !173)
+!173 = !DINamespace(name: "accum", scope: !148)
+!174 = !{!169}
+!175 = !{!176}
+!176 = !DITemplateTypeParameter(name: "I", type: !152)
+!177 = !DILocation(line: 0, scope: !170, inlinedAt: !178)
+!178 = !DILocation(line: 3658, column: 9, scope: !144, inlinedAt: !168)
+!179 = !DILocalVariable(name: "self", arg: 1, scope: !180, file: !181, line: 259, type: !152)
+!180 = distinct !DISubprogram(name: "fold<u32, u32, core::iter::traits::accum::{impl#38}::sum::{closure_env#0}<core::slice::iter::Iter<u32>>>", linkageName: "_ZN91_$LT$core..slice..iter..Iter$LT$T$GT$$u20$as$u20$core..iter..traits..iterator..Iterator$GT$4fold17h2b0920ce6c2f14faE", scope: !182, file: !181, line: 259, type: !183, scopeLine: 259, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !5, templateParams: !204, retainedNodes: !187)
+!181 = !DIFile(filename: "library/core/src/slice/iter/macros.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "87d1f0c2746f51593d75ddf4c9271f14")
+!182 = !DINamespace(name: "{impl#171}", scope: !153)
+!183 = !DISubroutineType(types: !184)
+!184 = !{!12, !152, !12, !185}
+!185 = !DICompositeType(tag: DW_TAG_structure_type, name: "{closure_env#0}<core::slice::iter::Iter<u32>>", scope: !186, file: !14, align: 8, elements: !20, identifier: "670b11eb528bbf97ad3ffb38ba65345a")
+!186 = !DINamespace(name: "sum", scope: !172)
+!187 = !{!179, !188, !189, !190, !192, !194, !196, !198, !200, !202}
+!188 = !DILocalVariable(name: "init", arg: 2, scope: !180, file: !181, line: 259, type: !12)
+!189 = !DILocalVariable(name: "f", arg: 3, scope: !180, file: !181, line: 259, type: !185)
+!190 = !DILocalVariable(name: "len", scope: !191, file: !181, line: 29, type: !19, align: 64)
+!191 = distinct !DILexicalBlock(scope: !180, file: !181, line: 29, column: 13)
+!192 = !DILocalVariable(name: "end", scope: !193, file: !181, line: 33, type: !156, align: 64)
+!193 = distinct !DILexicalBlock(scope: !180, file: !181, line: 33, column: 13)
+!194 = !DILocalVariable(name: "acc", scope: !195, file: !181, line: 273, type: !12, align: 32)
+!195 = distinct !DILexicalBlock(scope: !180, file: !181, line: 273, column: 17)
+!196 = !DILocalVariable(name: "i", scope: !197, file: !181, line: 274, type: !19, align: 64)
+!197 = distinct !DILexicalBlock(scope: !195, file: !181, line: 274, column: 17)
+!198 = !DILocalVariable(name: "len", scope: !199, file: !181, line: 275, type: !19, align: 64)
+!199 = distinct !DILexicalBlock(scope: !197, file: !181, line: 275, column: 17)
+!200 = !DILocalVariable(name: "len", scope: !201, file: !181, line: 29, type: !19, align: 64)
+!201 = distinct !DILexicalBlock(scope: !197, file: !181, line: 29, column: 13)
+!202 = !DILocalVariable(name: "end", scope: !203, file: !181, line: 33, type: !156, align: 64)
+!203 = distinct !DILexicalBlock(scope: !197, file: !181, line: 33, column: 13)
+!204 = !{!59, !205, !206}
+!205 = !DITemplateTypeParameter(name: "B", type: !12)
+!206 = !DITemplateTypeParameter(name: "F", type: !185)
+!207 = !DILocation(line: 0, scope: !180, inlinedAt: !208)
+!208 = distinct !DILocation(line: 72, column: 22, scope: !170, inlinedAt: !178)
+!209 = !DILocation(line: 259, column: 42, scope: !180, inlinedAt: !208)
+!210 = !DILocalVariable(name: "rhs", scope: !211, file: !212, line: 832, type: !19, align: 64)
+!211 = distinct !DISubprogram(name: "unchecked_add", linkageName: "_RNvMs9_NtCsgEmfK2I1SDS_4core3numj13unchecked_add", scope: !213, file: !212, line: 832, type: !215, scopeLine: 832, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !5, templateParams: !20, retainedNodes: !217)
+!212 = !DIFile(filename: "library/core/src/num/uint_macros.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "de2f29a3485fc28d710ba6c3062e6efa")
+!213 = !DINamespace(name: "{impl#11}", scope: !214)
+!214 = !DINamespace(name: "num", scope: !36)
+!215 = !DISubroutineType(types: !216)
+!216 = !{!19, !19, !19, !90}
+!217 = !{!218, !210}
+!218 = !DILocalVariable(name: "self", arg: 1, scope: !211, file: !212, line: 832, type: !19)
+!219 = !DILocation(line: 0, scope: !211, inlinedAt: !220)
+!220 = distinct !DILocation(line: 283, column: 36, scope: !199, inlinedAt: !208)
+!221 = !DILocation(line: 0, scope: !193, inlinedAt: !208)
+!222 = !DILocalVariable(name: "self", arg: 1, scope: !223, file: !224, line: 1719, type: !229)
+!223 = distinct !DISubprogram(name: "eq<u32>", linkageName: "_ZN78_$LT$core..ptr..non_null..NonNull$LT$T$GT$$u20$as$u20$core..cmp..PartialEq$GT$2eq17h19553650368389c0E", scope: !225, file: !224, line: 1719, type: !226, scopeLine: 1719, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !5, templateParams: !58, retainedNodes: !230)
+!224 = !DIFile(filename: "library/core/src/ptr/non_null.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "e5222a5160165ba1cd09076ad7a11733")
+!225 = !DINamespace(name: "{impl#16}", scope: !97)
+!226 = !DISubroutineType(types: !227)
+!227 = !{!228, !229, !229}
+!228 = !DIBasicType(name: "bool", size: 8, encoding: DW_ATE_boolean)
+!229 = !DIDerivedType(tag: DW_TAG_pointer_type, name: "&core::ptr::non_null::NonNull<u32>", baseType: !156, size: 64, align: 64, dwarfAddressSpace: 0)
+!230 = !{!222, !231}
+!231 = !DILocalVariable(name: "other", arg: 2, scope: !223, file: !224, line: 1719, type: !229)
+!232 = !DILocation(line: 1719, column: 11, scope: !223, inlinedAt: !233)
+!233 = distinct !DILocation(line: 44, column: 20, scope: !193, inlinedAt: !208)
+!234 = !DILocation(line: 1719, column: 18, scope: !223, inlinedAt: !233)
+!235 = !DILocation(line: 1720, column: 9, scope: !223, inlinedAt: !233)
+!236 = !DILocation(line: 25, column: 86, scope: !180, inlinedAt: !208)
+!237 = !DILocation(line: 284, column: 24, scope: !199, inlinedAt: !208)
+!238 = !DILocation(line: 0, scope: !195, inlinedAt: !208)
+!239 = !DILocation(line: 659, column: 28, scope: !240, inlinedAt: !247)
+!240 = distinct !DISubprogram(name: "add<u32>", linkageName: "_ZN4core3ptr8non_null16NonNull$LT$T$GT$3add17h8016823a93315c9cE", scope: !156, file: !224, line: 651, type: !241, scopeLine: 651, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !5, templateParams: !58, declaration: !243, retainedNodes: !244)
+!241 = !DISubroutineType(types: !242)
+!242 = !{!156, !156, !19}
+!243 = !DISubprogram(name: "add<u32>", linkageName: "_ZN4core3ptr8non_null16NonNull$LT$T$GT$3add17h8016823a93315c9cE", scope: !156, file: !224, line: 651, type: !241, scopeLine: 651, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagOptimized, templateParams: !58)
+!244 = !{!245, !246}
+!245 = !DILocalVariable(name: "self", arg: 1, scope: !240, file: !224, line: 651, type: !156)
+!246 = !DILocalVariable(name: "count", arg: 2, scope: !240, file: !224, line: 651, type: !19)
+!247 = distinct !DILocation(line: 279, column: 67, scope: !199, inlinedAt: !208)
+!248 = !DILocation(line: 279, column: 27, scope: !199, inlinedAt: !208)
+!249 = !DILocation(line: 105, column: 45, scope: !250, inlinedAt: !260)
+!250 = distinct !DISubprogram(name: "add", linkageName: "_RNvXs1_NtNtCsgEmfK2I1SDS_4core3ops5arithmNtB5_3Add3add", scope: !252, file: !251, line: 105, type: !255, scopeLine: 105, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !5, templateParams: !20, retainedNodes: !257)
+!251 = !DIFile(filename: "library/core/src/ops/arith.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "82a843efd6957c9872d7564e4a5df756")
+!252 = !DINamespace(name: "{impl#3}", scope: !253)
+!253 = !DINamespace(name: "arith", scope: !254)
+!254 = !DINamespace(name: "ops", scope: !36)
+!255 = !DISubroutineType(types: !256)
+!256 = !{!12, !12, !12, !90}
+!257 = !{!258, !259}
+!258 = !DILocalVariable(name: "self", arg: 1, scope: !250, file: !251, line: 105, type: !12)
+!259 = !DILocalVariable(name: "other", arg: 2, scope: !250, file: !251, line: 105, type: !12)
+!260 = distinct !DILocation(line: 39, column: 17, scope: !261, inlinedAt: !269)
+!261 = distinct !DISubprogram(name: "add", linkageName: "_RNvXso_NtNtCsgEmfK2I1SDS_4core3ops5arithmINtB5_3AddRmE3add", scope: !263, file: !262, line: 38, type: !264, scopeLine: 38, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !5, templateParams: !20, retainedNodes: !266)
+!262 = !DIFile(filename: "library/core/src/internal_macros.rs", directory: "/rustc/59807616e1fa2540724bfbac14d7976d7e4a3860", checksumkind: CSK_MD5, checksum: "9ec673d06a3eb53c47178443a566ec32")
+!263 = !DINamespace(name: "{impl#26}", scope: !253)
+!264 = !DISubroutineType(types: !265)
+!265 = !{!12, !12, !48, !90}
+!266 = !{!267, !268}
+!267 = !DILocalVariable(name: "self", arg: 1, scope: !261, file: !262, line: 38, type: !12)
+!268 = !DILocalVariable(name: "other", arg: 2, scope: !261, file: !262, line: 38, type: !48)
+!269 = distinct !DILocation(line: 75, column: 28, scope: !270, inlinedAt: !278)
+!270 = distinct !DISubprogram(name: "{closure#0}<core::slice::iter::Iter<u32>>", linkageName: "_ZN69_$LT$u32$u20$as$u20$core..iter..traits..accum..Sum$LT$$RF$u32$GT$$GT$3sum28_$u7b$$u7b$closure$u7d$$u7d$17hb87f1733c1554ea7E", scope: !186, file: !171, line: 75, type: !271, scopeLine: 75, flags: DIFlagPrototyped, spFlags: DISPFlagLocalToUnit | DISPFlagDefinition | DISPFlagOptimized, unit: !5, templateParams: !175, retainedNodes: !274)
+!271 = !DISubroutineType(types: !272)
+!272 = !{!12, !273, !12, !48}
+!273 = !DIDerivedType(tag: DW_TAG_pointer_type, name: "&mut core::iter::traits::accum::{impl#38}::sum::{closure_env#0}<core::slice::iter::Iter<u32>>", baseType: !185, size: 64, align: 64, dwarfAddressSpace: 0)
+!274 = !{!275, !276, !277}
+!275 = !DILocalVariable(name: "a", arg: 2, scope: !270, file: !171, line: 75, type: !12)
+!276 = !DILocalVariable(name: "b", arg: 3, scope: !270, file: !171, line: 75, type: !48)
+!277 = !DILocalVariable(arg: 1, scope: !270, file: !171, line: 75, type: !273)
+!278 = distinct !DILocation(line: 279, column: 27, scope: !199, inlinedAt: !208)
+!279 = distinct !{!279, !280, !281}
+!280 = !{!"llvm.loop.isvectorized", i32 1}
+!281 = !{!"llvm.loop.unroll.runtime.disable"}
+!282 = !DILocation(line: 0, scope: !197, inlinedAt: !208)
+!283 = !DILocation(line: 0, scope: !240, inlinedAt: !247)
+!284 = !DILocation(line: 0, scope: !270, inlinedAt: !278)
+!285 = !DILocation(line: 0, scope: !261, inlinedAt: !269)
+!286 = !DILocation(line: 0, scope: !250, inlinedAt: !260)
+!287 = !DILocation(line: 844, column: 17, scope: !211, inlinedAt: !220)
+!288 = distinct !{!288, !281, !280}
+!289 = !DILocation(line: 4, column: 2, scope: !139)
@@ -0,0 +1,147 @@
+; ModuleID = 'playground.d3871e328ee0cb96-cgu.0'
+source_filename = "playground.d3871e328ee0cb96-cgu.0"
+target datalayout = "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-i128:128-f80:128-n8:16:32:64-S128"
+target triple = "x86_64-unknown-linux-gnu"
+
+@alloc_466e6e10d4d5a95679ab35d1156aa54e = private unnamed_addr constant [11 x i8] c"src/lib.rs\00", align 1
+@alloc_7a949f35e6ddbe66e80e80d2b5e21e32 = private unnamed_addr constant <{ ptr, [16 x i8] }> <{ ptr @alloc_466e6e10d4d5a95679ab35d1156aa54e, [16 x i8] c"\0A\00\00\00\00\00\00\00\0C\00\00\00\1D\00\00\00" }>, align 8
+@alloc_0a8bf98f3b82fab2e8d83de81afa38a4 = private unnamed_addr constant <{ ptr, [16 x i8] }> <{ ptr @alloc_466e6e10d4d5a95679ab35d1156aa54e, [16 x i8] c"\0A\00\00\00\00\00\00\00\08\00\00\00\05\00\00\00" }>, align 8
+
+; playground::checked_sum
+; Function Attrs: nonlazybind uwtable
+define noundef i32 @_ZN10playground11checked_sum17h9f20f835d8d96096E(ptr noalias noundef nonnull readonly align 4 captures(none) %values.0, i64 noundef range(i64 0, 2305843009213693952) %values.1) unnamed_addr #0 {
+start:
+; call playground::sum
+  %_2 = tail call noundef i32 @_ZN10playground3sum17h120ca45857a523a1E(ptr noalias noundef nonnull readonly align 4 captures(address, read_provenance) %values.0, i64 noundef %values.1) #7
+; call playground::get
+  %_3 = tail call noundef i32 @_ZN10playground3get17h53dae1e8bd5cfe57E(ptr noalias noundef nonnull readonly align 4 captures(address, read_provenance) %values.0, i64 noundef %values.1, i64 noundef 0) #7
+  %_0 = add i32 %_3, %_2
+  ret i32 %_0
+}
+
+; playground::first_or_zero
+; Function Attrs: nonlazybind uwtable
+define noundef i32 @_ZN10playground13first_or_zero17h693ee13ff9fbfb7cE(ptr noalias noundef nonnull readonly align 4 captures(none) %values.0, i64 noundef range(i64 0, 2305843009213693952) %values.1) unnamed_addr #0 {
+start:
+  %_5.not = icmp eq i64 %values.1, 0
+  br i1 %_5.not, label %bb2, label %bb1, !prof !3
+
+bb2:                                              ; preds = %start
+; call core::option::unwrap_failed
+  tail call void @_RNvNtCsgEmfK2I1SDS_4core6option13unwrap_failed(ptr noalias noundef readonly align 8 captures(address, read_provenance) dereferenceable(24) @alloc_7a949f35e6ddbe66e80e80d2b5e21e32) #8
+  unreachable
+
+bb1:                                              ; preds = %start
+  %_7 = load i32, ptr %values.0, align 4, !noundef !4
+  ret i32 %_7
+}
+
+; playground::get
+; Function Attrs: noinline nonlazybind uwtable
+define noundef i32 @_ZN10playground3get17h53dae1e8bd5cfe57E(ptr noalias noundef nonnull readonly align 4 captures(none) %values.0, i64 noundef range(i64 0, 2305843009213693952) %values.1, i64 noundef %index) unnamed_addr #1 {
+start:
+  %_4 = icmp ult i64 %index, %values.1
+  br i1 %_4, label %bb1, label %panic
+
+bb1:                                              ; preds = %start
+  %0 = getelementptr inbounds nuw i32, ptr %values.0, i64 %index
+  %_0 = load i32, ptr %0, align 4, !noundef !4
+  ret i32 %_0
+
+panic:                                            ; preds = %start
+; call core::panicking::panic_bounds_check
+  tail call void @_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check(i64 noundef %index, i64 noundef %values.1, ptr noalias noundef readonly align 8 captures(address, read_provenance) dereferenceable(24) @alloc_0a8bf98f3b82fab2e8d83de81afa38a4) #8
+  unreachable
+}
+
+; playground::sum
+; Function Attrs: nofree noinline norecurse nosync nounwind nonlazybind memory(argmem: read) uwtable
+define noundef i32 @_ZN10playground3sum17h120ca45857a523a1E(ptr noalias noundef nonnull readonly align 4 captures(none) %values.0, i64 noundef range(i64 0, 2305843009213693952) %values.1) unnamed_addr #2 personality ptr @rust_eh_personality {
+start:
+  %0 = icmp eq i64 %values.1, 0
+  br i1 %0, label %"_ZN91_$LT$core..slice..iter..Iter$LT$T$GT$$u20$as$u20$core..iter..traits..iterator..Iterator$GT$4fold17h2b0920ce6c2f14faE.exit", label %bb8.i.preheader
+
+bb8.i.preheader:                                  ; preds = %start
+  %min.iters.check = icmp samesign ult i64 %values.1, 8
+  br i1 %min.iters.check, label %bb8.i.preheader3, label %vector.ph
+
+vector.ph:                                        ; preds = %bb8.i.preheader
+  %n.vec = and i64 %values.1, 2305843009213693944
+  br label %vector.body
+
+vector.body:                                      ; preds = %vector.body, %vector.ph
+  %index = phi i64 [ 0, %vector.ph ], [ %index.next, %vector.body ]
+  %vec.phi = phi <4 x i32> [ zeroinitializer, %vector.ph ], [ %3, %vector.body ]
+  %vec.phi1 = phi <4 x i32> [ zeroinitializer, %vector.ph ], [ %4, %vector.body ]
+  %1 = getelementptr inbounds nuw i32, ptr %values.0, i64 %index
+  %2 = getelementptr inbounds nuw i8, ptr %1, i64 16
+  %wide.load = load <4 x i32>, ptr %1, align 4
+  %wide.load2 = load <4 x i32>, ptr %2, align 4
+  %3 = add <4 x i32> %wide.load, %vec.phi
+  %4 = add <4 x i32> %wide.load2, %vec.phi1
+  %index.next = add nuw i64 %index, 8
+  %5 = icmp eq i64 %index.next, %n.vec
+  br i1 %5, label %middle.block, label %vector.body, !llvm.loop !5
+
+middle.block:                                     ; preds = %vector.body
+  %bin.rdx = add <4 x i32> %4, %3
+  %6 = tail call i32 @llvm.vector.reduce.add.v4i32(<4 x i32> %bin.rdx)
+  %cmp.n = icmp eq i64 %values.1, %n.vec
+  br i1 %cmp.n, label %"_ZN91_$LT$core..slice..iter..Iter$LT$T$GT$$u20$as$u20$core..iter..traits..iterator..Iterator$GT$4fold17h2b0920ce6c2f14faE.exit", label %bb8.i.preheader3
+
+bb8.i.preheader3:                                 ; preds = %bb8.i.preheader, %middle.block
+  %i.sroa.0.0.i.ph = phi i64 [ 0, %bb8.i.preheader ], [ %n.vec, %middle.block ]
+  %acc.sroa.0.0.i.ph = phi i32 [ 0, %bb8.i.preheader ], [ %6, %middle.block ]
+  br label %bb8.i
+
+bb8.i:                                            ; preds = %bb8.i.preheader3, %bb8.i
+  %i.sroa.0.0.i = phi i64 [ %_27.i, %bb8.i ], [ %i.sroa.0.0.i.ph, %bb8.i.preheader3 ]
+  %acc.sroa.0.0.i = phi i32 [ %_5.0.i.i, %bb8.i ], [ %acc.sroa.0.0.i.ph, %bb8.i.preheader3 ]
+  %_45.i = getelementptr inbounds nuw i32, ptr %values.0, i64 %i.sroa.0.0.i
+  %_45.val.i = load i32, ptr %_45.i, align 4, !noundef !4
+  %_5.0.i.i = add i32 %_45.val.i, %acc.sroa.0.0.i
+  %_27.i = add nuw nsw i64 %i.sroa.0.0.i, 1
+  %_28.i = icmp eq i64 %_27.i, %values.1
+  br i1 %_28.i, label %"_ZN91_$LT$core..slice..iter..Iter$LT$T$GT$$u20$as$u20$core..iter..traits..iterator..Iterator$GT$4fold17h2b0920ce6c2f14faE.exit", label %bb8.i, !llvm.loop !8
+
+"_ZN91_$LT$core..slice..iter..Iter$LT$T$GT$$u20$as$u20$core..iter..traits..iterator..Iterator$GT$4fold17h2b0920ce6c2f14faE.exit": ; preds = %bb8.i, %middle.block, %start
+  %_0.sroa.0.0.i = phi i32 [ 0, %start ], [ %6, %middle.block ], [ %_5.0.i.i, %bb8.i ]
+  ret i32 %_0.sroa.0.0.i
+}
+
+; core::option::unwrap_failed
+; Function Attrs: cold noinline noreturn nonlazybind uwtable
+declare void @_RNvNtCsgEmfK2I1SDS_4core6option13unwrap_failed(ptr noalias noundef readonly align 8 captures(address, read_provenance) dereferenceable(24)) unnamed_addr #3
+
+; core::panicking::panic_bounds_check
+; Function Attrs: cold minsize noinline noreturn nonlazybind optsize uwtable
+declare void @_RNvNtCsgEmfK2I1SDS_4core9panicking18panic_bounds_check(i64 noundef, i64 noundef, ptr noalias noundef readonly align 8 captures(address, read_provenance) dereferenceable(24)) unnamed_addr #4
+
+; Function Attrs: nounwind nonlazybind uwtable
+declare noundef range(i32 0, 10) i32 @rust_eh_personality(i32 noundef, i32 noundef, i64 noundef, ptr noundef, ptr noundef) unnamed_addr #5
+
+; Function Attrs: nocallback nofree nosync nounwind speculatable willreturn memory(none)
+declare i32 @llvm.vector.reduce.add.v4i32(<4 x i32>) #6
+
+attributes #0 = { nonlazybind uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #1 = { noinline nonlazybind uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #2 = { nofree noinline norecurse nosync nounwind nonlazybind memory(argmem: read) uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #3 = { cold noinline noreturn nonlazybind uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #4 = { cold minsize noinline noreturn nonlazybind optsize uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #5 = { nounwind nonlazybind uwtable "probe-stack"="inline-asm" "target-cpu"="x86-64" }
+attributes #6 = { nocallback nofree nosync nounwind speculatable willreturn memory(none) }
+attributes #7 = { noinline }
+attributes #8 = { noinline noreturn }
+
+!llvm.module.flags = !{!0, !1}
+!llvm.ident = !{!2}
+
+!0 = !{i32 8, !"PIC Level", i32 2}
+!1 = !{i32 2, !"RtLibUseGOT", i32 1}
+!2 = !{!"rustc version 1.95.0 (59807616e 2026-04-14)"}
+!3 = !{!"branch_weights", !"expected", i32 1, i32 2000}
+!4 = !{}
+!5 = distinct !{!5, !6, !7}
+!6 = !{!"llvm.loop.isvectorized", i32 1}
+!7 = !{!"llvm.loop.unroll.runtime.disable"}
+!8 = distinct !{!8, !7, !6}
@@ -0,0 +1,10 @@
+fn main() {
+	let x = 5;
+	if true {
+		x = 6;
+	}
+	println!("{x}");
+	let v = vec![1];
+	let w = v;
+	println!("{:?}", v);
+}
@@ -0,0 +1,57 @@
+(module
+  (type (;0;) (func (param i32 i32) (result i32)))
+  (type (;1;) (func (param i32) (result i64)))
+  (type (;2;) (func (param f64) (result f64)))
+  (type (;3;) (func))
+  (func $add (type 0) (param i32 i32) (result i32)
+    local.get 1
+    local.get 0
+    i32.add)
+  (func $factorial (type 1) (param i32) (result i64)
+    (local i64)
+    i64.const 1
+    local.set 1
+    block  ;; label = @1
+      local.get 0
+      i32.const 2
+      i32.lt_u
+      br_if 0 (;@1;)
+      loop  ;; label = @2
+        local.get 1
+        local.get 0
+        i64.extend_i32_u
+        i64.mul
+        local.set 1
+        local.get 0
+        i32.const -1
+        i32.add
+        local.tee 0
+        i32.const 1
+        i32.gt_u
+        br_if 0 (;@2;)
+      end
+    end
+    local.get 1)
+  (func $half (type 2) (param f64) (result f64)
+    local.get 0
+    f64.const 0x1p-1 (;=0.5;)
+    f64.mul)
+  (func $spin (type 3)
+    loop  ;; label = @1
+      br 0 (;@1;)
+    end)
+  (func $boom (type 3)
+    unreachable)
+  (table (;0;) 1 1 funcref)
+  (memory (;0;) 16)
+  (global $__stack_pointer (mut i32) (i32.const 1048576))
+  (global (;1;) i32 (i32.const 1048576))
+  (global (;2;) i32 (i32.const 1048576))
+  (export "memory" (memory 0))
+  (export "add" (func $add))
+  (export "factorial" (func $factorial))
+  (export "half" (func $half))
+  (export "spin" (func $spin))
+  (export "boom" (func $boom))
+  (export "__data_end" (global 1))
+  (export "__heap_base" (global 2)))